@@ -324,4 +324,121 @@ criterion_group!(
     bench_bf16_to_f64
 );
 
-criterion_main!(f16_sisd, bf16_sisd, f16_simd);
+// Bit patterns spread over the whole range, so that subnormal, overflowing and NaN inputs take
+// their branches as often as normal ones do
+fn spread_f32() -> Vec<f32> {
+    (0..SIMD_LARGE_BENCH_SLICE_LEN as u32)
+        .map(|i| f32::from_bits(i.wrapping_mul(0x0041_C64F)))
+        .collect()
+}
+
+fn spread_f64() -> Vec<f64> {
+    (0..SIMD_LARGE_BENCH_SLICE_LEN as u64)
+        .map(|i| f64::from_bits(i.wrapping_mul(0x0041_C64E_6D00_0001)))
+        .collect()
+}
+
+fn spread_u16() -> Vec<u16> {
+    (0..SIMD_LARGE_BENCH_SLICE_LEN as u16)
+        .map(|i| i.wrapping_mul(0x9E37))
+        .collect()
+}
+
+fn bench_spread_to_f16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Convert half From spread values");
+    let f32s = spread_f32();
+    let f64s = spread_f64();
+    group.bench_function("f16::from_f32", |b| {
+        b.iter(|| {
+            f32s.iter()
+                .map(|&x| f16::from_f32(x).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+    group.bench_function("f16::from_f32_round", |b| {
+        b.iter(|| {
+            f32s.iter()
+                .map(|&x| f16::from_f32_round(x, RoundingMode::NearestTiesToEven).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+    group.bench_function("f16::from_f64", |b| {
+        b.iter(|| {
+            f64s.iter()
+                .map(|&x| f16::from_f64(x).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+    group.bench_function("f16::from_f64_round", |b| {
+        b.iter(|| {
+            f64s.iter()
+                .map(|&x| f16::from_f64_round(x, RoundingMode::NearestTiesToEven).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+    group.bench_function("bf16::from_f64", |b| {
+        b.iter(|| {
+            f64s.iter()
+                .map(|&x| bf16::from_f64(x).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+    group.bench_function("bf16::from_f64_round", |b| {
+        b.iter(|| {
+            f64s.iter()
+                .map(|&x| bf16::from_f64_round(x, RoundingMode::NearestTiesToEven).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+}
+
+fn bench_spread_from_f16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Convert half to spread values");
+    let f16s: Vec<_> = spread_u16().into_iter().map(f16::from_bits).collect();
+    let bf16s: Vec<_> = spread_u16().into_iter().map(bf16::from_bits).collect();
+    group.bench_function("f16::to_f32", |b| {
+        b.iter(|| f16s.iter().map(|x| x.to_f32()).sum::<f32>())
+    });
+    group.bench_function("f16::to_f64", |b| {
+        b.iter(|| f16s.iter().map(|x| x.to_f64()).sum::<f64>())
+    });
+    group.bench_function("bf16::to_f64", |b| {
+        b.iter(|| bf16s.iter().map(|x| x.to_f64()).sum::<f64>())
+    });
+}
+
+fn bench_spread_slice_f32_to_f16(c: &mut Criterion) {
+    let f32s = spread_f32();
+    let mut buffer = [f16::ZERO; SIMD_LARGE_BENCH_SLICE_LEN];
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_f32_slice/spread",
+        |b: &mut Bencher<'_>| b.iter(|| buffer.convert_from_f32_slice(&f32s)),
+    );
+}
+
+fn bench_spread_slice_bf16_f16(c: &mut Criterion) {
+    let f16s: Vec<_> = spread_u16().into_iter().map(f16::from_bits).collect();
+    let bf16s: Vec<_> = spread_u16().into_iter().map(bf16::from_bits).collect();
+    let mut f16_buffer = [f16::ZERO; SIMD_LARGE_BENCH_SLICE_LEN];
+    let mut bf16_buffer = [bf16::ZERO; SIMD_LARGE_BENCH_SLICE_LEN];
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_bf16_slice/spread",
+        |b: &mut Bencher<'_>| b.iter(|| f16_buffer.convert_from_bf16_slice(&bf16s)),
+    );
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_f16_slice/spread",
+        |b: &mut Bencher<'_>| b.iter(|| bf16_buffer.convert_from_f16_slice(&f16s)),
+    );
+}
+
+// The default conversions should stay well ahead of the `_round` variants, which go through the
+// general rounding code
+criterion_group!(
+    spread,
+    bench_spread_to_f16,
+    bench_spread_from_f16,
+    bench_spread_slice_f32_to_f16,
+    bench_spread_slice_bf16_f16
+);
+
+criterion_main!(f16_sisd, bf16_sisd, f16_simd, spread);
@@ -0,0 +1,946 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+    },
+    num::FpCategory,
+    ops::Neg,
+    str::FromStr,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse, shortest, softfloat, ParseHalfError, RoundingMode, Saturation, Status};
+
+/// A 16-bit floating point type implementing the Arm alternative half-precision (AHP) format
+///
+/// AHP has the same layout as [`f16`][crate::f16], with 5 exponent bits, a bias of 15 and 10
+/// fraction bits, but the all-ones exponent holds ordinary normal values instead of ±∞ and NaN.
+/// This extends the range to ±131008, and every bit pattern is a finite number. It is selected by
+/// the `FPSCR.AHP` bit of Arm processors and is emitted by some sensor and DSP firmware.
+///
+/// Following the Arm conversion rules, values that overflow saturate to [`MAX`][f16ahp::MAX] or
+/// [`MIN`][f16ahp::MIN], ±∞ also saturates, and NaN converts to zero. All three are invalid
+/// operations, which the `_with_status` conversions report in [`Status::invalid`].
+///
+/// Like [`f16`][crate::f16], [`f16ahp`] is intended for storage. No arithmetic is implemented;
+/// convert to [`f32`] to compute.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// assert_eq!(f16ahp::from_f32(100000.0).to_f32(), 99968.0);
+/// assert_eq!(f16ahp::from_f32(1e6), f16ahp::MAX);
+/// assert_eq!(f16ahp::MAX.to_f32(), 131008.0);
+///
+/// let (x, status) = f16ahp::from_f32_with_status(f32::NAN);
+/// assert_eq!(x, f16ahp::ZERO);
+/// assert!(status.invalid);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct f16ahp(u16);
+
+/// A 16-bit floating point type implementing the IBM DLFloat16 format
+///
+/// DLFloat16 has 6 exponent bits with a bias of 31 and 9 fraction bits, for a precision of 10 bits
+/// and a range of about ±8.6 × 10⁹. It was designed for deep learning accelerators and trades the
+/// special cases of IEEE 754 for range: there are no subnormals, the zero exponent holds normal
+/// values except for ±0, and the all-ones pattern `S.111111.111111111` is the only NaN and also
+/// stands in for ±∞.
+///
+/// Values that overflow during conversion become that pattern, or [`MAX`][dlfloat16::MAX] with
+/// the saturating conversions. Values smaller than [`MIN_POSITIVE`][dlfloat16::MIN_POSITIVE] in
+/// magnitude after rounding flush to zero.
+///
+/// No arithmetic is implemented; convert to [`f32`] to compute.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// let x = dlfloat16::from_f32(0.3);
+///
+/// assert_eq!(x.to_f32(), 0.2998046875);
+/// assert_eq!(x.to_string(), "0.3");
+/// assert!(dlfloat16::from_f32(f32::INFINITY).is_nan());
+/// assert_eq!(dlfloat16::from_f32(1e-10), dlfloat16::ZERO);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct dlfloat16(u16);
+
+macro_rules! impl_alt16 {
+    ($ty:ident, $fmt:expr) => {
+        impl $ty {
+            /// Constructs a value from the raw bits
+            #[inline]
+            pub const fn from_bits(bits: u16) -> $ty {
+                $ty(bits)
+            }
+
+            /// Converts the value into its raw bits
+            #[inline]
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Returns the memory representation of the underlying bit representation as a byte
+            /// array in little-endian byte order
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; 2] {
+                self.0.to_le_bytes()
+            }
+
+            /// Returns the memory representation of the underlying bit representation as a byte
+            /// array in big-endian (network) byte order
+            #[inline]
+            pub fn to_be_bytes(self) -> [u8; 2] {
+                self.0.to_be_bytes()
+            }
+
+            /// Returns the memory representation of the underlying bit representation as a byte
+            /// array in native byte order
+            #[inline]
+            pub fn to_ne_bytes(self) -> [u8; 2] {
+                self.0.to_ne_bytes()
+            }
+
+            /// Creates a value from its representation as a byte array in little endian
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; 2]) -> $ty {
+                $ty::from_bits(u16::from_le_bytes(bytes))
+            }
+
+            /// Creates a value from its representation as a byte array in big endian
+            #[inline]
+            pub fn from_be_bytes(bytes: [u8; 2]) -> $ty {
+                $ty::from_bits(u16::from_be_bytes(bytes))
+            }
+
+            /// Creates a value from its representation as a byte array in native endian
+            #[inline]
+            pub fn from_ne_bytes(bytes: [u8; 2]) -> $ty {
+                $ty::from_bits(u16::from_ne_bytes(bytes))
+            }
+
+            /// Constructs a value from a 32-bit floating point value
+            ///
+            /// The value is rounded to nearest, ties to even. Values too large in magnitude, ±∞
+            /// and NaN are converted as described for the type.
+            #[inline]
+            pub fn from_f32(value: f32) -> $ty {
+                $ty::from_f32_round(value, RoundingMode::default())
+            }
+
+            /// Constructs a value from a 64-bit floating point value
+            ///
+            /// The value is rounded to nearest, ties to even, exactly once. Special values are
+            /// handled like [`from_f32`][Self::from_f32].
+            #[inline]
+            pub fn from_f64(value: f64) -> $ty {
+                $ty::from_f64_round(value, RoundingMode::default())
+            }
+
+            /// Constructs a value from a 32-bit floating point value using the given rounding mode
+            ///
+            /// Values that are not exactly representable are rounded once according to `mode`.
+            /// See [`RoundingMode`] for how `mode` affects values that are out of range.
+            #[inline]
+            pub fn from_f32_round(value: f32, mode: RoundingMode) -> $ty {
+                $ty(softfloat::from_f32($fmt, value, mode) as u16)
+            }
+
+            /// Constructs a value from a 64-bit floating point value using the given rounding mode
+            ///
+            /// See [`from_f32_round`][Self::from_f32_round] for details.
+            #[inline]
+            pub fn from_f64_round(value: f64, mode: RoundingMode) -> $ty {
+                $ty(softfloat::from_f64($fmt, value, mode) as u16)
+            }
+
+            /// Constructs a value from a 32-bit floating point value using stochastic rounding
+            ///
+            /// `random` should be a uniformly distributed random number, of which the most
+            /// significant bits are used as the rounding threshold, like
+            /// [`f16::from_f32_stochastic`][crate::f16::from_f32_stochastic].
+            #[inline]
+            pub fn from_f32_stochastic(value: f32, random: u32) -> $ty {
+                $ty(softfloat::from_f32($fmt, value, softfloat::Stochastic(random)) as u16)
+            }
+
+            /// Constructs a value from a 32-bit floating point value, clamping values that are out
+            /// of range to the largest finite magnitude
+            ///
+            /// Finite values are rounded to nearest exactly like [`from_f32`][Self::from_f32],
+            /// except that results that would overflow become [`MAX`][Self::MAX] or
+            /// [`MIN`][Self::MIN]. Whether ±∞ is also clamped, and whether NaN is converted to
+            /// zero, is controlled by `saturation`.
+            #[inline]
+            pub fn from_f32_saturating(value: f32, saturation: Saturation) -> $ty {
+                let bits = $ty::from_f32(value).0 as u32;
+                let bits = saturation.apply($fmt, value as f64, bits);
+                $ty(bits as u16)
+            }
+
+            /// Constructs a value from a 64-bit floating point value, clamping values that are out
+            /// of range to the largest finite magnitude
+            ///
+            /// See [`from_f32_saturating`][Self::from_f32_saturating] for details.
+            #[inline]
+            pub fn from_f64_saturating(value: f64, saturation: Saturation) -> $ty {
+                let bits = $ty::from_f64(value).0 as u32;
+                let bits = saturation.apply($fmt, value, bits);
+                $ty(bits as u16)
+            }
+
+            /// Constructs a value from a 32-bit floating point value, flushing subnormals to zero
+            ///
+            /// Subnormal inputs are treated as zero, and results that are subnormal after
+            /// rounding to nearest are replaced with zero. Both keep their sign. All other values
+            /// convert exactly like [`from_f32`][Self::from_f32].
+            #[inline]
+            pub fn from_f32_ftz(value: f32) -> $ty {
+                if value.classify() == FpCategory::Subnormal {
+                    $ty((value.to_bits() >> 16) as u16 & 0x8000)
+                } else {
+                    $ty::from_f32(value).flush_subnormal()
+                }
+            }
+
+            /// Constructs a value from a 64-bit floating point value, flushing subnormals to zero
+            ///
+            /// See [`from_f32_ftz`][Self::from_f32_ftz] for details.
+            #[inline]
+            pub fn from_f64_ftz(value: f64) -> $ty {
+                if value.classify() == FpCategory::Subnormal {
+                    $ty((value.to_bits() >> 48) as u16 & 0x8000)
+                } else {
+                    $ty::from_f64(value).flush_subnormal()
+                }
+            }
+
+            /// Constructs a value from a 32-bit floating point value, reporting IEEE exceptions
+            ///
+            /// The result is the same as [`from_f32`][Self::from_f32]. The returned [`Status`]
+            /// raises `inexact` when the value was rounded, `overflow` or `underflow` when it was
+            /// out of range, and `invalid` for inputs that have no counterpart in the format and
+            /// for signaling NaNs.
+            #[inline]
+            pub fn from_f32_with_status(value: f32) -> ($ty, Status) {
+                let mut status = Status::default();
+                let bits =
+                    softfloat::from_f32_status($fmt, value, RoundingMode::default(), &mut status);
+                ($ty(bits as u16), status)
+            }
+
+            /// Constructs a value from a 64-bit floating point value, reporting IEEE exceptions
+            ///
+            /// See [`from_f32_with_status`][Self::from_f32_with_status] for details.
+            #[inline]
+            pub fn from_f64_with_status(value: f64) -> ($ty, Status) {
+                let mut status = Status::default();
+                let bits =
+                    softfloat::from_f64_status($fmt, value, RoundingMode::default(), &mut status);
+                ($ty(bits as u16), status)
+            }
+
+            /// Parses a decimal number from ASCII bytes, rounding to nearest, ties to even
+            ///
+            /// The syntax is the same as for [`f16::from_ascii`][crate::f16::from_ascii]. The
+            /// literals `inf` and `nan` are converted like the corresponding [`f32`] values.
+            #[inline]
+            pub fn from_ascii(src: &[u8]) -> Result<$ty, ParseHalfError> {
+                parse::parse($fmt, src, false).map(|bits| $ty(bits as u16))
+            }
+
+            /// Parses a decimal number from ASCII bytes, rejecting values that overflow or
+            /// underflow
+            ///
+            /// This is like [`from_ascii`][Self::from_ascii], except that a finite value that is
+            /// too large in magnitude is reported as
+            /// [`Overflow`][crate::ParseHalfErrorKind::Overflow], and a nonzero value that would
+            /// round to ±0 is reported as [`Underflow`][crate::ParseHalfErrorKind::Underflow].
+            /// Formats without ±∞ or NaN also report `inf` as
+            /// [`Overflow`][crate::ParseHalfErrorKind::Overflow] and `nan` as
+            /// [`Invalid`][crate::ParseHalfErrorKind::Invalid].
+            #[inline]
+            pub fn from_ascii_strict(src: &[u8]) -> Result<$ty, ParseHalfError> {
+                parse::parse($fmt, src, true).map(|bits| $ty(bits as u16))
+            }
+
+            /// Parses a decimal number from a string, rejecting values that overflow or underflow
+            ///
+            /// See [`from_ascii_strict`][Self::from_ascii_strict] for details.
+            #[inline]
+            pub fn from_str_strict(src: &str) -> Result<$ty, ParseHalfError> {
+                $ty::from_ascii_strict(src.as_bytes())
+            }
+
+            /// Converts the value into an [`f32`] value
+            ///
+            /// This conversion is lossless as all values can be represented exactly in [`f32`].
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                softfloat::to_f32($fmt, self.0 as u32)
+            }
+
+            /// Converts the value into an [`f64`] value
+            ///
+            /// This conversion is lossless as all values can be represented exactly in [`f64`].
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.to_f32() as f64
+            }
+
+            /// Converts the value into an [`f32`] value, treating subnormal values as zero
+            ///
+            /// Subnormal values convert to a zero of the same sign; all other values convert
+            /// exactly like [`to_f32`][Self::to_f32].
+            #[inline]
+            pub fn to_f32_daz(self) -> f32 {
+                self.flush_subnormal().to_f32()
+            }
+
+            /// Converts the value into an [`f64`] value, treating subnormal values as zero
+            ///
+            /// Subnormal values convert to a zero of the same sign; all other values convert
+            /// exactly like [`to_f64`][Self::to_f64].
+            #[inline]
+            pub fn to_f64_daz(self) -> f64 {
+                self.flush_subnormal().to_f64()
+            }
+
+            /// Replaces a subnormal value with a zero of the same sign
+            ///
+            /// All other values are returned unchanged.
+            #[inline]
+            pub const fn flush_subnormal(self) -> $ty {
+                if $fmt.is_subnormal(self.0 as u32) {
+                    $ty(self.0 & 0x8000u16)
+                } else {
+                    self
+                }
+            }
+
+            /// Returns `true` if this value is NaN and `false` otherwise
+            ///
+            /// This is always `false` for formats without NaN.
+            #[inline]
+            pub const fn is_nan(self) -> bool {
+                $fmt.is_nan(self.0 as u32)
+            }
+
+            /// Returns `true` if this value is ±∞ and `false` otherwise
+            ///
+            /// This is always `false` for formats without infinities.
+            #[inline]
+            pub const fn is_infinite(self) -> bool {
+                $fmt.is_infinite(self.0 as u32)
+            }
+
+            /// Returns `true` if this number is neither infinite nor NaN
+            #[inline]
+            pub const fn is_finite(self) -> bool {
+                !self.is_nan() && !self.is_infinite()
+            }
+
+            /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN
+            #[inline]
+            pub const fn is_normal(self) -> bool {
+                self.0 & 0x7FFFu16 != 0 && !$fmt.is_subnormal(self.0 as u32) && self.is_finite()
+            }
+
+            /// Returns the floating point category of the number
+            ///
+            /// If only one property is going to be tested, it is generally faster to use the
+            /// specific predicate instead.
+            pub fn classify(self) -> FpCategory {
+                if self.is_nan() {
+                    FpCategory::Nan
+                } else if self.is_infinite() {
+                    FpCategory::Infinite
+                } else if self.0 & 0x7FFFu16 == 0 {
+                    FpCategory::Zero
+                } else if $fmt.is_subnormal(self.0 as u32) {
+                    FpCategory::Subnormal
+                } else {
+                    FpCategory::Normal
+                }
+            }
+
+            /// Returns a number that represents the sign of `self`
+            ///
+            /// * 1.0 if the number is positive, +0.0 or +∞
+            /// * −1.0 if the number is negative, −0.0 or −∞
+            /// * NaN if the number is NaN
+            pub fn signum(self) -> $ty {
+                if self.is_nan() {
+                    self
+                } else {
+                    $ty((self.0 & 0x8000u16) | $ty::ONE.0)
+                }
+            }
+
+            /// Returns `true` if and only if `self` has a positive sign, including +0.0, NaNs with
+            /// a positive sign bit and +∞
+            #[inline]
+            pub const fn is_sign_positive(self) -> bool {
+                self.0 & 0x8000u16 == 0
+            }
+
+            /// Returns `true` if and only if `self` has a negative sign, including −0.0, NaNs with
+            /// a negative sign bit and −∞
+            #[inline]
+            pub const fn is_sign_negative(self) -> bool {
+                self.0 & 0x8000u16 != 0
+            }
+        }
+
+        impl From<$ty> for f32 {
+            #[inline]
+            fn from(x: $ty) -> f32 {
+                x.to_f32()
+            }
+        }
+
+        impl From<$ty> for f64 {
+            #[inline]
+            fn from(x: $ty) -> f64 {
+                x.to_f64()
+            }
+        }
+
+        impl PartialEq for $ty {
+            fn eq(&self, other: &$ty) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    false
+                } else {
+                    (self.0 == other.0) || ((self.0 | other.0) & 0x7FFFu16 == 0)
+                }
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, other: &$ty) -> Option<Ordering> {
+                if self.is_nan() || other.is_nan() {
+                    None
+                } else {
+                    let neg = self.0 & 0x8000u16 != 0;
+                    let other_neg = other.0 & 0x8000u16 != 0;
+                    match (neg, other_neg) {
+                        (false, false) => Some(self.0.cmp(&other.0)),
+                        (false, true) => {
+                            if (self.0 | other.0) & 0x7FFFu16 == 0 {
+                                Some(Ordering::Equal)
+                            } else {
+                                Some(Ordering::Greater)
+                            }
+                        }
+                        (true, false) => {
+                            if (self.0 | other.0) & 0x7FFFu16 == 0 {
+                                Some(Ordering::Equal)
+                            } else {
+                                Some(Ordering::Less)
+                            }
+                        }
+                        (true, true) => Some(other.0.cmp(&self.0)),
+                    }
+                }
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseHalfError;
+            fn from_str(src: &str) -> Result<$ty, ParseHalfError> {
+                $ty::from_ascii(src.as_bytes())
+            }
+        }
+
+        impl Debug for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    Debug::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::debug($fmt, self.0 as u32, f)
+                }
+            }
+        }
+
+        impl Display for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    Display::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::display($fmt, self.0 as u32, f)
+                }
+            }
+        }
+
+        impl LowerExp for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    LowerExp::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::exp($fmt, self.0 as u32, false, f)
+                }
+            }
+        }
+
+        impl UpperExp for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    UpperExp::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::exp($fmt, self.0 as u32, true, f)
+                }
+            }
+        }
+
+        impl Binary for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:b}", self.0)
+            }
+        }
+
+        impl Octal for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:o}", self.0)
+            }
+        }
+
+        impl LowerHex for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:x}", self.0)
+            }
+        }
+
+        impl UpperHex for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:X}", self.0)
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(self.0 ^ 0x8000)
+            }
+        }
+    };
+}
+
+impl_alt16!(f16ahp, softfloat::AHP);
+impl_alt16!(dlfloat16, softfloat::DLF16);
+
+impl f16ahp {
+    /// Approximate number of [`f16ahp`] significant digits in base 10
+    pub const DIGITS: u32 = 3;
+    /// [`f16ahp`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f16ahp = f16ahp(0x1400u16);
+    /// Number of [`f16ahp`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 11;
+    /// Largest finite [`f16ahp`] value
+    pub const MAX: f16ahp = f16ahp(0x7FFFu16);
+    /// Maximum possible [`f16ahp`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 5;
+    /// Maximum possible [`f16ahp`] power of 2 exponent
+    pub const MAX_EXP: i32 = 17;
+    /// Smallest finite [`f16ahp`] value
+    pub const MIN: f16ahp = f16ahp(0xFFFFu16);
+    /// Minimum possible normal [`f16ahp`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -4;
+    /// One greater than the minimum possible normal [`f16ahp`] power of 2 exponent
+    pub const MIN_EXP: i32 = -13;
+    /// Smallest positive normal [`f16ahp`] value
+    pub const MIN_POSITIVE: f16ahp = f16ahp(0x0400u16);
+    /// The radix or base of the internal representation of [`f16ahp`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f16ahp`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f16ahp = f16ahp(0x0001u16);
+    /// Maximum subnormal [`f16ahp`] value
+    pub const MAX_SUBNORMAL: f16ahp = f16ahp(0x03FFu16);
+
+    /// [`f16ahp`] 1
+    pub const ONE: f16ahp = f16ahp(0x3C00u16);
+    /// [`f16ahp`] 0
+    pub const ZERO: f16ahp = f16ahp(0x0000u16);
+    /// [`f16ahp`] -0
+    pub const NEG_ZERO: f16ahp = f16ahp(0x8000u16);
+
+    /// [`f16ahp`] Euler's number (ℯ)
+    pub const E: f16ahp = f16ahp(0x4170u16);
+    /// [`f16ahp`] Archimedes' constant (π)
+    pub const PI: f16ahp = f16ahp(0x4248u16);
+    /// [`f16ahp`] 1/π
+    pub const FRAC_1_PI: f16ahp = f16ahp(0x3518u16);
+    /// [`f16ahp`] 1/√2
+    pub const FRAC_1_SQRT_2: f16ahp = f16ahp(0x39A8u16);
+    /// [`f16ahp`] 2/π
+    pub const FRAC_2_PI: f16ahp = f16ahp(0x3918u16);
+    /// [`f16ahp`] 2/√π
+    pub const FRAC_2_SQRT_PI: f16ahp = f16ahp(0x3C83u16);
+    /// [`f16ahp`] π/2
+    pub const FRAC_PI_2: f16ahp = f16ahp(0x3E48u16);
+    /// [`f16ahp`] π/3
+    pub const FRAC_PI_3: f16ahp = f16ahp(0x3C30u16);
+    /// [`f16ahp`] π/4
+    pub const FRAC_PI_4: f16ahp = f16ahp(0x3A48u16);
+    /// [`f16ahp`] π/6
+    pub const FRAC_PI_6: f16ahp = f16ahp(0x3830u16);
+    /// [`f16ahp`] π/8
+    pub const FRAC_PI_8: f16ahp = f16ahp(0x3648u16);
+    /// [`f16ahp`] 𝗅𝗇 10
+    pub const LN_10: f16ahp = f16ahp(0x409Bu16);
+    /// [`f16ahp`] 𝗅𝗇 2
+    pub const LN_2: f16ahp = f16ahp(0x398Cu16);
+    /// [`f16ahp`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: f16ahp = f16ahp(0x36F3u16);
+    /// [`f16ahp`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: f16ahp = f16ahp(0x34D1u16);
+    /// [`f16ahp`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: f16ahp = f16ahp(0x3DC5u16);
+    /// [`f16ahp`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: f16ahp = f16ahp(0x42A5u16);
+    /// [`f16ahp`] √2
+    pub const SQRT_2: f16ahp = f16ahp(0x3DA8u16);
+
+    /// Converts an [`f16`][crate::f16] value into [`f16ahp`]
+    ///
+    /// Finite values are copied exactly, since AHP only adds values beyond the range of
+    /// [`f16`][crate::f16]. ±∞ saturates to [`MAX`][f16ahp::MAX] or [`MIN`][f16ahp::MIN] and
+    /// NaN becomes zero, as in [`from_f32`][Self::from_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16ahp::from_f16(f16::MAX).to_bits(), f16::MAX.to_bits());
+    /// assert_eq!(f16ahp::from_f16(f16::NEG_INFINITY), f16ahp::MIN);
+    /// ```
+    #[inline]
+    pub fn from_f16(value: crate::f16) -> f16ahp {
+        if value.is_finite() {
+            f16ahp(value.to_bits())
+        } else {
+            f16ahp::from_f32(value.to_f32())
+        }
+    }
+
+    /// Converts the value into an [`f16`][crate::f16]
+    ///
+    /// Values with the all-ones exponent are beyond the range of [`f16`][crate::f16] and become
+    /// ±∞. All other values are copied exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16ahp::ONE.to_f16(), f16::ONE);
+    /// assert_eq!(f16ahp::MAX.to_f16(), f16::INFINITY);
+    /// ```
+    #[inline]
+    pub fn to_f16(self) -> crate::f16 {
+        crate::f16::from_f32(self.to_f32())
+    }
+}
+
+impl dlfloat16 {
+    /// Approximate number of [`dlfloat16`] significant digits in base 10
+    pub const DIGITS: u32 = 2;
+    /// [`dlfloat16`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: dlfloat16 = dlfloat16(0x2C00u16);
+    /// Number of [`dlfloat16`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 10;
+    /// Largest finite [`dlfloat16`] value
+    pub const MAX: dlfloat16 = dlfloat16(0x7FFEu16);
+    /// Maximum possible [`dlfloat16`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 9;
+    /// Maximum possible [`dlfloat16`] power of 2 exponent
+    pub const MAX_EXP: i32 = 33;
+    /// Smallest finite [`dlfloat16`] value
+    pub const MIN: dlfloat16 = dlfloat16(0xFFFEu16);
+    /// Minimum possible normal [`dlfloat16`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -9;
+    /// One greater than the minimum possible normal [`dlfloat16`] power of 2 exponent
+    pub const MIN_EXP: i32 = -30;
+    /// Smallest positive normal [`dlfloat16`] value
+    pub const MIN_POSITIVE: dlfloat16 = dlfloat16(0x0001u16);
+    /// [`dlfloat16`] Not a Number (NaN), which also stands in for ±∞
+    pub const NAN: dlfloat16 = dlfloat16(0x7FFFu16);
+    /// The radix or base of the internal representation of [`dlfloat16`]
+    pub const RADIX: u32 = 2;
+
+    /// [`dlfloat16`] 1
+    pub const ONE: dlfloat16 = dlfloat16(0x3E00u16);
+    /// [`dlfloat16`] 0
+    pub const ZERO: dlfloat16 = dlfloat16(0x0000u16);
+    /// [`dlfloat16`] -0
+    pub const NEG_ZERO: dlfloat16 = dlfloat16(0x8000u16);
+
+    /// [`dlfloat16`] Euler's number (ℯ)
+    pub const E: dlfloat16 = dlfloat16(0x40B8u16);
+    /// [`dlfloat16`] Archimedes' constant (π)
+    pub const PI: dlfloat16 = dlfloat16(0x4124u16);
+    /// [`dlfloat16`] 1/π
+    pub const FRAC_1_PI: dlfloat16 = dlfloat16(0x3A8Cu16);
+    /// [`dlfloat16`] 1/√2
+    pub const FRAC_1_SQRT_2: dlfloat16 = dlfloat16(0x3CD4u16);
+    /// [`dlfloat16`] 2/π
+    pub const FRAC_2_PI: dlfloat16 = dlfloat16(0x3C8Cu16);
+    /// [`dlfloat16`] 2/√π
+    pub const FRAC_2_SQRT_PI: dlfloat16 = dlfloat16(0x3E42u16);
+    /// [`dlfloat16`] π/2
+    pub const FRAC_PI_2: dlfloat16 = dlfloat16(0x3F24u16);
+    /// [`dlfloat16`] π/3
+    pub const FRAC_PI_3: dlfloat16 = dlfloat16(0x3E18u16);
+    /// [`dlfloat16`] π/4
+    pub const FRAC_PI_4: dlfloat16 = dlfloat16(0x3D24u16);
+    /// [`dlfloat16`] π/6
+    pub const FRAC_PI_6: dlfloat16 = dlfloat16(0x3C18u16);
+    /// [`dlfloat16`] π/8
+    pub const FRAC_PI_8: dlfloat16 = dlfloat16(0x3B24u16);
+    /// [`dlfloat16`] 𝗅𝗇 10
+    pub const LN_10: dlfloat16 = dlfloat16(0x404Du16);
+    /// [`dlfloat16`] 𝗅𝗇 2
+    pub const LN_2: dlfloat16 = dlfloat16(0x3CC6u16);
+    /// [`dlfloat16`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: dlfloat16 = dlfloat16(0x3B79u16);
+    /// [`dlfloat16`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: dlfloat16 = dlfloat16(0x3A69u16);
+    /// [`dlfloat16`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: dlfloat16 = dlfloat16(0x3EE3u16);
+    /// [`dlfloat16`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: dlfloat16 = dlfloat16(0x4152u16);
+    /// [`dlfloat16`] √2
+    pub const SQRT_2: dlfloat16 = dlfloat16(0x3ED4u16);
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::{f16, ParseHalfErrorKind};
+    use core::f64::consts;
+    use std::format;
+
+    #[test]
+    fn exhaustive_round_trip() {
+        for bits in 0..=0xFFFFu16 {
+            let x = f16ahp::from_bits(bits);
+            assert!(x.is_finite());
+            assert_eq!(f16ahp::from_f32(x.to_f32()).to_bits(), bits);
+            assert_eq!(f16ahp::from_f64(x.to_f64()).to_bits(), bits);
+            assert_eq!(format!("{}", x).parse::<f16ahp>().unwrap().to_bits(), bits);
+
+            let y = dlfloat16::from_bits(bits);
+            if y.is_nan() {
+                assert!(y.to_f32().is_nan());
+                assert!(dlfloat16::from_f32(y.to_f32()).is_nan());
+            } else {
+                assert_eq!(dlfloat16::from_f32(y.to_f32()).to_bits(), bits);
+                assert_eq!(
+                    format!("{:e}", y).parse::<dlfloat16>().unwrap().to_bits(),
+                    bits
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ahp_matches_f16() {
+        // Below the all-ones exponent, AHP has the same encoding as binary16
+        for bits in 0..0x7C00u16 {
+            let x = f16::from_bits(bits);
+            assert_eq!(f16ahp::from_bits(bits).to_f32(), x.to_f32());
+            assert_eq!(f16ahp::from_f16(x).to_bits(), bits);
+            assert_eq!(f16ahp::from_bits(bits).to_f16().to_bits(), bits);
+        }
+        assert_eq!(f16ahp::from_bits(0x7C00).to_f32(), 65536.0);
+        assert_eq!(f16ahp::from_f32(65520.0).to_bits(), 0x7C00);
+        assert_eq!(f16ahp::from_f32(-65536.0).to_bits(), 0xFC00);
+    }
+
+    #[test]
+    fn ahp_saturation() {
+        // Halfway past the largest value still saturates instead of overflowing
+        let (x, status) = f16ahp::from_f32_with_status(131072.0);
+        assert_eq!(x, f16ahp::MAX);
+        assert!(status.invalid && !status.overflow);
+        assert_eq!(f16ahp::from_f32(131040.0), f16ahp::MAX);
+        assert_eq!(f16ahp::from_f64(-1e300), f16ahp::MIN);
+        assert_eq!(f16ahp::from_f32(f32::INFINITY), f16ahp::MAX);
+        assert_eq!(f16ahp::from_f32(f32::NEG_INFINITY), f16ahp::MIN);
+        assert_eq!(f16ahp::from_f32(f32::NAN).to_bits(), 0);
+        assert_eq!(f16ahp::from_f32(-f32::NAN).to_bits(), 0x8000);
+        assert_eq!(
+            f16ahp::from_f32_round(1e6, RoundingMode::TowardPositive),
+            f16ahp::MAX
+        );
+        let (_, status) = f16ahp::from_f32_with_status(1.0);
+        assert!(status.is_empty());
+
+        assert_eq!("1e6".parse::<f16ahp>(), Ok(f16ahp::MAX));
+        assert_eq!("inf".parse::<f16ahp>(), Ok(f16ahp::MAX));
+        assert!(f16ahp::from_str_strict("131008").is_ok());
+        assert!(f16ahp::from_str_strict("131072").is_err());
+        assert!(f16ahp::from_str_strict("1e-10").is_err());
+        assert_eq!(
+            f16ahp::from_str_strict("nan").unwrap_err().kind(),
+            ParseHalfErrorKind::Invalid
+        );
+        assert_eq!(
+            f16ahp::from_str_strict("-inf").unwrap_err().kind(),
+            ParseHalfErrorKind::Overflow
+        );
+        assert!(dlfloat16::from_str_strict("nan").unwrap().is_nan());
+        assert!(dlfloat16::from_str_strict("inf").unwrap().is_nan());
+    }
+
+    #[test]
+    fn dlfloat_encoding() {
+        assert_eq!(dlfloat16::ONE.to_f32(), 1.0);
+        assert_eq!(dlfloat16::MAX.to_f64(), 8573157376.0);
+        assert_eq!(
+            dlfloat16::MIN_POSITIVE.to_f64(),
+            2f64.powi(-31) * (1.0 + 1.0 / 512.0)
+        );
+        assert!(dlfloat16::from_bits(0xFFFF).is_nan());
+        assert!(!dlfloat16::MAX.is_nan());
+        assert!(dlfloat16::NAN.to_f32().is_nan());
+        assert!((-dlfloat16::NAN).is_nan());
+        assert_eq!(dlfloat16::MIN_POSITIVE.classify(), FpCategory::Normal);
+        assert_eq!(
+            dlfloat16::MIN_POSITIVE.flush_subnormal(),
+            dlfloat16::MIN_POSITIVE
+        );
+        assert_eq!(dlfloat16::ZERO.classify(), FpCategory::Zero);
+
+        // Overflow, ±∞ and NaN all produce the NaN pattern with the sign of the input
+        let (x, status) = dlfloat16::from_f32_with_status(1e10);
+        assert_eq!(x.to_bits(), 0x7FFF);
+        assert!(status.overflow && status.inexact);
+        assert_eq!(dlfloat16::from_f32(f32::NEG_INFINITY).to_bits(), 0xFFFF);
+        assert_eq!(dlfloat16::from_f32(f32::NAN).to_bits(), 0x7FFF);
+        let keep = Saturation::default();
+        let clamp = Saturation {
+            clamp_infinity: true,
+            nan_to_zero: false,
+        };
+        assert_eq!(dlfloat16::from_f32_saturating(-1e10, keep), dlfloat16::MIN);
+        assert!(dlfloat16::from_f32_saturating(f32::INFINITY, keep).is_nan());
+        assert_eq!(
+            dlfloat16::from_f32_saturating(f32::INFINITY, clamp),
+            dlfloat16::MAX
+        );
+        assert_eq!(
+            dlfloat16::from_f32_round(1e10, RoundingMode::TowardZero),
+            dlfloat16::MAX
+        );
+
+        // No subnormals: anything below the smallest normal after rounding flushes to zero
+        let min = dlfloat16::MIN_POSITIVE.to_f64();
+        let ulp = 2f64.powi(-40);
+        assert_eq!(
+            dlfloat16::from_f64(min - ulp / 4.0),
+            dlfloat16::MIN_POSITIVE
+        );
+        assert_eq!(dlfloat16::from_f64(min - ulp / 2.0), dlfloat16::ZERO);
+        let (x, status) = dlfloat16::from_f64_with_status(-(min - ulp));
+        assert_eq!(x.to_bits(), 0x8000);
+        assert!(status.underflow && status.inexact);
+        assert_eq!(dlfloat16::from_f64(2f64.powi(-40)), dlfloat16::ZERO);
+        assert!(dlfloat16::from_str_strict("1e-10").is_err());
+        assert!(dlfloat16::from_str_strict("1e10").is_err());
+        assert!(dlfloat16::from_str_strict("1e9").is_ok());
+    }
+
+    #[test]
+    fn constants() {
+        macro_rules! check {
+            ($ty:ident) => {
+                assert_eq!($ty::ONE.to_f32(), 1.0);
+                assert_eq!($ty::ZERO.to_f32(), 0.0);
+                assert_eq!($ty::NEG_ZERO.to_f32().to_bits(), (-0.0f32).to_bits());
+                assert_eq!(
+                    $ty::ONE.to_f32() + $ty::EPSILON.to_f32(),
+                    $ty::from_bits($ty::ONE.to_bits() + 1).to_f32()
+                );
+                assert_eq!($ty::MIN, -$ty::MAX);
+                assert_eq!(
+                    $ty::MANTISSA_DIGITS,
+                    $ty::ONE.to_bits().trailing_zeros() + 1
+                );
+                let max = $ty::MAX.to_f64();
+                assert_eq!($ty::MAX_EXP, max.log2().floor() as i32 + 1);
+                assert_eq!($ty::MAX_10_EXP, max.log10().floor() as i32);
+                let min = $ty::MIN_POSITIVE.to_f64();
+                assert_eq!($ty::MIN_EXP, min.log2().floor() as i32 + 1);
+                assert_eq!($ty::MIN_10_EXP, min.log10().ceil() as i32);
+                assert_eq!(
+                    $ty::DIGITS,
+                    (($ty::MANTISSA_DIGITS - 1) as f64 * 2f64.log10()).floor() as u32
+                );
+
+                assert_eq!($ty::E, $ty::from_f64(consts::E));
+                assert_eq!($ty::PI, $ty::from_f64(consts::PI));
+                assert_eq!($ty::FRAC_1_PI, $ty::from_f64(consts::FRAC_1_PI));
+                assert_eq!($ty::FRAC_1_SQRT_2, $ty::from_f64(consts::FRAC_1_SQRT_2));
+                assert_eq!($ty::FRAC_2_PI, $ty::from_f64(consts::FRAC_2_PI));
+                assert_eq!($ty::FRAC_2_SQRT_PI, $ty::from_f64(consts::FRAC_2_SQRT_PI));
+                assert_eq!($ty::FRAC_PI_2, $ty::from_f64(consts::FRAC_PI_2));
+                assert_eq!($ty::FRAC_PI_3, $ty::from_f64(consts::FRAC_PI_3));
+                assert_eq!($ty::FRAC_PI_4, $ty::from_f64(consts::FRAC_PI_4));
+                assert_eq!($ty::FRAC_PI_6, $ty::from_f64(consts::FRAC_PI_6));
+                assert_eq!($ty::FRAC_PI_8, $ty::from_f64(consts::FRAC_PI_8));
+                assert_eq!($ty::LN_10, $ty::from_f64(consts::LN_10));
+                assert_eq!($ty::LN_2, $ty::from_f64(consts::LN_2));
+                assert_eq!($ty::LOG10_E, $ty::from_f64(consts::LOG10_E));
+                assert_eq!($ty::LOG10_2, $ty::from_f64(2f64.log10()));
+                assert_eq!($ty::LOG2_E, $ty::from_f64(consts::LOG2_E));
+                assert_eq!($ty::LOG2_10, $ty::from_f64(10f64.log2()));
+                assert_eq!($ty::SQRT_2, $ty::from_f64(consts::SQRT_2));
+            };
+        }
+        check!(f16ahp);
+        check!(dlfloat16);
+        assert_eq!(f16ahp::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-24));
+        assert_eq!(f16ahp::MAX_SUBNORMAL.classify(), FpCategory::Subnormal);
+    }
+
+    #[test]
+    fn compare_and_format() {
+        assert!(f16ahp::MIN < f16ahp::NEG_ZERO);
+        assert_eq!(f16ahp::NEG_ZERO, f16ahp::ZERO);
+        assert!(dlfloat16::NAN.partial_cmp(&dlfloat16::ONE).is_none());
+        assert!(dlfloat16::NAN != dlfloat16::NAN);
+        assert!(dlfloat16::MIN_POSITIVE > -dlfloat16::MAX);
+
+        assert_eq!(format!("{}", f16ahp::MAX), "131000");
+        assert_eq!(format!("{:?}", f16ahp::from_f32(1.5)), "1.5");
+        assert_eq!(format!("{:e}", dlfloat16::MAX), "8.57e9");
+        assert_eq!(format!("{:?}", dlfloat16::NAN), "NaN");
+        assert_eq!(format!("{:.2}", dlfloat16::ONE), "1.00");
+        assert_eq!(format!("{:X}", f16ahp::ONE), "3C00");
+        assert_eq!(dlfloat16::from_f32(-2.5).signum(), -dlfloat16::ONE);
+    }
+}
@@ -5,13 +5,18 @@ use core::{
     fmt::{
         Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
     },
-    num::{FpCategory, ParseFloatError},
+    num::FpCategory,
     ops::{Add, Div, Mul, Neg, Rem, Sub},
     str::FromStr,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    f16, hex, parse, shortest, softfloat, FpClass, HexFloat, ParseHalfError, RoundingMode,
+    Saturation, Status,
+};
+
 pub(crate) mod convert;
 
 /// A 16-bit floating point type implementing the [`bfloat16`] format
@@ -21,9 +26,11 @@ pub(crate) mod convert;
 /// having a lower precision than [`f16`][crate::f16]. While [`f16`][crate::f16] has a precision of
 /// 11 bits, [`bf16`] has a precision of only 8 bits.
 ///
-/// Like [`f16`][crate::f16], [`bf16`] does not offer arithmetic operations as it is intended for
-/// compact storage rather than calculations. Operations should be performed with [`f32`] or
-/// higher-precision types and converted to/from [`bf16`] as necessary.
+/// Like [`f16`][crate::f16], [`bf16`] is intended for compact storage rather than calculations, so
+/// only the basic arithmetic operators, [`sqrt`][bf16::sqrt] and [`mul_add`][bf16::mul_add] are
+/// implemented. These are computed in software on the 16-bit representation and are correctly
+/// rounded. Other operations should be performed with [`f32`] or higher-precision types and
+/// converted to/from [`bf16`] as necessary.
 ///
 /// [`bfloat16`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
 #[allow(non_camel_case_types)]
@@ -61,12 +68,405 @@ impl bf16 {
         bf16(convert::f64_to_bf16(value))
     }
 
+    /// Constructs a [`bf16`] value from a 32-bit floating point value using the given rounding mode
+    ///
+    /// Values that are not exactly representable are rounded once according to `mode`. Whether
+    /// out of range values become ±∞ or the largest finite value also depends on `mode`; see
+    /// [`RoundingMode`] for details. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = 1.0 + f32::EPSILON;
+    ///
+    /// assert_eq!(bf16::from_f32_round(x, RoundingMode::TowardZero), bf16::ONE);
+    /// assert!(bf16::from_f32_round(x, RoundingMode::TowardPositive) > bf16::ONE);
+    /// assert_eq!(bf16::from_f32_round(f32::MAX, RoundingMode::TowardZero), bf16::MAX);
+    /// ```
+    #[inline]
+    pub fn from_f32_round(value: f32, mode: RoundingMode) -> bf16 {
+        bf16(softfloat::from_f32(softfloat::BF16, value, mode) as u16)
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value using the given rounding mode
+    ///
+    /// Values that are not exactly representable are rounded once according to `mode`. Whether
+    /// out of range values become ±∞ or the largest finite value also depends on `mode`; see
+    /// [`RoundingMode`] for details. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = -(1.0 + f64::EPSILON);
+    ///
+    /// assert_eq!(bf16::from_f64_round(x, RoundingMode::TowardPositive), -bf16::ONE);
+    /// assert!(bf16::from_f64_round(x, RoundingMode::TowardNegative) < -bf16::ONE);
+    /// ```
+    #[inline]
+    pub fn from_f64_round(value: f64, mode: RoundingMode) -> bf16 {
+        bf16(softfloat::from_f64(softfloat::BF16, value, mode) as u16)
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value using stochastic rounding
+    ///
+    /// A value that is not exactly representable is rounded away from zero with probability
+    /// proportional to its distance from the value truncated towards zero, so the result is
+    /// unbiased on average. `random` supplies the randomness and should be uniformly distributed
+    /// over all of [`u32`]; the same `value` and `random` always give the same result, which makes
+    /// results reproducible from a seeded generator. Exactly representable values and NaN are
+    /// never affected, and values beyond the finite range may round to ±∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // A quarter of the way from 1 to the next representable value
+    /// let x = 1.0 + bf16::EPSILON.to_f32() / 4.0;
+    ///
+    /// assert_eq!(bf16::from_f32_stochastic(x, 0), bf16::from_bits(bf16::ONE.to_bits() + 1));
+    /// assert_eq!(bf16::from_f32_stochastic(x, u32::MAX / 2), bf16::ONE);
+    /// assert_eq!(bf16::from_f32_stochastic(1.0, 0), bf16::ONE);
+    /// ```
+    #[inline]
+    pub fn from_f32_stochastic(value: f32, random: u32) -> bf16 {
+        bf16(softfloat::from_f32(softfloat::BF16, value, softfloat::Stochastic(random)) as u16)
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, clamping values that are
+    /// out of range to the largest finite magnitude instead of overflowing to ±∞
+    ///
+    /// Finite values are rounded to nearest exactly like [`from_f32`][Self::from_f32], except that
+    /// results that would overflow become [`MAX`][bf16::MAX] or [`MIN`][bf16::MIN]. Whether ±∞ is
+    /// also clamped, and whether NaN is converted to zero, is controlled by `saturation`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let keep = Saturation::default();
+    /// let clamp = Saturation { clamp_infinity: true, nan_to_zero: true };
+    ///
+    /// assert_eq!(bf16::from_f32_saturating(f32::MAX, keep), bf16::MAX);
+    /// assert_eq!(bf16::from_f32_saturating(f32::MIN, keep), bf16::MIN);
+    /// assert_eq!(bf16::from_f32_saturating(f32::INFINITY, keep), bf16::INFINITY);
+    /// assert_eq!(bf16::from_f32_saturating(f32::INFINITY, clamp), bf16::MAX);
+    /// assert!(bf16::from_f32_saturating(f32::NAN, keep).is_nan());
+    /// assert_eq!(bf16::from_f32_saturating(f32::NAN, clamp), bf16::ZERO);
+    /// ```
+    #[inline]
+    pub fn from_f32_saturating(value: f32, saturation: Saturation) -> bf16 {
+        let bits = bf16::from_f32(value).0 as u32;
+        let bits = saturation.apply(softfloat::BF16, value as f64, bits);
+        bf16(bits as u16)
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, clamping values that are
+    /// out of range to the largest finite magnitude instead of overflowing to ±∞
+    ///
+    /// Finite values are rounded to nearest exactly like [`from_f64`][Self::from_f64], except that
+    /// results that would overflow become [`MAX`][bf16::MAX] or [`MIN`][bf16::MIN]. Whether ±∞ is
+    /// also clamped, and whether NaN is converted to zero, is controlled by `saturation`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let keep = Saturation::default();
+    ///
+    /// assert_eq!(bf16::from_f64_saturating(1e300, keep), bf16::MAX);
+    /// assert_eq!(bf16::from_f64_saturating(-1e300, keep), bf16::MIN);
+    /// assert_eq!(bf16::from_f64_saturating(f64::NEG_INFINITY, keep), bf16::NEG_INFINITY);
+    /// ```
+    #[inline]
+    pub fn from_f64_saturating(value: f64, saturation: Saturation) -> bf16 {
+        let bits = bf16::from_f64(value).0 as u32;
+        let bits = saturation.apply(softfloat::BF16, value, bits);
+        bf16(bits as u16)
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, flushing subnormals to zero
+    ///
+    /// This emulates hardware running in flush-to-zero (FTZ) and denormals-are-zero (DAZ) modes.
+    /// Subnormal 32-bit inputs are treated as zero, and results that are subnormal after rounding
+    /// to nearest are replaced with zero. Both keep their sign. All other values convert exactly
+    /// like [`from_f32`][Self::from_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let tiny = bf16::MIN_POSITIVE.to_f32() / 2.0;
+    ///
+    /// assert_ne!(bf16::from_f32(tiny), bf16::ZERO);
+    /// assert_eq!(bf16::from_f32_ftz(tiny).to_bits(), bf16::ZERO.to_bits());
+    /// assert_eq!(bf16::from_f32_ftz(-tiny).to_bits(), bf16::NEG_ZERO.to_bits());
+    /// assert_eq!(bf16::from_f32_ftz(1.5), bf16::from_f32(1.5));
+    /// ```
+    #[inline]
+    pub fn from_f32_ftz(value: f32) -> bf16 {
+        if value.classify() == FpCategory::Subnormal {
+            bf16((value.to_bits() >> 16) as u16 & 0x8000)
+        } else {
+            bf16::from_f32(value).flush_subnormal()
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, flushing subnormals to zero
+    ///
+    /// This emulates hardware running in flush-to-zero (FTZ) and denormals-are-zero (DAZ) modes.
+    /// Subnormal 64-bit inputs are treated as zero, and results that are subnormal after rounding
+    /// to nearest are replaced with zero. Both keep their sign. All other values convert exactly
+    /// like [`from_f64`][Self::from_f64].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let tiny = bf16::MIN_POSITIVE.to_f64() / 2.0;
+    ///
+    /// assert_eq!(bf16::from_f64_ftz(-tiny).to_bits(), bf16::NEG_ZERO.to_bits());
+    /// assert_eq!(bf16::from_f64_ftz(1.5), bf16::from_f64(1.5));
+    /// ```
+    #[inline]
+    pub fn from_f64_ftz(value: f64) -> bf16 {
+        if value.classify() == FpCategory::Subnormal {
+            bf16((value.to_bits() >> 48) as u16 & 0x8000)
+        } else {
+            bf16::from_f64(value).flush_subnormal()
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, reporting IEEE exceptions
+    ///
+    /// The result is the same as [`from_f32`][Self::from_f32]. The returned [`Status`] raises
+    /// `inexact` if the value was rounded, `overflow` if it became ±∞, `underflow` if it was tiny
+    /// and inexact, and `invalid` if it was a signaling NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let (x, status) = bf16::from_f32_with_status(0.1);
+    ///
+    /// assert_eq!(x, bf16::from_f32(0.1));
+    /// assert!(status.inexact && !status.overflow);
+    ///
+    /// let (_, status) = bf16::from_f32_with_status(f32::MAX);
+    /// assert!(status.overflow && status.inexact);
+    /// ```
+    #[inline]
+    pub fn from_f32_with_status(value: f32) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::from_f32_status(
+            softfloat::BF16,
+            value,
+            RoundingMode::default(),
+            &mut status,
+        );
+        (bf16(bits as u16), status)
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, reporting IEEE exceptions
+    ///
+    /// The result is the same as [`from_f64`][Self::from_f64]. The returned [`Status`] raises
+    /// flags as described for [`from_f32_with_status`][Self::from_f32_with_status].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let (x, status) = bf16::from_f64_with_status(1e-300);
+    ///
+    /// assert_eq!(x, bf16::ZERO);
+    /// assert!(status.underflow && status.inexact);
+    /// assert!(bf16::from_f64_with_status(2.0).1.is_empty());
+    /// ```
+    #[inline]
+    pub fn from_f64_with_status(value: f64) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::from_f64_status(
+            softfloat::BF16,
+            value,
+            RoundingMode::default(),
+            &mut status,
+        );
+        (bf16(bits as u16), status)
+    }
+
+    /// Parses a decimal number from ASCII bytes, rounding to the nearest [`bf16`] value
+    ///
+    /// This accepts the same syntax as [`FromStr`], but on a byte slice so that callers do not
+    /// need to validate UTF-8 first. The decimal value is rounded once, directly to the nearest
+    /// representable value with ties to even. Values too large to represent become ±∞ and values
+    /// too small become ±0; use [`from_ascii_strict`][Self::from_ascii_strict] to reject them
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_ascii(b"1.5"), Ok(bf16::from_f32(1.5)));
+    /// assert_eq!(bf16::from_ascii(b"-inf"), Ok(bf16::NEG_INFINITY));
+    /// assert_eq!(bf16::from_ascii(b"1e39"), Ok(bf16::INFINITY));
+    /// assert!(bf16::from_ascii(b"1.5x").is_err());
+    /// ```
+    #[inline]
+    pub fn from_ascii(src: &[u8]) -> Result<bf16, ParseHalfError> {
+        parse::parse(softfloat::BF16, src, false).map(|bits| bf16(bits as u16))
+    }
+
+    /// Parses a decimal number from ASCII bytes, rejecting values that overflow or underflow
+    ///
+    /// This is like [`from_ascii`][Self::from_ascii], except that a finite value that would round
+    /// to ±∞ is reported as [`Overflow`][crate::ParseHalfErrorKind::Overflow], and a nonzero value
+    /// that would round to ±0 is reported as
+    /// [`Underflow`][crate::ParseHalfErrorKind::Underflow]. The literals `inf`, `nan` and zero are
+    /// still accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::ParseHalfErrorKind;
+    ///
+    /// assert_eq!(bf16::from_ascii_strict(b"3.3895314e38"), Ok(bf16::MAX));
+    /// assert_eq!(
+    ///     bf16::from_ascii_strict(b"1e39").unwrap_err().kind(),
+    ///     ParseHalfErrorKind::Overflow
+    /// );
+    /// assert_eq!(
+    ///     bf16::from_ascii_strict(b"1e-50").unwrap_err().kind(),
+    ///     ParseHalfErrorKind::Underflow
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_ascii_strict(src: &[u8]) -> Result<bf16, ParseHalfError> {
+        parse::parse(softfloat::BF16, src, true).map(|bits| bf16(bits as u16))
+    }
+
+    /// Parses a decimal number from a string, rejecting values that overflow or underflow
+    ///
+    /// See [`from_ascii_strict`][Self::from_ascii_strict] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_str_strict("0.5"), Ok(bf16::from_f32(0.5)));
+    /// assert!(bf16::from_str_strict("1e39").is_err());
+    /// ```
+    #[inline]
+    pub fn from_str_strict(src: &str) -> Result<bf16, ParseHalfError> {
+        bf16::from_ascii_strict(src.as_bytes())
+    }
+
+    /// Parses a hexadecimal floating point number from ASCII bytes, such as `0x1.8p+3`
+    ///
+    /// The syntax is that of C's `strtod`: an optional sign, a `0x` or `0X` prefix, hex digits
+    /// with an optional point, and an optional binary exponent introduced by `p` or `P`. `inf`,
+    /// `infinity` and `nan` are also accepted, ignoring case. Values that are not exactly
+    /// representable are rounded to nearest, ties to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_hex_ascii(b"0x1.8p+3"), Ok(bf16::from_f32(12.0)));
+    /// assert_eq!(bf16::from_hex_ascii(b"0x1.fep+127"), Ok(bf16::MAX));
+    /// assert_eq!(bf16::from_hex_ascii(b"0x1p-133"), Ok(bf16::MIN_POSITIVE_SUBNORMAL));
+    /// assert!(bf16::from_hex_ascii(b"1.5").is_err());
+    /// ```
+    #[inline]
+    pub fn from_hex_ascii(src: &[u8]) -> Result<bf16, ParseHalfError> {
+        hex::parse(softfloat::BF16, src).map(|bits| bf16(bits as u16))
+    }
+
+    /// Parses a hexadecimal floating point number from a string, such as `0x1.8p+3`
+    ///
+    /// See [`from_hex_ascii`][Self::from_hex_ascii] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_hex_str("-0x1p-1"), Ok(bf16::from_f32(-0.5)));
+    /// ```
+    #[inline]
+    pub fn from_hex_str(src: &str) -> Result<bf16, ParseHalfError> {
+        bf16::from_hex_ascii(src.as_bytes())
+    }
+
+    /// Returns a wrapper that formats the value exactly in hexadecimal, such as `0x1.8p+3`
+    ///
+    /// See [`HexFloat`] for the output format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(12.0);
+    /// assert_eq!(x.to_hex().to_string(), "0x1.8p+3");
+    /// assert_eq!(format!("{:X}", x.to_hex()), "0X1.8P+3");
+    /// assert_eq!(bf16::from_hex_str(&x.to_hex().to_string()), Ok(x));
+    /// ```
+    #[inline]
+    pub const fn to_hex(self) -> HexFloat<bf16> {
+        HexFloat(self)
+    }
+
     /// Converts a [`bf16`] into the underlying bit representation
     #[inline]
     pub const fn to_bits(self) -> u16 {
         self.0
     }
 
+    /// Splits the number into its sign, biased exponent and trailing significand fields
+    ///
+    /// The exponent is the raw 8-bit field, from 0 for zeros and subnormals to 255 for infinities
+    /// and NaN. The significand is the 7 stored bits, without the implicit leading bit of normal
+    /// numbers. [`from_parts`][Self::from_parts] reassembles the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-1.5).to_parts(), (true, 127, 0x40));
+    /// assert_eq!(bf16::INFINITY.to_parts(), (false, 255, 0));
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_parts(), (false, 0, 1));
+    /// ```
+    #[inline]
+    pub const fn to_parts(self) -> (bool, u8, u16) {
+        (
+            self.0 & 0x8000u16 != 0,
+            ((self.0 & 0x7F80u16) >> 7) as u8,
+            self.0 & 0x007Fu16,
+        )
+    }
+
+    /// Assembles a number from its sign, biased exponent and trailing significand fields
+    ///
+    /// This is the inverse of [`to_parts`][Self::to_parts]. Returns `None` if `mantissa` does not fit
+    /// in 7 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_parts(true, 127, 0x40), Some(bf16::from_f32(-1.5)));
+    /// assert_eq!(bf16::from_parts(false, 0, 1), Some(bf16::MIN_POSITIVE_SUBNORMAL));
+    /// assert_eq!(bf16::from_parts(false, 0, 0x80), None);
+    /// ```
+    #[inline]
+    pub const fn from_parts(sign: bool, exp: u8, mantissa: u16) -> Option<bf16> {
+        if mantissa > 0x007F {
+            None
+        } else {
+            Some(bf16(((sign as u16) << 15) | ((exp as u16) << 7) | mantissa))
+        }
+    }
+
     /// Returns the memory representation of the underlying bit representation as a byte array in
     /// little-endian byte order
     ///
@@ -182,200 +582,1456 @@ impl bf16 {
     ///
     /// This conversion is lossless as all values can be represented exactly in [`f64`].
     #[inline]
-    pub fn to_f64(self) -> f64 {
-        convert::bf16_to_f64(self.0)
+    pub fn to_f64(self) -> f64 {
+        convert::bf16_to_f64(self.0)
+    }
+
+    /// Converts a [`bf16`] value into a [`f16`] value
+    ///
+    /// This rounds to the nearest [`f16`] value, ties to even, exactly as converting through
+    /// [`f32`] would, but without the intermediate step. Normal [`bf16`] values within the range of
+    /// [`f16`] convert exactly, since [`f16`] has more fraction bits. Values beyond [`f16::MAX`]
+    /// round to ±∞ once they reach 65520, and values below [`f16::MIN_POSITIVE`] round to
+    /// [`f16`] subnormals or to a zero of the same sign. NaN values stay NaN and keep their
+    /// payload.
+    ///
+    /// Use [`HalfFloatSliceExt::convert_from_bf16_slice`][crate::slice::HalfFloatSliceExt::convert_from_bf16_slice]
+    /// to convert whole slices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(1.5).to_f16(), f16::from_f32(1.5));
+    /// assert_eq!(bf16::from_f32(65536.0).to_f16(), f16::INFINITY);
+    /// assert_eq!(bf16::from_f64(2f64.powi(-26)).to_f16().to_bits(), 0);
+    /// assert_eq!(bf16::from_f64(3.0 * 2f64.powi(-26)).to_f16(), f16::MIN_POSITIVE_SUBNORMAL);
+    /// ```
+    #[inline]
+    pub fn to_f16(self) -> f16 {
+        f16::from_bits(convert::bf16_to_f16(self.0))
+    }
+
+    /// Converts a [`bf16`] value into a `f32` value, treating subnormal values as zero
+    ///
+    /// This emulates hardware running in denormals-are-zero (DAZ) mode. Subnormal values convert
+    /// to a zero of the same sign; all other values convert exactly like
+    /// [`to_f32`][Self::to_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_f32_daz().to_bits(), 0.0f32.to_bits());
+    /// assert_eq!((-bf16::MAX_SUBNORMAL).to_f32_daz().to_bits(), (-0.0f32).to_bits());
+    /// assert_eq!(bf16::MIN_POSITIVE.to_f32_daz(), bf16::MIN_POSITIVE.to_f32());
+    /// ```
+    #[inline]
+    pub fn to_f32_daz(self) -> f32 {
+        self.flush_subnormal().to_f32()
+    }
+
+    /// Converts a [`bf16`] value into a `f64` value, treating subnormal values as zero
+    ///
+    /// This emulates hardware running in denormals-are-zero (DAZ) mode. Subnormal values convert
+    /// to a zero of the same sign; all other values convert exactly like
+    /// [`to_f64`][Self::to_f64].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!((-bf16::MIN_POSITIVE_SUBNORMAL).to_f64_daz().to_bits(), (-0.0f64).to_bits());
+    /// assert_eq!(bf16::ONE.to_f64_daz(), 1.0);
+    /// ```
+    #[inline]
+    pub fn to_f64_daz(self) -> f64 {
+        self.flush_subnormal().to_f64()
+    }
+
+    /// Replaces a subnormal value with a zero of the same sign
+    ///
+    /// All other values, including zeros, infinities and NaN, are returned unchanged. This can
+    /// be used to emulate flush-to-zero hardware on the inputs or results of any operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::MAX_SUBNORMAL.flush_subnormal().to_bits(), bf16::ZERO.to_bits());
+    /// assert_eq!((-bf16::MAX_SUBNORMAL).flush_subnormal().to_bits(), bf16::NEG_ZERO.to_bits());
+    /// assert_eq!(bf16::MIN_POSITIVE.flush_subnormal(), bf16::MIN_POSITIVE);
+    /// ```
+    #[inline]
+    pub const fn flush_subnormal(self) -> bf16 {
+        if self.0 & 0x7F80u16 == 0 {
+            bf16(self.0 & 0x8000u16)
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let nan = bf16::NAN;
+    /// let f = bf16::from_f32(7.0_f32);
+    ///
+    /// assert!(nan.is_nan());
+    /// assert!(!f.is_nan());
+    /// ```
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7FFFu16 > 0x7F80u16
+    }
+
+    /// Constructs a positive quiet NaN carrying `payload`
+    ///
+    /// The payload is the 6 trailing significand bits below the quiet bit, so only the low 6
+    /// bits of `payload` are used. Conversions to wider formats and back keep the payload, as
+    /// described in the [crate documentation](crate).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let nan = bf16::nan_with_payload(5);
+    ///
+    /// assert!(nan.is_nan() && !nan.is_signaling_nan());
+    /// assert_eq!(nan.payload(), Some(5));
+    /// assert_eq!(bf16::from_f32(nan.to_f32()).to_bits(), nan.to_bits());
+    /// assert_eq!(nan.to_f32().to_bits(), 0x7FC5_0000);
+    /// ```
+    #[inline]
+    pub const fn nan_with_payload(payload: u16) -> bf16 {
+        bf16(0x7F80u16 | 0x0040u16 | (payload & 0x003Fu16))
+    }
+
+    /// Returns the payload of a NaN, or `None` if `self` is not NaN
+    ///
+    /// The payload excludes the sign and the quiet bit, so a signaling NaN and the quiet NaN that
+    /// [`make_quiet`][Self::make_quiet] makes from it have the same payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::NAN.payload(), Some(0));
+    /// assert_eq!((-bf16::nan_with_payload(3)).payload(), Some(3));
+    /// assert_eq!(bf16::INFINITY.payload(), None);
+    /// ```
+    #[inline]
+    pub const fn payload(self) -> Option<u16> {
+        if self.is_nan() {
+            Some(self.0 & 0x003Fu16)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `self` is a signaling NaN
+    ///
+    /// Signaling NaNs have the most significant significand bit clear. Arithmetic on them raises
+    /// the `invalid` exception flag and produces a quiet NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let snan = bf16::from_bits(0x7F81);
+    ///
+    /// assert!(snan.is_signaling_nan());
+    /// assert!(!bf16::NAN.is_signaling_nan());
+    /// assert!(!bf16::INFINITY.is_signaling_nan());
+    /// ```
+    #[inline]
+    pub const fn is_signaling_nan(self) -> bool {
+        self.is_nan() && self.0 & 0x0040u16 == 0
+    }
+
+    /// Converts a signaling NaN into a quiet NaN with the same sign and payload
+    ///
+    /// All other values, including quiet NaNs, are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let snan = bf16::from_bits(0x7F81);
+    ///
+    /// assert!(!snan.make_quiet().is_signaling_nan());
+    /// assert_eq!(snan.make_quiet().payload(), snan.payload());
+    /// assert_eq!(bf16::ONE.make_quiet(), bf16::ONE);
+    /// ```
+    #[inline]
+    pub const fn make_quiet(self) -> bf16 {
+        if self.is_nan() {
+            bf16(self.0 | 0x0040u16)
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if this value is ±∞ and `false` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let f = bf16::from_f32(7.0f32);
+    /// let inf = bf16::INFINITY;
+    /// let neg_inf = bf16::NEG_INFINITY;
+    /// let nan = bf16::NAN;
+    ///
+    /// assert!(!f.is_infinite());
+    /// assert!(!nan.is_infinite());
+    ///
+    /// assert!(inf.is_infinite());
+    /// assert!(neg_inf.is_infinite());
+    /// ```
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7FFFu16 == 0x7F80u16
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let f = bf16::from_f32(7.0f32);
+    /// let inf = bf16::INFINITY;
+    /// let neg_inf = bf16::NEG_INFINITY;
+    /// let nan = bf16::NAN;
+    ///
+    /// assert!(f.is_finite());
+    ///
+    /// assert!(!nan.is_finite());
+    /// assert!(!inf.is_finite());
+    /// assert!(!neg_inf.is_finite());
+    /// ```
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.0 & 0x7F80u16 != 0x7F80u16
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let min = bf16::MIN_POSITIVE;
+    /// let max = bf16::MAX;
+    /// let lower_than_min = bf16::from_f32(1.0e-39_f32);
+    /// let zero = bf16::from_f32(0.0_f32);
+    ///
+    /// assert!(min.is_normal());
+    /// assert!(max.is_normal());
+    ///
+    /// assert!(!zero.is_normal());
+    /// assert!(!bf16::NAN.is_normal());
+    /// assert!(!bf16::INFINITY.is_normal());
+    /// // Values between 0 and `min` are subnormal.
+    /// assert!(!lower_than_min.is_normal());
+    /// ```
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        let exp = self.0 & 0x7F80u16;
+        exp != 0x7F80u16 && exp != 0
+    }
+
+    /// Returns the floating point category of the number
+    ///
+    /// If only one property is going to be tested, it is generally faster to use the specific
+    /// predicate instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::FpCategory;
+    /// # use half::prelude::*;
+    ///
+    /// let num = bf16::from_f32(12.4_f32);
+    /// let inf = bf16::INFINITY;
+    ///
+    /// assert_eq!(num.classify(), FpCategory::Normal);
+    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// ```
+    pub fn classify(self) -> FpCategory {
+        let exp = self.0 & 0x7F80u16;
+        let man = self.0 & 0x007Fu16;
+        match (exp, man) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (0x7F80u16, 0) => FpCategory::Infinite,
+            (0x7F80u16, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
+
+    /// Returns the IEEE 754 class of the number
+    ///
+    /// This is a finer distinction than [`classify`][Self::classify], separating signaling and
+    /// quiet NaN and the signs of all other values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::NAN.class(), FpClass::QuietNaN);
+    /// assert_eq!(bf16::NEG_ZERO.class(), FpClass::NegativeZero);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.class(), FpClass::PositiveSubnormal);
+    /// assert_eq!(bf16::MIN.class(), FpClass::NegativeNormal);
+    /// ```
+    #[inline]
+    pub const fn class(self) -> FpClass {
+        softfloat::class(softfloat::BF16, self.0 as u32)
+    }
+
+    /// Returns the lesser of two numbers, propagating NaN
+    ///
+    /// This is the IEEE 754-2019 `minimum` operation. If either operand is NaN, the result is a
+    /// quiet NaN, and `-0.0` is considered less than `+0.0`. Use [`min`][Self::min] to ignore NaN
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    ///
+    /// assert_eq!(x.minimum(bf16::from_f32(2.0)), x);
+    /// assert!(x.minimum(bf16::NAN).is_nan());
+    /// assert!(bf16::ZERO.minimum(bf16::NEG_ZERO).is_sign_negative());
+    /// ```
+    #[inline]
+    pub const fn minimum(self, other: bf16) -> bf16 {
+        bf16(softfloat::minimum(softfloat::BF16, self.0 as u32, other.0 as u32) as u16)
+    }
+
+    /// Returns the greater of two numbers, propagating NaN
+    ///
+    /// This is the IEEE 754-2019 `maximum` operation. If either operand is NaN, the result is a
+    /// quiet NaN, and `+0.0` is considered greater than `-0.0`. Use [`max`][Self::max] to ignore
+    /// NaN instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    ///
+    /// assert_eq!(x.maximum(bf16::from_f32(2.0)), bf16::from_f32(2.0));
+    /// assert!(x.maximum(bf16::NAN).is_nan());
+    /// assert!(bf16::NEG_ZERO.maximum(bf16::ZERO).is_sign_positive());
+    /// ```
+    #[inline]
+    pub const fn maximum(self, other: bf16) -> bf16 {
+        bf16(softfloat::maximum(softfloat::BF16, self.0 as u32, other.0 as u32) as u16)
+    }
+
+    /// Returns the lesser of two numbers, ignoring NaN
+    ///
+    /// This is the IEEE 754-2019 `minimumNumber` operation. If exactly one operand is NaN, the
+    /// other is returned, and if both are, the result is a quiet NaN. `-0.0` is considered less
+    /// than `+0.0`. This is the same as [`min`][Self::min].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    ///
+    /// assert_eq!(x.minimum_number(bf16::NAN), x);
+    /// assert!(bf16::NAN.minimum_number(bf16::NAN).is_nan());
+    /// ```
+    #[inline]
+    pub const fn minimum_number(self, other: bf16) -> bf16 {
+        bf16(softfloat::minimum_number(softfloat::BF16, self.0 as u32, other.0 as u32) as u16)
+    }
+
+    /// Returns the greater of two numbers, ignoring NaN
+    ///
+    /// This is the IEEE 754-2019 `maximumNumber` operation. If exactly one operand is NaN, the
+    /// other is returned, and if both are, the result is a quiet NaN. `+0.0` is considered greater
+    /// than `-0.0`. This is the same as [`max`][Self::max].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    ///
+    /// assert_eq!(bf16::NAN.maximum_number(x), x);
+    /// assert!(bf16::NAN.maximum_number(bf16::NAN).is_nan());
+    /// ```
+    #[inline]
+    pub const fn maximum_number(self, other: bf16) -> bf16 {
+        bf16(softfloat::maximum_number(softfloat::BF16, self.0 as u32, other.0 as u32) as u16)
+    }
+
+    /// Returns `true` if `self` is ordered before or equal to `other` in the IEEE 754 total order
+    ///
+    /// The total order is: negative quiet NaNs, negative signaling NaNs, −∞, negative numbers,
+    /// `-0.0`, `+0.0`, positive numbers, +∞, positive signaling NaNs and positive quiet NaNs. NaNs
+    /// of the same kind are ordered by payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert!(bf16::NEG_ZERO.total_order(bf16::ZERO));
+    /// assert!(!bf16::ZERO.total_order(bf16::NEG_ZERO));
+    /// assert!(bf16::INFINITY.total_order(bf16::NAN));
+    /// assert!((-bf16::NAN).total_order(bf16::NEG_INFINITY));
+    /// ```
+    #[inline]
+    pub const fn total_order(self, other: bf16) -> bool {
+        softfloat::total_order_key(softfloat::BF16, self.0 as u32)
+            <= softfloat::total_order_key(softfloat::BF16, other.0 as u32)
+    }
+
+    /// Returns `true` if `self.abs()` is ordered before or equal to `other.abs()` in the IEEE 754
+    /// total order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert!(bf16::ONE.total_order_mag(bf16::from_f32(-2.0)));
+    /// assert!(bf16::ZERO.total_order_mag(bf16::NEG_ZERO));
+    /// assert!(!(-bf16::NAN).total_order_mag(bf16::INFINITY));
+    /// ```
+    #[inline]
+    pub const fn total_order_mag(self, other: bf16) -> bool {
+        self.abs().total_order(other.abs())
+    }
+
+    /// Returns the ordering between `self` and `other` in the IEEE 754 total order
+    ///
+    /// Unlike the [`PartialOrd`] implementation, this orders every value, in the same order as
+    /// [`total_order`][bf16::total_order] and [`f32::total_cmp`]. Two values are only equal if they
+    /// have the same bits, so `-0.0` is less than `+0.0` and NaNs are ordered by sign and payload.
+    /// [`TotalOrd`][crate::TotalOrd] wraps a value so that it implements [`Ord`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// # use core::cmp::Ordering;
+    /// let mut values = [bf16::INFINITY, bf16::NAN, bf16::ZERO, -bf16::NAN, bf16::NEG_ZERO];
+    /// values.sort_by(bf16::total_cmp);
+    ///
+    /// assert_eq!(values.reinterpret_cast(), [0xFFC0, 0x8000, 0x0000, 0x7F80, 0x7FC0]);
+    /// assert_eq!(bf16::ONE.total_cmp(&bf16::ONE), Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn total_cmp(&self, other: &bf16) -> Ordering {
+        softfloat::total_order_key(softfloat::BF16, self.0 as u32)
+            .cmp(&softfloat::total_order_key(softfloat::BF16, other.0 as u32))
+    }
+
+    /// Returns a number that represents the sign of `self`
+    ///
+    /// * 1.0 if the number is positive, +0.0 or [`INFINITY`][bf16::INFINITY]
+    /// * −1.0 if the number is negative, −0.0` or [`NEG_INFINITY`][bf16::NEG_INFINITY]
+    /// * [`NAN`][bf16::NAN] if the number is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let f = bf16::from_f32(3.5_f32);
+    ///
+    /// assert_eq!(f.signum(), bf16::from_f32(1.0));
+    /// assert_eq!(bf16::NEG_INFINITY.signum(), bf16::from_f32(-1.0));
+    ///
+    /// assert!(bf16::NAN.signum().is_nan());
+    /// ```
+    pub fn signum(self) -> bf16 {
+        if self.is_nan() {
+            self
+        } else if self.0 & 0x8000u16 != 0 {
+            bf16::from_f32(-1.0)
+        } else {
+            bf16::from_f32(1.0)
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including +0.0, NaNs with a
+    /// positive sign bit and +∞
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let nan = bf16::NAN;
+    /// let f = bf16::from_f32(7.0_f32);
+    /// let g = bf16::from_f32(-7.0_f32);
+    ///
+    /// assert!(f.is_sign_positive());
+    /// assert!(!g.is_sign_positive());
+    /// // NaN can be either positive or negative
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & 0x8000u16 == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including −0.0, NaNs with a
+    /// negative sign bit and −∞
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    ///
+    /// let nan = bf16::NAN;
+    /// let f = bf16::from_f32(7.0f32);
+    /// let g = bf16::from_f32(-7.0f32);
+    ///
+    /// assert!(!f.is_sign_negative());
+    /// assert!(g.is_sign_negative());
+    /// // NaN can be either positive or negative
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & 0x8000u16 != 0
+    }
+
+    /// Computes the absolute value of `self`
+    ///
+    /// This only clears the sign bit, so it is exact and also applies to NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-3.5).abs(), bf16::from_f32(3.5));
+    /// assert!(bf16::NEG_ZERO.abs().is_sign_positive());
+    /// ```
+    #[inline]
+    pub const fn abs(self) -> bf16 {
+        bf16(self.0 & 0x7FFFu16)
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of `sign`
+    ///
+    /// If `self` is NaN, a NaN with the sign bit of `sign` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let f = bf16::from_f32(3.5);
+    ///
+    /// assert_eq!(f.copysign(bf16::NEG_ZERO), bf16::from_f32(-3.5));
+    /// assert_eq!((-f).copysign(bf16::ZERO), f);
+    /// ```
+    #[inline]
+    pub const fn copysign(self, sign: bf16) -> bf16 {
+        bf16((self.0 & 0x7FFFu16) | (sign.0 & 0x8000u16))
+    }
+
+    /// Returns the minimum of the two numbers, ignoring NaN
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned. `-0.0` is considered
+    /// less than `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    /// let y = bf16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.min(y), x);
+    /// assert_eq!(x.min(bf16::NAN), x);
+    /// ```
+    #[inline]
+    pub const fn min(self, other: bf16) -> bf16 {
+        self.minimum_number(other)
+    }
+
+    /// Returns the maximum of the two numbers, ignoring NaN
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned. `+0.0` is considered
+    /// greater than `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    /// let y = bf16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.max(y), y);
+    /// assert_eq!(x.max(bf16::NAN), x);
+    /// ```
+    #[inline]
+    pub const fn max(self, other: bf16) -> bf16 {
+        self.maximum_number(other)
+    }
+
+    /// Restrict a value to a certain interval unless it is NaN
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is less than `min`.
+    /// Otherwise this returns `self`. Returns NaN if the initial value was NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let min = bf16::from_f32(-2.0);
+    /// let max = bf16::ONE;
+    ///
+    /// assert_eq!(bf16::from_f32(-3.0).clamp(min, max), min);
+    /// assert_eq!(bf16::ZERO.clamp(min, max), bf16::ZERO);
+    /// assert_eq!(bf16::from_f32(2.0).clamp(min, max), max);
+    /// assert!(bf16::NAN.clamp(min, max).is_nan());
+    /// ```
+    #[inline]
+    pub fn clamp(self, min: bf16, max: bf16) -> bf16 {
+        assert!(
+            min <= max,
+            "min > max, or either was NaN. min = {:?}, max = {:?}",
+            min,
+            max
+        );
+        let mut x = self;
+        if x < min {
+            x = min;
+        }
+        if x > max {
+            x = max;
+        }
+        x
+    }
+
+    /// Returns the largest integer less than or equal to `self`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(3.7).floor(), bf16::from_f32(3.0));
+    /// assert_eq!(bf16::from_f32(-3.7).floor(), bf16::from_f32(-4.0));
+    /// ```
+    #[inline]
+    pub const fn floor(self) -> bf16 {
+        self.round_to_integral(RoundingMode::TowardNegative)
+    }
+
+    /// Returns the smallest integer greater than or equal to `self`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(3.01).ceil(), bf16::from_f32(4.0));
+    /// assert_eq!(bf16::from_f32(-0.5).ceil(), bf16::NEG_ZERO);
+    /// ```
+    #[inline]
+    pub const fn ceil(self) -> bf16 {
+        self.round_to_integral(RoundingMode::TowardPositive)
+    }
+
+    /// Returns the nearest integer to `self`, rounding half-way cases away from `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(2.5).round(), bf16::from_f32(3.0));
+    /// assert_eq!(bf16::from_f32(-2.5).round(), bf16::from_f32(-3.0));
+    /// ```
+    #[inline]
+    pub const fn round(self) -> bf16 {
+        self.round_to_integral(RoundingMode::NearestTiesToAway)
+    }
+
+    /// Returns the nearest integer to `self`, rounding half-way cases to the even integer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(2.5).round_ties_even(), bf16::from_f32(2.0));
+    /// assert_eq!(bf16::from_f32(3.5).round_ties_even(), bf16::from_f32(4.0));
+    /// ```
+    #[inline]
+    pub const fn round_ties_even(self) -> bf16 {
+        self.round_to_integral(RoundingMode::NearestTiesToEven)
+    }
+
+    /// Returns the integer part of `self`, rounding towards zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(3.7).trunc(), bf16::from_f32(3.0));
+    /// assert_eq!(bf16::from_f32(-3.7).trunc(), bf16::from_f32(-3.0));
+    /// ```
+    #[inline]
+    pub const fn trunc(self) -> bf16 {
+        self.round_to_integral(RoundingMode::TowardZero)
+    }
+
+    /// Rounds `self` to an integer with the given rounding mode
+    ///
+    /// [`RoundingMode::ToOdd`] rounds inexact values to the odd neighbouring integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let f = bf16::from_f32(-1.5);
+    ///
+    /// assert_eq!(f.round_to_integral(RoundingMode::TowardPositive), -bf16::ONE);
+    /// assert_eq!(f.round_to_integral(RoundingMode::NearestTiesToEven), bf16::from_f32(-2.0));
+    /// ```
+    #[inline]
+    pub const fn round_to_integral(self, mode: RoundingMode) -> bf16 {
+        bf16(softfloat::round_to_integral(softfloat::BF16, self.0 as u32, mode) as u16)
+    }
+
+    /// Returns the fractional part of `self`
+    ///
+    /// The result is exact and has the sign of `self`, or is `+0.0` if `self` is an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(3.75).fract(), bf16::from_f32(0.75));
+    /// assert_eq!(bf16::from_f32(-3.75).fract(), bf16::from_f32(-0.75));
+    /// ```
+    #[inline]
+    pub fn fract(self) -> bf16 {
+        self - self.trunc()
+    }
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`, correctly rounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(4.0).recip(), bf16::from_f32(0.25));
+    /// assert_eq!(bf16::NEG_ZERO.recip(), bf16::NEG_INFINITY);
+    /// ```
+    #[inline]
+    pub fn recip(self) -> bf16 {
+        bf16::ONE / self
+    }
+
+    /// Converts radians to degrees, correctly rounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::PI.to_degrees(), bf16::from_f32(180.0));
+    /// ```
+    #[inline]
+    pub fn to_degrees(self) -> bf16 {
+        bf16::from_f64(self.to_f64() * (180.0 / core::f64::consts::PI))
+    }
+
+    /// Converts degrees to radians, correctly rounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(180.0).to_radians(), bf16::PI);
+    /// ```
+    #[inline]
+    pub fn to_radians(self) -> bf16 {
+        bf16::from_f64(self.to_f64() * (core::f64::consts::PI / 180.0))
+    }
+
+    /// Returns the least number greater than `self`
+    ///
+    /// This is the IEEE 754 `nextUp` operation. ±0 steps to
+    /// [`MIN_POSITIVE_SUBNORMAL`][bf16::MIN_POSITIVE_SUBNORMAL], [`MAX`][bf16::MAX] to +∞ and −∞ to
+    /// [`MIN`][bf16::MIN]. +∞ is returned unchanged and NaN is returned as a quiet NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.next_up(), bf16::ONE + bf16::EPSILON);
+    /// assert_eq!(bf16::NEG_ZERO.next_up(), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::MAX.next_up(), bf16::INFINITY);
+    /// ```
+    #[inline]
+    pub const fn next_up(self) -> bf16 {
+        if self.is_nan() {
+            self.make_quiet()
+        } else if self.0 == 0x7F80u16 {
+            self
+        } else if self.0 & 0x7FFFu16 == 0 {
+            bf16(1)
+        } else if self.0 & 0x8000u16 != 0 {
+            bf16(self.0 - 1)
+        } else {
+            bf16(self.0 + 1)
+        }
+    }
+
+    /// Returns the greatest number less than `self`
+    ///
+    /// This is the IEEE 754 `nextDown` operation. ±0 steps to the negative of
+    /// [`MIN_POSITIVE_SUBNORMAL`][bf16::MIN_POSITIVE_SUBNORMAL], [`MIN`][bf16::MIN] to −∞ and +∞ to
+    /// [`MAX`][bf16::MAX]. −∞ is returned unchanged and NaN is returned as a quiet NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.next_down(), bf16::ONE - bf16::EPSILON / bf16::from_f32(2.0));
+    /// assert_eq!(bf16::ZERO.next_down(), -bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::INFINITY.next_down(), bf16::MAX);
+    /// ```
+    #[inline]
+    pub const fn next_down(self) -> bf16 {
+        if self.is_nan() {
+            self.make_quiet()
+        } else if self.0 == 0xFF80u16 {
+            self
+        } else if self.0 & 0x7FFFu16 == 0 {
+            bf16(0x8001u16)
+        } else if self.0 & 0x8000u16 != 0 {
+            bf16(self.0 + 1)
+        } else {
+            bf16(self.0 - 1)
+        }
+    }
+
+    /// Returns the next representable number after `self` in the direction of `toward`
+    ///
+    /// Returns `toward` if the two are equal, so that stepping from `+0.0` toward `-0.0` gives
+    /// `-0.0`, and a quiet NaN if either is NaN. This is C's `nextafter`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::ONE;
+    ///
+    /// assert_eq!(x.next_after(bf16::INFINITY), x.next_up());
+    /// assert_eq!(x.next_after(bf16::ZERO), x.next_down());
+    /// assert_eq!(x.next_after(x), x);
+    /// assert!(bf16::ZERO.next_after(bf16::NEG_ZERO).is_sign_negative());
+    /// ```
+    #[inline]
+    pub const fn next_after(self, toward: bf16) -> bf16 {
+        if self.is_nan() {
+            self.make_quiet()
+        } else if toward.is_nan() {
+            toward.make_quiet()
+        } else if self.0 == toward.0 || (self.0 | toward.0) & 0x7FFFu16 == 0 {
+            toward
+        } else if self.total_order(toward) {
+            self.next_up()
+        } else {
+            self.next_down()
+        }
+    }
+
+    /// Returns the unit in the last place of the number
+    ///
+    /// This is the positive distance from `self` to the next number of greater magnitude, or to
+    /// the number that would follow [`MAX`][bf16::MAX] if the exponent range were unbounded. The ULP
+    /// of zero and subnormal numbers is [`MIN_POSITIVE_SUBNORMAL`][bf16::MIN_POSITIVE_SUBNORMAL].
+    /// ±∞ returns +∞ and NaN returns a quiet NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.ulp(), bf16::EPSILON);
+    /// assert_eq!(bf16::from_f32(-1.5).ulp(), bf16::EPSILON);
+    /// assert_eq!(bf16::ZERO.ulp(), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::MIN_POSITIVE.ulp(), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// ```
+    #[inline]
+    pub const fn ulp(self) -> bf16 {
+        if self.is_nan() {
+            return self.make_quiet();
+        }
+        let exp = (self.0 & 0x7F80u16) >> 7;
+        if exp == 0xFF {
+            bf16(0x7F80u16)
+        } else if exp > 7 {
+            bf16((exp - 7) << 7)
+        } else if exp > 1 {
+            bf16(1 << (exp - 1))
+        } else {
+            bf16(1)
+        }
+    }
+
+    /// Returns the number of representable values between `self` and `other`
+    ///
+    /// Adjacent numbers are 1 apart, and `-0.0` and `+0.0` count as a single value, so the
+    /// distance is 0 if the two are equal. ±∞ is one step beyond [`MAX`][bf16::MAX] and
+    /// [`MIN`][bf16::MIN]. Two NaNs are 0 apart, and NaN is [`u32::MAX`] apart from any other
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1.0);
+    ///
+    /// assert_eq!(x.ulp_distance(x.next_up().next_up()), 2);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.ulp_distance(-bf16::MIN_POSITIVE_SUBNORMAL), 2);
+    /// assert_eq!(bf16::NEG_ZERO.ulp_distance(bf16::ZERO), 0);
+    /// assert_eq!(x.ulp_distance(bf16::NAN), u32::MAX);
+    /// ```
+    #[inline]
+    pub const fn ulp_distance(self, other: bf16) -> u32 {
+        softfloat::ulp_distance(softfloat::BF16, self.0 as u32, other.0 as u32)
+    }
+
+    /// Breaks the number into a normalized fraction and an integral power of two
+    ///
+    /// Returns `(m, e)` such that `self` is exactly `m * 2^e`, with the magnitude of `m` in
+    /// `[0.5, 1)`. Subnormal numbers are normalized. Zeros, infinities and NaN are returned
+    /// unchanged with an exponent of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-12.0).frexp(), (bf16::from_f32(-0.75), 4));
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.frexp(), (bf16::from_f32(0.5), -132));
+    /// assert_eq!(bf16::ZERO.frexp(), (bf16::ZERO, 0));
+    /// ```
+    #[inline]
+    pub const fn frexp(self) -> (bf16, i32) {
+        if self.0 & 0x7FFFu16 == 0 || self.0 & 0x7F80u16 == 0x7F80u16 {
+            return (self, 0);
+        }
+        let exp = self.ilogb();
+        let man = if self.0 & 0x7F80u16 == 0 {
+            // Shift the leading bit of a subnormal into the implicit position
+            (self.0 << (-126 - exp)) & 0x007Fu16
+        } else {
+            self.0 & 0x007Fu16
+        };
+        (bf16((self.0 & 0x8000u16) | (126 << 7) | man), exp + 1)
+    }
+
+    /// Multiplies the number by `2^exp`, correctly rounded
+    ///
+    /// The result is exact unless it overflows to ±∞ or is subnormal and loses low bits, in which
+    /// case it is rounded to nearest, ties to even. This is the inverse of [`frexp`][Self::frexp].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-0.75).ldexp(4), bf16::from_f32(-12.0));
+    /// assert_eq!(bf16::from_f32(0.5).ldexp(-132), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::MAX.ldexp(1), bf16::INFINITY);
+    /// ```
+    #[inline]
+    pub fn ldexp(self, exp: i32) -> bf16 {
+        bf16(softfloat::scalbn(softfloat::BF16, self.0 as u32, exp, &mut Status::default()) as u16)
+    }
+
+    /// Multiplies the number by `2^n`, correctly rounded
+    ///
+    /// This is the IEEE 754 `scaleB` operation, and is the same as [`ldexp`][Self::ldexp].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(3.0).scalbn(-1), bf16::from_f32(1.5));
+    /// ```
+    #[inline]
+    pub fn scalbn(self, n: i32) -> bf16 {
+        self.ldexp(n)
+    }
+
+    /// Returns the exponent of the number as an integer
+    ///
+    /// This is the unbiased exponent of the number when normalized, so `2^ilogb(x) <= |x| <
+    /// 2^(ilogb(x) + 1)`, including for subnormal numbers. Zero and NaN return [`i32::MIN`] and
+    /// ±∞ returns [`i32::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-12.0).ilogb(), 3);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.ilogb(), -133);
+    /// assert_eq!(bf16::ZERO.ilogb(), i32::MIN);
+    /// assert_eq!(bf16::NEG_INFINITY.ilogb(), i32::MAX);
+    /// ```
+    #[inline]
+    pub const fn ilogb(self) -> i32 {
+        let exp = ((self.0 & 0x7F80u16) >> 7) as i32;
+        let man = (self.0 & 0x007Fu16) as u32;
+        if exp == 0xFF {
+            if man == 0 {
+                i32::MAX
+            } else {
+                i32::MIN
+            }
+        } else if exp == 0 {
+            if man == 0 {
+                i32::MIN
+            } else {
+                31 - man.leading_zeros() as i32 - 133
+            }
+        } else {
+            exp - 127
+        }
+    }
+
+    /// Returns the exponent of the number as a floating point value
+    ///
+    /// This is [`ilogb`][Self::ilogb] as a [`bf16`], except that zero returns −∞, ±∞ returns +∞ and NaN
+    /// returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-12.0).logb(), bf16::from_f32(3.0));
+    /// assert_eq!(bf16::NEG_ZERO.logb(), bf16::NEG_INFINITY);
+    /// assert_eq!(bf16::NEG_INFINITY.logb(), bf16::INFINITY);
+    /// ```
+    #[inline]
+    pub fn logb(self) -> bf16 {
+        if self.is_nan() {
+            self.make_quiet()
+        } else if self.is_infinite() {
+            bf16::INFINITY
+        } else if self.0 & 0x7FFFu16 == 0 {
+            bf16::NEG_INFINITY
+        } else {
+            bf16::from_f32(self.ilogb() as f32)
+        }
+    }
+
+    /// Returns the mantissa, base 2 exponent and sign of the number as integers
+    ///
+    /// The original number is `sign * mantissa * 2^exponent`. The mantissa includes the implicit
+    /// leading bit of normal numbers, as with `f32` in `num-traits`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let (mantissa, exponent, sign) = bf16::from_f32(-12.0).integer_decode();
+    ///
+    /// assert_eq!((mantissa, exponent, sign), (0xC0, -4, -1));
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.integer_decode(), (2, -133 - 1, 1));
+    /// ```
+    #[inline]
+    pub const fn integer_decode(self) -> (u64, i16, i8) {
+        let exp = ((self.0 & 0x7F80u16) >> 7) as i16;
+        let mantissa = if exp == 0 {
+            (self.0 & 0x007Fu16) << 1
+        } else {
+            (self.0 & 0x007Fu16) | 0x0080u16
+        };
+        let sign = if self.0 & 0x8000u16 != 0 { -1 } else { 1 };
+        (mantissa as u64, exp - (127 + 7), sign)
+    }
+
+    /// Returns the square root of the number, correctly rounded
+    ///
+    /// Returns NaN if `self` is a negative number other than `-0.0`. The result is computed
+    /// directly on the 16-bit representation and is identical on every platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let f = bf16::from_f32(4.0);
+    ///
+    /// assert_eq!(f.sqrt(), bf16::from_f32(2.0));
+    /// assert!(bf16::from_f32(-1.0).sqrt().is_nan());
+    /// assert_eq!(bf16::NEG_ZERO.sqrt(), bf16::NEG_ZERO);
+    /// ```
+    #[inline]
+    pub fn sqrt(self) -> bf16 {
+        self.sqrt_with_status().0
+    }
+
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error, yielding a
+    /// more accurate result than an unfused multiply-add
+    ///
+    /// The result is computed directly on the 16-bit representation and is identical on every
+    /// platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let m = bf16::from_f32(10.0);
+    /// let x = bf16::from_f32(4.0);
+    /// let b = bf16::from_f32(60.0);
+    ///
+    /// assert_eq!(m.mul_add(x, b), bf16::from_f32(100.0));
+    /// ```
+    #[inline]
+    pub fn mul_add(self, a: bf16, b: bf16) -> bf16 {
+        self.mul_add_with_status(a, b).0
+    }
+
+    /// Returns `e^(self)`, the exponential function, correctly rounded
+    ///
+    /// Like the other elementary functions of this type, the result is the exact value rounded
+    /// once to nearest, identical on every platform and available without the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.exp(), bf16::E);
+    /// assert_eq!(bf16::NEG_INFINITY.exp(), bf16::ZERO);
+    /// ```
+    #[inline]
+    pub fn exp(self) -> bf16 {
+        bf16(softfloat::math::exp(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Returns `2^(self)`, correctly rounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-3.0).exp2(), bf16::from_f32(0.125));
+    /// assert_eq!(bf16::from_f32(0.5).exp2(), bf16::SQRT_2);
+    /// ```
+    #[inline]
+    pub fn exp2(self) -> bf16 {
+        bf16(softfloat::math::exp2(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Returns the natural logarithm of the number, correctly rounded
+    ///
+    /// Returns −∞ for `±0.0` and NaN for negative numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::E.ln(), bf16::ONE);
+    /// assert_eq!(bf16::ZERO.ln(), bf16::NEG_INFINITY);
+    /// assert!(bf16::from_f32(-1.0).ln().is_nan());
+    /// ```
+    #[inline]
+    pub fn ln(self) -> bf16 {
+        bf16(softfloat::math::ln(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Returns the base 2 logarithm of the number, correctly rounded
+    ///
+    /// Returns −∞ for `±0.0` and NaN for negative numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(1024.0).log2(), bf16::from_f32(10.0));
+    /// assert_eq!(bf16::from_f32(0.125).log2(), bf16::from_f32(-3.0));
+    /// ```
+    #[inline]
+    pub fn log2(self) -> bf16 {
+        bf16(softfloat::math::log2(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Returns the base 10 logarithm of the number, correctly rounded
+    ///
+    /// Returns −∞ for `±0.0` and NaN for negative numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(1000.0).log10(), bf16::from_f32(3.0));
+    /// assert_eq!(bf16::E.log10(), bf16::LOG10_E);
+    /// ```
+    #[inline]
+    pub fn log10(self) -> bf16 {
+        bf16(softfloat::math::log10(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Computes the sine of a number in radians, correctly rounded
+    ///
+    /// Arguments of any size are reduced exactly, so the result stays correctly rounded for large
+    /// arguments too. Returns NaN for ±∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::FRAC_PI_2.sin(), bf16::ONE);
+    /// assert_eq!(bf16::NEG_ZERO.sin(), bf16::NEG_ZERO);
+    /// assert!(bf16::INFINITY.sin().is_nan());
+    /// ```
+    #[inline]
+    pub fn sin(self) -> bf16 {
+        bf16(softfloat::math::sin(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Computes the cosine of a number in radians, correctly rounded
+    ///
+    /// Returns NaN for ±∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ZERO.cos(), bf16::ONE);
+    /// assert_eq!(bf16::PI.cos(), -bf16::ONE);
+    /// ```
+    #[inline]
+    pub fn cos(self) -> bf16 {
+        bf16(softfloat::math::cos(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Computes the tangent of a number in radians, correctly rounded
+    ///
+    /// Returns NaN for ±∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(0.5);
+    ///
+    /// assert_eq!(x.tan(), bf16::from_f64(0.5f64.tan()));
+    /// assert_eq!(bf16::NEG_ZERO.tan(), bf16::NEG_ZERO);
+    /// ```
+    #[inline]
+    pub fn tan(self) -> bf16 {
+        bf16(softfloat::math::tan(softfloat::BF16, self.0 as u32) as u16)
+    }
+
+    /// Computes the hyperbolic tangent of a number, correctly rounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(20.0).tanh(), bf16::ONE);
+    /// assert_eq!(bf16::NEG_INFINITY.tanh(), -bf16::ONE);
+    /// ```
+    #[inline]
+    pub fn tanh(self) -> bf16 {
+        bf16(softfloat::math::tanh(softfloat::BF16, self.0 as u32) as u16)
     }
 
-    /// Returns `true` if this value is NaN and `false` otherwise
+    /// Returns the cube root of the number, correctly rounded
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
-    ///
-    /// let nan = bf16::NAN;
-    /// let f = bf16::from_f32(7.0_f32);
-    ///
-    /// assert!(nan.is_nan());
-    /// assert!(!f.is_nan());
+    /// assert_eq!(bf16::from_f32(-27.0).cbrt(), bf16::from_f32(-3.0));
+    /// assert_eq!(bf16::INFINITY.cbrt(), bf16::INFINITY);
     /// ```
     #[inline]
-    pub const fn is_nan(self) -> bool {
-        self.0 & 0x7FFFu16 > 0x7F80u16
+    pub fn cbrt(self) -> bf16 {
+        bf16(softfloat::math::cbrt(softfloat::BF16, self.0 as u32) as u16)
     }
 
-    /// Returns `true` if this value is ±∞ and `false` otherwise
+    /// Raises a number to a floating point power, correctly rounded
+    ///
+    /// Special cases follow IEEE 754 `pow`: `x.powf(±0.0)` and `1.0.powf(y)` are `1.0` even for NaN,
+    /// and negative numbers raised to a power that is not an integer are NaN. Exact results that lie
+    /// halfway between two representable values round to even.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(2.0).powf(bf16::from_f32(10.0)), bf16::from_f32(1024.0));
+    /// assert_eq!(bf16::NAN.powf(bf16::ZERO), bf16::ONE);
+    /// assert!(bf16::from_f32(-2.0).powf(bf16::from_f32(0.5)).is_nan());
+    /// ```
+    #[inline]
+    pub fn powf(self, n: bf16) -> bf16 {
+        bf16(softfloat::math::pow(softfloat::BF16, self.0 as u32, n.0 as u32) as u16)
+    }
+
+    /// Computes `sqrt(self² + other²)` without intermediate overflow or underflow, correctly rounded
     ///
-    /// let f = bf16::from_f32(7.0f32);
-    /// let inf = bf16::INFINITY;
-    /// let neg_inf = bf16::NEG_INFINITY;
-    /// let nan = bf16::NAN;
+    /// Returns +∞ if either argument is infinite, even if the other is NaN.
     ///
-    /// assert!(!f.is_infinite());
-    /// assert!(!nan.is_infinite());
+    /// # Examples
     ///
-    /// assert!(inf.is_infinite());
-    /// assert!(neg_inf.is_infinite());
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(3.0).hypot(bf16::from_f32(-4.0)), bf16::from_f32(5.0));
+    /// assert_eq!(bf16::MAX.hypot(bf16::ONE), bf16::MAX);
     /// ```
     #[inline]
-    pub const fn is_infinite(self) -> bool {
-        self.0 & 0x7FFFu16 == 0x7F80u16
+    pub fn hypot(self, other: bf16) -> bf16 {
+        bf16(softfloat::math::hypot(softfloat::BF16, self.0 as u32, other.0 as u32) as u16)
     }
 
-    /// Returns `true` if this number is neither infinite nor NaN
+    /// Computes `self + rhs`, correctly rounded, and reports IEEE exceptions
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
+    /// let (x, status) = bf16::MAX.add_with_status(bf16::MAX);
     ///
-    /// let f = bf16::from_f32(7.0f32);
-    /// let inf = bf16::INFINITY;
-    /// let neg_inf = bf16::NEG_INFINITY;
-    /// let nan = bf16::NAN;
-    ///
-    /// assert!(f.is_finite());
-    ///
-    /// assert!(!nan.is_finite());
-    /// assert!(!inf.is_finite());
-    /// assert!(!neg_inf.is_finite());
+    /// assert_eq!(x, bf16::INFINITY);
+    /// assert!(status.overflow && status.inexact);
+    /// assert!(bf16::INFINITY.add_with_status(bf16::NEG_INFINITY).1.invalid);
     /// ```
     #[inline]
-    pub const fn is_finite(self) -> bool {
-        self.0 & 0x7F80u16 != 0x7F80u16
+    pub fn add_with_status(self, rhs: bf16) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::add(softfloat::BF16, self.0 as u32, rhs.0 as u32, &mut status);
+        (bf16(bits as u16), status)
     }
 
-    /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN
+    /// Computes `self - rhs`, correctly rounded, and reports IEEE exceptions
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
+    /// let (x, status) = bf16::ONE.sub_with_status(bf16::ONE);
     ///
-    /// let min = bf16::MIN_POSITIVE;
-    /// let max = bf16::MAX;
-    /// let lower_than_min = bf16::from_f32(1.0e-39_f32);
-    /// let zero = bf16::from_f32(0.0_f32);
+    /// assert_eq!(x, bf16::ZERO);
+    /// assert!(status.is_empty());
+    /// ```
+    #[inline]
+    pub fn sub_with_status(self, rhs: bf16) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::sub(softfloat::BF16, self.0 as u32, rhs.0 as u32, &mut status);
+        (bf16(bits as u16), status)
+    }
+
+    /// Computes `self * rhs`, correctly rounded, and reports IEEE exceptions
     ///
-    /// assert!(min.is_normal());
-    /// assert!(max.is_normal());
+    /// # Examples
     ///
-    /// assert!(!zero.is_normal());
-    /// assert!(!bf16::NAN.is_normal());
-    /// assert!(!bf16::INFINITY.is_normal());
-    /// // Values between 0 and `min` are subnormal.
-    /// assert!(!lower_than_min.is_normal());
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let (x, status) = bf16::MIN_POSITIVE.mul_with_status(bf16::from_f32(0.5));
+    ///
+    /// assert!(!x.is_normal() && x != bf16::ZERO);
+    /// assert!(!status.underflow && !status.inexact);
     /// ```
     #[inline]
-    pub fn is_normal(self) -> bool {
-        let exp = self.0 & 0x7F80u16;
-        exp != 0x7F80u16 && exp != 0
+    pub fn mul_with_status(self, rhs: bf16) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::mul(softfloat::BF16, self.0 as u32, rhs.0 as u32, &mut status);
+        (bf16(bits as u16), status)
     }
 
-    /// Returns the floating point category of the number
-    ///
-    /// If only one property is going to be tested, it is generally faster to use the specific
-    /// predicate instead.
+    /// Computes `self / rhs`, correctly rounded, and reports IEEE exceptions
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use std::num::FpCategory;
     /// # use half::prelude::*;
+    /// let (x, status) = bf16::ONE.div_with_status(bf16::ZERO);
     ///
-    /// let num = bf16::from_f32(12.4_f32);
-    /// let inf = bf16::INFINITY;
-    ///
-    /// assert_eq!(num.classify(), FpCategory::Normal);
-    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// assert_eq!(x, bf16::INFINITY);
+    /// assert!(status.division_by_zero && !status.invalid);
+    /// assert!(bf16::ONE.div_with_status(bf16::from_f32(3.0)).1.inexact);
     /// ```
-    pub fn classify(self) -> FpCategory {
-        let exp = self.0 & 0x7F80u16;
-        let man = self.0 & 0x007Fu16;
-        match (exp, man) {
-            (0, 0) => FpCategory::Zero,
-            (0, _) => FpCategory::Subnormal,
-            (0x7F80u16, 0) => FpCategory::Infinite,
-            (0x7F80u16, _) => FpCategory::Nan,
-            _ => FpCategory::Normal,
-        }
+    #[inline]
+    pub fn div_with_status(self, rhs: bf16) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::div(softfloat::BF16, self.0 as u32, rhs.0 as u32, &mut status);
+        (bf16(bits as u16), status)
     }
 
-    /// Returns a number that represents the sign of `self`
+    /// Computes `self % rhs` and reports IEEE exceptions
     ///
-    /// * 1.0 if the number is positive, +0.0 or [`INFINITY`][bf16::INFINITY]
-    /// * −1.0 if the number is negative, −0.0` or [`NEG_INFINITY`][bf16::NEG_INFINITY]
-    /// * [`NAN`][bf16::NAN] if the number is NaN
+    /// The remainder is always exact, so only `invalid` can be raised.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
+    /// let (x, status) = bf16::ONE.rem_with_status(bf16::ZERO);
     ///
-    /// let f = bf16::from_f32(3.5_f32);
-    ///
-    /// assert_eq!(f.signum(), bf16::from_f32(1.0));
-    /// assert_eq!(bf16::NEG_INFINITY.signum(), bf16::from_f32(-1.0));
-    ///
-    /// assert!(bf16::NAN.signum().is_nan());
+    /// assert!(x.is_nan() && status.invalid);
     /// ```
-    pub fn signum(self) -> bf16 {
-        if self.is_nan() {
-            self
-        } else if self.0 & 0x8000u16 != 0 {
-            bf16::from_f32(-1.0)
-        } else {
-            bf16::from_f32(1.0)
-        }
+    #[inline]
+    pub fn rem_with_status(self, rhs: bf16) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::rem(softfloat::BF16, self.0 as u32, rhs.0 as u32, &mut status);
+        (bf16(bits as u16), status)
     }
 
-    /// Returns `true` if and only if `self` has a positive sign, including +0.0, NaNs with a
-    /// positive sign bit and +∞
+    /// Returns the square root of the number, correctly rounded, and reports IEEE exceptions
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
-    ///
-    /// let nan = bf16::NAN;
-    /// let f = bf16::from_f32(7.0_f32);
-    /// let g = bf16::from_f32(-7.0_f32);
-    ///
-    /// assert!(f.is_sign_positive());
-    /// assert!(!g.is_sign_positive());
-    /// // NaN can be either positive or negative
-    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// assert!(bf16::from_f32(4.0).sqrt_with_status().1.is_empty());
+    /// assert!(bf16::from_f32(2.0).sqrt_with_status().1.inexact);
+    /// assert!(bf16::from_f32(-1.0).sqrt_with_status().1.invalid);
     /// ```
     #[inline]
-    pub const fn is_sign_positive(self) -> bool {
-        self.0 & 0x8000u16 == 0
+    pub fn sqrt_with_status(self) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::sqrt(softfloat::BF16, self.0 as u32, &mut status);
+        (bf16(bits as u16), status)
     }
 
-    /// Returns `true` if and only if `self` has a negative sign, including −0.0, NaNs with a
-    /// negative sign bit and −∞
+    /// Fused multiply-add with a single rounding, like [`mul_add`][Self::mul_add], that also
+    /// reports IEEE exceptions
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use half::prelude::*;
+    /// let (x, status) = bf16::INFINITY.mul_add_with_status(bf16::ZERO, bf16::ONE);
     ///
-    /// let nan = bf16::NAN;
-    /// let f = bf16::from_f32(7.0f32);
-    /// let g = bf16::from_f32(-7.0f32);
-    ///
-    /// assert!(!f.is_sign_negative());
-    /// assert!(g.is_sign_negative());
-    /// // NaN can be either positive or negative
-    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// assert!(x.is_nan() && status.invalid);
     /// ```
     #[inline]
-    pub const fn is_sign_negative(self) -> bool {
-        self.0 & 0x8000u16 != 0
+    pub fn mul_add_with_status(self, a: bf16, b: bf16) -> (bf16, Status) {
+        let mut status = Status::default();
+        let bits = softfloat::mul_add(
+            softfloat::BF16,
+            self.0 as u32,
+            a.0 as u32,
+            b.0 as u32,
+            &mut status,
+        );
+        (bf16(bits as u16), status)
     }
 
     /// Approximate number of [`bf16`] significant digits in base 10
@@ -590,33 +2246,49 @@ impl PartialOrd for bf16 {
 }
 
 impl FromStr for bf16 {
-    type Err = ParseFloatError;
-    fn from_str(src: &str) -> Result<bf16, ParseFloatError> {
-        f32::from_str(src).map(bf16::from_f32)
+    type Err = ParseHalfError;
+    fn from_str(src: &str) -> Result<bf16, ParseHalfError> {
+        bf16::from_ascii(src.as_bytes())
     }
 }
 
 impl Debug for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:?}", self.to_f32())
+        if f.alternate() || f.precision().is_some() {
+            Debug::fmt(&self.to_f32(), f)
+        } else {
+            shortest::debug(softfloat::BF16, self.0 as u32, f)
+        }
     }
 }
 
 impl Display for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}", self.to_f32())
+        if f.alternate() || f.precision().is_some() {
+            Display::fmt(&self.to_f32(), f)
+        } else {
+            shortest::display(softfloat::BF16, self.0 as u32, f)
+        }
     }
 }
 
 impl LowerExp for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:e}", self.to_f32())
+        if f.alternate() || f.precision().is_some() {
+            LowerExp::fmt(&self.to_f32(), f)
+        } else {
+            shortest::exp(softfloat::BF16, self.0 as u32, false, f)
+        }
     }
 }
 
 impl UpperExp for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:E}", self.to_f32())
+        if f.alternate() || f.precision().is_some() {
+            UpperExp::fmt(&self.to_f32(), f)
+        } else {
+            shortest::exp(softfloat::BF16, self.0 as u32, true, f)
+        }
     }
 }
 
@@ -656,7 +2328,7 @@ impl Add for bf16 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) + Self::to_f32(rhs))
+        self.add_with_status(rhs).0
     }
 }
 
@@ -664,7 +2336,7 @@ impl Sub for bf16 {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) - Self::to_f32(rhs))
+        self.sub_with_status(rhs).0
     }
 }
 
@@ -672,7 +2344,7 @@ impl Mul for bf16 {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) * Self::to_f32(rhs))
+        self.mul_with_status(rhs).0
     }
 }
 
@@ -680,7 +2352,7 @@ impl Div for bf16 {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) / Self::to_f32(rhs))
+        self.div_with_status(rhs).0
     }
 }
 
@@ -688,7 +2360,7 @@ impl Rem for bf16 {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) % Self::to_f32(rhs))
+        self.rem_with_status(rhs).0
     }
 }
 
@@ -698,6 +2370,7 @@ impl Rem for bf16 {
     clippy::neg_cmp_op_on_partial_ord
 )]
 #[cfg(test)]
+#[allow(clippy::legacy_numeric_constants)]
 mod test {
     use super::*;
     use core::cmp::Ordering;
@@ -878,6 +2551,65 @@ mod test {
         assert!(neg_nan16_from_32.is_nan() && neg_nan16_from_32.is_sign_negative());
     }
 
+    #[test]
+    fn test_nan_payload_propagation() {
+        use crate::slice::HalfFloatSliceExt;
+
+        let mut nans = [bf16::ZERO; 2 * 0x7F];
+        let nan_bits = (0x7F81..=0x7FFFu16).chain(0xFF81..=0xFFFF);
+        for (n, bits) in nans.iter_mut().zip(nan_bits) {
+            *n = bf16::from_bits(bits);
+        }
+        let mut f32s = [0f32; 2 * 0x7F];
+        let mut f64s = [0f64; 2 * 0x7F];
+        nans.convert_to_f32_slice(&mut f32s);
+        nans.convert_to_f64_slice(&mut f64s);
+        let mut from_f32s = [bf16::ZERO; 2 * 0x7F];
+        let mut from_f64s = [bf16::ZERO; 2 * 0x7F];
+        from_f32s.convert_from_f32_slice(&f32s);
+        from_f64s.convert_from_f64_slice(&f64s);
+
+        for (i, &b) in nans.iter().enumerate() {
+            let quiet = b.make_quiet();
+            let payload = b.payload().unwrap();
+            let sign = (b.to_bits() & 0x8000) as u32;
+            let expected32 = (sign << 16) | 0x7FC0_0000 | (payload as u32) << 16;
+            let expected64 = ((sign as u64) << 48) | 0x7FF8_0000_0000_0000 | (payload as u64) << 45;
+
+            assert_eq!(b.to_f32().to_bits(), expected32, "{:?}", b);
+            assert_eq!(b.to_f64().to_bits(), expected64, "{:?}", b);
+            assert_eq!(f32s[i].to_bits(), expected32, "{:?}", b);
+            assert_eq!(f64s[i].to_bits(), expected64, "{:?}", b);
+            assert_eq!(from_f32s[i].to_bits(), quiet.to_bits());
+            assert_eq!(from_f64s[i].to_bits(), quiet.to_bits());
+            assert_eq!(bf16::from_f32(b.to_f32()).to_bits(), quiet.to_bits());
+            assert_eq!(bf16::from_f64(b.to_f64()).to_bits(), quiet.to_bits());
+            let round = bf16::from_f64_round(b.to_f64(), RoundingMode::TowardZero);
+            assert_eq!(round.to_bits(), quiet.to_bits());
+            assert_eq!(
+                bf16::from_f32_with_status(b.to_f32()).0.to_bits(),
+                quiet.to_bits()
+            );
+
+            let h = b.to_f16();
+            assert!(!h.is_signaling_nan() && h.is_sign_negative() == b.is_sign_negative());
+            assert_eq!(h.payload(), Some(payload << 3));
+            assert_eq!(h.to_bf16().to_bits(), quiet.to_bits());
+        }
+
+        // Payload bits below bfloat16 precision are dropped
+        assert_eq!(
+            bf16::from_f32(f32::from_bits(0xFF80_FFFF)).to_bits(),
+            0xFFC0
+        );
+        assert_eq!(
+            bf16::from_f64(f64::from_bits(0x7FF0_0000_0000_0001)).to_bits(),
+            0x7FC0
+        );
+        let wide = f64::from_bits(0x7FF0_2000_0000_0001);
+        assert_eq!(bf16::from_f64(wide).payload(), Some(1));
+    }
+
     #[test]
     fn test_nan_conversion_to_larger() {
         let nan16 = bf16::from_bits(0x7F81u16);
@@ -941,6 +2673,306 @@ mod test {
         assert_eq!(bf16::from_bits(0x0005), bf16::from_f64(5.0 * tiny64));
     }
 
+    #[quickcheck]
+    fn qc_min_max(a: bf16, b: bf16) -> bool {
+        let same = |x: bf16, y: f32| (x.is_nan() && y.is_nan()) || x.to_f32() == y;
+        same(a.min(b), a.to_f32().min(b.to_f32())) && same(a.max(b), a.to_f32().max(b.to_f32()))
+    }
+
+    #[test]
+    fn test_class() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let class = x.class();
+            let expected = match (x.classify(), x.is_sign_negative()) {
+                (FpCategory::Nan, _) if bits & 0x0040 == 0 => FpClass::SignalingNaN,
+                (FpCategory::Nan, _) => FpClass::QuietNaN,
+                (FpCategory::Infinite, true) => FpClass::NegativeInfinity,
+                (FpCategory::Normal, true) => FpClass::NegativeNormal,
+                (FpCategory::Subnormal, true) => FpClass::NegativeSubnormal,
+                (FpCategory::Zero, true) => FpClass::NegativeZero,
+                (FpCategory::Zero, false) => FpClass::PositiveZero,
+                (FpCategory::Subnormal, false) => FpClass::PositiveSubnormal,
+                (FpCategory::Normal, false) => FpClass::PositiveNormal,
+                (FpCategory::Infinite, false) => FpClass::PositiveInfinity,
+            };
+            assert_eq!(class, expected, "{:04X}", bits);
+        }
+    }
+
+    #[quickcheck]
+    fn qc_ieee_minimum_maximum(a: bf16, b: bf16) -> bool {
+        let quiet = |x: bf16| x.is_nan() && x.to_bits() & 0x0040 != 0;
+        let (min, max) = (a.minimum(b), a.maximum(b));
+        let (min_num, max_num) = (a.minimum_number(b), a.maximum_number(b));
+        if a.is_nan() || b.is_nan() {
+            let number = if a.is_nan() { b } else { a };
+            quiet(min)
+                && quiet(max)
+                && (min_num.to_bits() == number.to_bits() || quiet(min_num) && number.is_nan())
+                && (max_num.to_bits() == number.to_bits() || quiet(max_num) && number.is_nan())
+        } else {
+            let (lo, hi) = if a.total_order(b) { (a, b) } else { (b, a) };
+            min.to_bits() == lo.to_bits()
+                && max.to_bits() == hi.to_bits()
+                && min_num.to_bits() == lo.to_bits()
+                && max_num.to_bits() == hi.to_bits()
+                && lo <= hi
+        }
+    }
+
+    #[test]
+    fn test_total_order() {
+        let ordered = [
+            -bf16::NAN,
+            bf16::from_bits(0xFF81),
+            bf16::NEG_INFINITY,
+            bf16::MIN,
+            -bf16::ONE,
+            -bf16::MIN_POSITIVE_SUBNORMAL,
+            bf16::NEG_ZERO,
+            bf16::ZERO,
+            bf16::MIN_POSITIVE_SUBNORMAL,
+            bf16::ONE,
+            bf16::MAX,
+            bf16::INFINITY,
+            bf16::from_bits(0x7F81),
+            bf16::NAN,
+        ];
+        for (i, &x) in ordered.iter().enumerate() {
+            for (j, &y) in ordered.iter().enumerate() {
+                assert_eq!(x.total_order(y), i <= j, "{:?} {:?}", x, y);
+                assert_eq!(x.total_cmp(&y), i.cmp(&j), "{:?} {:?}", x, y);
+                assert_eq!(
+                    x.total_order_mag(y),
+                    x.abs().total_order(y.abs()),
+                    "{:?} {:?}",
+                    x,
+                    y
+                );
+            }
+        }
+        assert!(bf16::from_bits(0x8001).total_order_mag(bf16::ONE));
+    }
+
+    #[test]
+    fn test_next_up_down_ulp() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            if x.is_nan() {
+                assert!(x.next_up().is_nan() && !x.next_up().is_signaling_nan());
+                assert!(x.next_down().is_nan() && !x.next_down().is_signaling_nan());
+                assert!(x.ulp().is_nan());
+                continue;
+            }
+            if x != bf16::INFINITY {
+                let up = x.next_up();
+                assert!(up > x, "{:?}", x);
+                assert_eq!(up.next_down(), x);
+                assert_eq!(x.ulp_distance(up), 1);
+                assert_eq!(x.next_after(bf16::INFINITY).to_bits(), up.to_bits());
+            }
+            if x != bf16::NEG_INFINITY {
+                let down = x.next_down();
+                assert!(down < x, "{:?}", x);
+                assert_eq!(down.next_up(), x);
+                assert_eq!(down.ulp_distance(x), 1);
+                assert_eq!(x.next_after(bf16::NEG_INFINITY).to_bits(), down.to_bits());
+            }
+            let a = x.abs();
+            if a < bf16::MAX {
+                assert_eq!(x.ulp(), a.next_up() - a, "{:?}", x);
+            }
+            assert_eq!(x.next_after(x).to_bits(), bits);
+        }
+
+        assert_eq!(bf16::MAX.ulp(), bf16::MAX - bf16::MAX.next_down());
+        assert_eq!(bf16::NEG_INFINITY.ulp(), bf16::INFINITY);
+        assert_eq!(bf16::INFINITY.next_up(), bf16::INFINITY);
+        assert_eq!(bf16::NEG_INFINITY.next_down(), bf16::NEG_INFINITY);
+        assert_eq!(bf16::NEG_INFINITY.next_up(), bf16::MIN);
+        assert_eq!(bf16::NEG_ZERO.next_after(bf16::ZERO).to_bits(), 0);
+        assert!(bf16::ONE.next_after(bf16::NAN).is_nan());
+        assert_eq!(
+            bf16::MIN.ulp_distance(bf16::MAX),
+            2 * (bf16::MAX.to_bits() as u32)
+        );
+        assert_eq!(bf16::NAN.ulp_distance(-bf16::NAN), 0);
+    }
+
+    #[quickcheck]
+    fn qc_ulp_distance(x: bf16, steps: u8) -> bool {
+        if x.is_nan() {
+            return x.ulp_distance(bf16::ONE) == u32::MAX;
+        }
+        let mut y = x;
+        let mut n = 0;
+        while n < steps as u32 && y != bf16::INFINITY {
+            y = y.next_up();
+            n += 1;
+        }
+        x.ulp_distance(y) == n && y.ulp_distance(x) == n
+    }
+
+    #[test]
+    fn test_decomposition() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let (sign, exp, man) = x.to_parts();
+            assert_eq!(bf16::from_parts(sign, exp, man).unwrap().to_bits(), bits);
+            assert_eq!(sign, x.is_sign_negative());
+
+            let (mantissa, exponent, sign) = x.integer_decode();
+            let (m, e) = x.frexp();
+            if x.is_nan() || x.is_infinite() || x.to_f64() == 0.0 {
+                assert_eq!(m.to_bits(), bits);
+                assert_eq!(e, 0);
+                continue;
+            }
+            let f = x.to_f64();
+            assert_eq!(
+                mantissa as f64 * 2f64.powi(exponent as i32) * sign as f64,
+                f
+            );
+            assert!(
+                m.abs() >= bf16::from_f32(0.5) && m.abs() < bf16::ONE,
+                "{:?}",
+                x
+            );
+            assert_eq!(m.to_f64() * 2f64.powi(e), f);
+            assert_eq!(m.ldexp(e).to_bits(), bits);
+
+            let k = x.ilogb();
+            assert!(
+                2f64.powi(k) <= f.abs() && f.abs() < 2f64.powi(k + 1),
+                "{:?}",
+                x
+            );
+            assert_eq!(x.logb(), bf16::from_f32(k as f32));
+        }
+        assert_eq!(bf16::from_parts(false, 0, 0x80), None);
+        assert!(bf16::NAN.logb().is_nan());
+        assert_eq!(bf16::NAN.ilogb(), i32::MIN);
+    }
+
+    #[test]
+    fn test_ldexp_rounds_once() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            for n in (-300..=300).step_by(3) {
+                let expected = bf16::from_f64(x.to_f64() * 2f64.powi(n));
+                let actual = x.ldexp(n);
+                if x.is_nan() {
+                    assert!(actual.is_nan());
+                } else {
+                    assert_eq!(actual.to_bits(), expected.to_bits(), "{:?} {}", x, n);
+                }
+            }
+        }
+        assert_eq!(bf16::MAX.ldexp(i32::MAX), bf16::INFINITY);
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.ldexp(i32::MIN), bf16::ZERO);
+        assert_eq!(
+            (-bf16::MAX).scalbn(i32::MIN).to_bits(),
+            bf16::NEG_ZERO.to_bits()
+        );
+        assert_eq!(bf16::from_bits(0x7F81).ldexp(1).to_bits(), 0x7F81 | 0x0040);
+    }
+
+    #[test]
+    fn test_sign_min_max_clamp() {
+        let two = bf16::from_f32(2.0);
+        assert_eq!(bf16::NEG_ZERO.abs().to_bits(), 0);
+        assert_eq!((-bf16::NAN).abs().to_bits(), bf16::NAN.to_bits());
+        assert_eq!(two.copysign(-bf16::NAN), -two);
+        assert_eq!(bf16::INFINITY.copysign(bf16::NEG_ZERO), bf16::NEG_INFINITY);
+
+        assert_eq!(
+            bf16::ZERO.min(bf16::NEG_ZERO).to_bits(),
+            bf16::NEG_ZERO.to_bits()
+        );
+        assert_eq!(
+            bf16::NEG_ZERO.min(bf16::ZERO).to_bits(),
+            bf16::NEG_ZERO.to_bits()
+        );
+        assert_eq!(
+            bf16::ZERO.max(bf16::NEG_ZERO).to_bits(),
+            bf16::ZERO.to_bits()
+        );
+        assert_eq!(
+            bf16::NEG_ZERO.max(bf16::ZERO).to_bits(),
+            bf16::ZERO.to_bits()
+        );
+        assert_eq!(bf16::NAN.min(two), two);
+        assert_eq!(two.max(bf16::NAN), two);
+        assert!(bf16::NAN.max(bf16::NAN).is_nan());
+        assert_eq!(bf16::NEG_INFINITY.max(bf16::MIN), bf16::MIN);
+        assert_eq!(bf16::INFINITY.min(bf16::MAX), bf16::MAX);
+
+        const CLAMPED: bf16 = bf16::from_bits(0x4400).min(bf16::ONE);
+        assert_eq!(CLAMPED, bf16::ONE);
+        assert_eq!(bf16::INFINITY.clamp(-two, two), two);
+        assert_eq!(
+            bf16::NEG_ZERO.clamp(bf16::ZERO, two).to_bits(),
+            bf16::NEG_ZERO.to_bits()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_nan_bound_panics() {
+        let _ = bf16::ONE.clamp(bf16::NAN, bf16::ONE);
+    }
+
+    #[test]
+    fn test_rounding_functions() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let f = x.to_f32();
+            for &(actual, expected) in &[
+                (x.floor(), f.floor()),
+                (x.ceil(), f.ceil()),
+                (x.round(), f.round()),
+                (x.trunc(), f.trunc()),
+                (x.fract(), f.fract()),
+            ] {
+                if f.is_nan() || expected.is_nan() {
+                    assert!(actual.is_nan(), "{:04X}", bits);
+                } else {
+                    assert_eq!(
+                        actual.to_f32().to_bits(),
+                        expected.to_bits(),
+                        "{:04X}",
+                        bits
+                    );
+                }
+            }
+        }
+        assert_eq!(bf16::from_f32(0.5).round_ties_even(), bf16::ZERO);
+        assert_eq!(bf16::from_f32(-1.5).round_ties_even(), bf16::from_f32(-2.0));
+        assert!(bf16::NEG_INFINITY.fract().is_nan());
+    }
+
+    #[test]
+    fn test_recip_and_angles() {
+        assert_eq!(bf16::from_f32(-0.5).recip(), -bf16::from_f32(2.0));
+        assert_eq!(bf16::INFINITY.recip(), bf16::ZERO);
+        assert_eq!(bf16::from_f32(90.0).to_radians(), bf16::FRAC_PI_2);
+        assert!(bf16::NAN.to_degrees().is_nan());
+
+        // The product is computed in f64 and rounded once more. Check that no exact product lies
+        // close enough to a rounding boundary for that to differ from a single rounding.
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits).to_f64();
+            for &c in &[180.0 / core::f64::consts::PI, core::f64::consts::PI / 180.0] {
+                let p = x * c;
+                let d = p * 2f64.powi(-51);
+                if p.is_finite() {
+                    assert_eq!(bf16::from_f64(p - d), bf16::from_f64(p + d), "{:04X}", bits);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_comparisons() {
         let zero = bf16::from_f64(0.0);
@@ -1209,4 +3241,92 @@ mod test {
             f.0 == roundtrip.0
         }
     }
+
+    #[quickcheck]
+    fn qc_from_f32_round_nearest_matches_from_f32(bits: u32) -> bool {
+        let x = f32::from_bits(bits);
+        let rounded = bf16::from_f32_round(x, RoundingMode::NearestTiesToEven);
+        let expected = bf16::from_f32(x);
+        if x.is_nan() {
+            rounded.is_nan() && expected.is_nan()
+        } else {
+            rounded.0 == expected.0
+        }
+    }
+
+    #[test]
+    fn test_bf16_to_f16() {
+        for bits in 0..=u16::MAX {
+            let b = bf16::from_bits(bits);
+            assert_eq!(b.to_f16().to_bits(), f16::from_f32(b.to_f32()).to_bits());
+        }
+
+        // The vectorized slice conversion gives the same results
+        use crate::slice::HalfFloatSliceExt;
+        for high in 0..=0xFFu16 {
+            let mut values = [bf16::ZERO; 256];
+            for (low, x) in values.iter_mut().enumerate() {
+                *x = bf16::from_bits(high << 8 | low as u16);
+            }
+            let mut buffer = [f16::ZERO; 256];
+            buffer.convert_from_bf16_slice(&values);
+            for (b, h) in values.iter().zip(&buffer) {
+                assert_eq!(h.to_bits(), b.to_f16().to_bits());
+            }
+        }
+
+        assert_eq!(bf16::from_f32(65280.0).to_f16(), f16::from_f32(65280.0));
+        assert_eq!(bf16::from_f32(65536.0).to_f16(), f16::INFINITY);
+        assert_eq!(bf16::MIN.to_f16(), f16::NEG_INFINITY);
+        assert_eq!(bf16::MIN_POSITIVE.to_f16(), f16::ZERO);
+        assert_eq!(
+            bf16::from_f64(-2f64.powi(-24)).to_f16(),
+            -f16::MIN_POSITIVE_SUBNORMAL
+        );
+    }
+
+    #[test]
+    fn test_from_f64_rounds_once() {
+        use crate::slice::HalfFloatSliceExt;
+
+        // Just above a tie, with the deciding bit far below f32 precision
+        let x = 1.0 + 2f64.powi(-8) + 2f64.powi(-40);
+        assert_eq!(bf16::from_f64(x).to_bits(), 0x3F81);
+        assert_eq!(bf16::from_f64(-x).to_bits(), 0x3F81 | 0x8000);
+        assert_eq!(bf16::from_f64(1.0 + 2f64.powi(-8)).to_bits(), 0x3F81 - 1);
+
+        let mut buffer = [bf16::ZERO; 5];
+        buffer.convert_from_f64_slice(&[x, -x, x, x, x]);
+        assert!(buffer.iter().all(|h| h.to_bits() & 0x7FFF == 0x3F81));
+    }
+
+    fn from_f64_is_correctly_rounded(x: f64) -> bool {
+        let rounded = bf16::from_f64(x);
+        let expected = bf16::from_f64_round(x, RoundingMode::NearestTiesToEven);
+        if x.is_nan() {
+            rounded.is_nan() && expected.is_nan()
+        } else {
+            rounded.0 == expected.0
+        }
+    }
+
+    #[quickcheck]
+    fn qc_from_f64_is_correctly_rounded(bits: u64) -> bool {
+        from_f64_is_correctly_rounded(f64::from_bits(bits))
+    }
+
+    #[quickcheck]
+    fn qc_from_f64_is_correctly_rounded_in_range(bits: u64) -> bool {
+        // Keep exponents within and around the normal and subnormal range. Sometimes keep only
+        // one bit more than the result's precision to produce exact ties, optionally with the
+        // lowest bit set so the value is just above the tie.
+        let exp = 1023 - 136 + (bits >> 52) % 270;
+        let man = match bits & 3 {
+            0 => bits & 0x000F_FFFF_FFFF_FFFF,
+            1 => bits & 0x000F_F000_0000_0000,
+            _ => (bits & 0x000F_F000_0000_0000) | 1,
+        };
+        let x = f64::from_bits((bits & 0x8000_0000_0000_0000) | man | (exp << 52));
+        from_f64_is_correctly_rounded(x)
+    }
 }
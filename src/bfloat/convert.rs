@@ -18,8 +18,9 @@ pub(crate) fn f32_to_bf16(value: f32) -> u16 {
 }
 
 pub(crate) fn f64_to_bf16(value: f64) -> u16 {
-    // Convert to raw bytes, truncating the last 32-bits of mantissa; that precision will always
-    // be lost on half-precision.
+    // Convert to raw bytes, keeping only the upper 32 bits. The lower 32 bits of mantissa are
+    // always lost on half-precision, but are folded into a sticky bit below so they still affect
+    // rounding.
     let val = value.to_bits();
     let x = (val >> 32) as u32;
 
@@ -40,6 +41,9 @@ pub(crate) fn f64_to_bf16(value: f64) -> u16 {
         return ((sign >> 16) | 0x7F80u32 | nan_bit | (man >> 13)) as u16;
     }
 
+    // Any of the truncated bits being set makes the value greater than a tie
+    let man = man | (val as u32 != 0) as u32;
+
     // The number is normalized, start assembling half precision version
     let half_sign = sign >> 16;
     // Unbias the exponent, then bias for bfloat16 precision
@@ -133,3 +137,108 @@ pub(crate) fn bf16_to_f64(i: u16) -> f64 {
     let man = (half_man & 0x007Fu64) << 45;
     f64::from_bits(sign | exp | man)
 }
+
+/// Rounds the bits of an `f32` to the nearest [`bf16`][crate::bf16], ties to even
+///
+/// This gives the same results as [`f32_to_bf16`] but is branch-free so that loops over slices
+/// vectorize.
+#[inline]
+fn f32_bits_to_bf16_nearest(x: u32) -> u16 {
+    (crate::softfloat::round_f32_bits_nearest(x, 16) >> 16) as u16
+}
+
+/// Converts the bits of an [`f16`][crate::f16] exactly into the bits of an `f32`
+///
+/// This gives the same results as [`f16_to_f32`][crate::binary16::convert::f16_to_f32] but is
+/// branch-free so that loops over slices vectorize. Subnormals are normalized by the hardware: an
+/// `f32` with the subnormal's fraction and the exponent of the smallest normal `f16` is exactly
+/// 2^-14 too large.
+#[inline]
+fn f16_bits_to_f32_bits(i: u16) -> u32 {
+    const MIN_NORMAL: u32 = 113 << 23;
+    let sign = ((i & 0x8000) as u32) << 16;
+    let exp = (i & 0x7C00) as u32;
+    let man = (i & 0x03FF) as u32;
+
+    let normal = ((exp | man) << 13) + ((127 - 15) << 23);
+    let subnormal =
+        (f32::from_bits(MIN_NORMAL | (man << 13)) - f32::from_bits(MIN_NORMAL)).to_bits();
+    // Set the most significant mantissa bit of NaN, and keep the rest of its payload
+    let special = 0x7F80_0000 | ((man != 0) as u32) << 22 | (man << 13);
+    let bits = if exp == 0x7C00 {
+        special
+    } else if exp == 0 {
+        subnormal
+    } else {
+        normal
+    };
+    sign | bits
+}
+
+/// Rounds the bits of a [`bf16`][crate::bf16] to the nearest [`f16`][crate::f16], ties to even
+///
+/// This gives the same results as [`f32_to_f16`][crate::binary16::convert::f32_to_f16] but is
+/// branch-free so that loops over slices vectorize. Subnormal results are rounded by the hardware:
+/// adding 0.5 leaves the value in a binade whose unit in the last place is that of `f16`
+/// subnormals, so the fraction of the sum is the rounded `f16` fraction.
+#[inline]
+fn bf16_bits_to_f16_nearest(i: u16) -> u16 {
+    const HALF: u32 = 0x3F00_0000;
+    let sign = i & 0x8000;
+    let abs = ((i & 0x7FFF) as u32) << 16;
+
+    // Rebias the exponent, rounding to even; carries out of the fraction increment the exponent
+    let normal = abs
+        .wrapping_sub((127 - 15) << 23)
+        .wrapping_add(0x0FFF + ((abs >> 13) & 1))
+        >> 13;
+    let subnormal = (f32::from_bits(abs) + f32::from_bits(HALF))
+        .to_bits()
+        .wrapping_sub(HALF);
+    // Set the most significant mantissa bit of NaN, and keep the rest of its payload
+    let special = if abs > 0x7F80_0000 {
+        0x7E00 | ((abs >> 13) & 0x03FF)
+    } else {
+        0x7C00
+    };
+    // Values from 65520 round to ∞, but 65536 and above would overflow the exponent in `normal`
+    let bits = if abs >= 0x4780_0000 {
+        special
+    } else if abs < 0x3880_0000 {
+        subnormal
+    } else {
+        normal
+    };
+    sign | bits as u16
+}
+
+#[inline]
+pub(crate) fn f16_to_bf16(i: u16) -> u16 {
+    // Every f16 is exact in f32, so this rounds only once
+    f32_bits_to_bf16_nearest(f16_bits_to_f32_bits(i))
+}
+
+#[inline]
+pub(crate) fn bf16_to_f16(i: u16) -> u16 {
+    bf16_bits_to_f16_nearest(i)
+}
+
+#[inline]
+pub(crate) fn f16x4_to_bf16x4(v: &[u16]) -> [u16; 4] {
+    [
+        f16_to_bf16(v[0]),
+        f16_to_bf16(v[1]),
+        f16_to_bf16(v[2]),
+        f16_to_bf16(v[3]),
+    ]
+}
+
+#[inline]
+pub(crate) fn bf16x4_to_f16x4(v: &[u16]) -> [u16; 4] {
+    [
+        bf16_to_f16(v[0]),
+        bf16_to_f16(v[1]),
+        bf16_to_f16(v[2]),
+        bf16_to_f16(v[3]),
+    ]
+}
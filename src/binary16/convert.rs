@@ -55,7 +55,7 @@ convert_fn! {
 convert_fn! {
     fn f64_to_f16(f: f64) -> u16 {
         if feature("f16c") {
-            unsafe { x86::f32_to_f16_x86_f16c(f as f32) }
+            unsafe { x86::f32_to_f16_x86_f16c(x86::f64_to_f32_odd(f)) }
         } else {
             f64_to_f16_fallback(f)
         }
@@ -75,7 +75,7 @@ convert_fn! {
 convert_fn! {
     fn f16_to_f64(i: u16) -> f64 {
         if feature("f16c") {
-            unsafe { x86::f16_to_f32_x86_f16c(i) as f64 }
+            x86::f32_to_f64(unsafe { x86::f16_to_f32_x86_f16c(i) })
         } else {
             f16_to_f64_fallback(i)
         }
@@ -201,8 +201,9 @@ fn f32_to_f16_fallback(value: f32) -> u16 {
 }
 
 fn f64_to_f16_fallback(value: f64) -> u16 {
-    // Convert to raw bytes, truncating the last 32-bits of mantissa; that precision will always
-    // be lost on half-precision.
+    // Convert to raw bytes, keeping only the upper 32 bits. The lower 32 bits of mantissa are
+    // always lost on half-precision, but are folded into a sticky bit below so they still affect
+    // rounding.
     let val = value.to_bits();
     let x = (val >> 32) as u32;
 
@@ -223,6 +224,9 @@ fn f64_to_f16_fallback(value: f64) -> u16 {
         return ((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 10)) as u16;
     }
 
+    // Any of the truncated bits being set makes the value greater than a tie
+    let man = man | (val as u32 != 0) as u32;
+
     // The number is normalized, start assembling half precision version
     let half_sign = sign >> 16;
     // Unbias the exponent, then bias for half precision
@@ -415,6 +419,43 @@ mod x86 {
         __m128, __m128i, _mm_cvtph_ps, _mm_cvtps_ph, _MM_FROUND_TO_NEAREST_INT,
     };
 
+    /// Narrows `f` to `f32` rounding to odd, so that rounding the result again to half precision
+    /// gives the same result as rounding `f` directly
+    #[inline]
+    pub(super) fn f64_to_f32_odd(f: f64) -> f32 {
+        if f.is_nan() {
+            // Casts may not preserve the payload, so keep its upper bits explicitly like the
+            // software conversion does
+            let x = f.to_bits();
+            let sign = (x >> 32) as u32 & 0x8000_0000;
+            return f32::from_bits(sign | 0x7FC0_0000 | ((x >> 29) as u32 & 0x003F_FFFF));
+        }
+        let r = f as f32;
+        if r as f64 == f {
+            return r;
+        }
+        // Step back towards zero if the cast rounded up in magnitude, then mark it inexact
+        let bits = r.to_bits();
+        let bits = if (r as f64).abs() > f.abs() {
+            bits - 1
+        } else {
+            bits
+        };
+        f32::from_bits(bits | 1)
+    }
+
+    /// Widens `f` to `f64`, keeping the payload of NaN values
+    #[inline]
+    pub(super) fn f32_to_f64(f: f32) -> f64 {
+        if f.is_nan() {
+            let x = f.to_bits();
+            let sign = ((x & 0x8000_0000) as u64) << 32;
+            f64::from_bits(sign | 0x7FF8_0000_0000_0000 | ((x & 0x003F_FFFF) as u64) << 29)
+        } else {
+            f as f64
+        }
+    }
+
     #[target_feature(enable = "f16c")]
     #[inline]
     pub(super) unsafe fn f16_to_f32_x86_f16c(i: u16) -> f32 {
@@ -467,10 +508,10 @@ mod x86 {
         // Let compiler vectorize this regular cast for now.
         // TODO: investigate auto-detecting sse2/avx convert features
         [
-            array[0] as f64,
-            array[1] as f64,
-            array[2] as f64,
-            array[3] as f64,
+            f32_to_f64(array[0]),
+            f32_to_f64(array[1]),
+            f32_to_f64(array[2]),
+            f32_to_f64(array[3]),
         ]
     }
 
@@ -481,7 +522,12 @@ mod x86 {
 
         // Let compiler vectorize this regular cast for now.
         // TODO: investigate auto-detecting sse2/avx convert features
-        let v = [v[0] as f32, v[1] as f32, v[2] as f32, v[3] as f32];
+        let v = [
+            f64_to_f32_odd(v[0]),
+            f64_to_f32_odd(v[1]),
+            f64_to_f32_odd(v[2]),
+            f64_to_f32_odd(v[3]),
+        ];
 
         let mut vec = MaybeUninit::<__m128>::uninit();
         ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
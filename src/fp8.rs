@@ -0,0 +1,991 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+    },
+    num::FpCategory,
+    ops::Neg,
+    str::FromStr,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parse, shortest,
+    softfloat::{self, RoundingMode},
+    ParseHalfError, Saturation,
+};
+
+/// An 8-bit floating point type implementing the OCP `E4M3` format
+///
+/// `E4M3` has 4 exponent bits with a bias of 7 and 3 fraction bits, for a precision of 4 bits and
+/// a range of ±448. It is the `E4M3FN` variant from the [OCP 8-bit floating point
+/// specification]: there are no infinities, and the all-ones exponent holds ordinary normal values
+/// except for `S.1111.111`, which is NaN. Values that overflow during conversion become NaN, or
+/// [`MAX`][f8e4m3::MAX] with the saturating conversions.
+///
+/// Like the 16-bit types, [`f8e4m3`] is intended for compact storage, such as of neural network
+/// weights and activations. No arithmetic is implemented; convert to [`f32`] to compute.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// let x = f8e4m3::from_f32(0.3);
+///
+/// assert_eq!(x.to_f32(), 0.3125);
+/// assert_eq!(x.to_string(), "0.3");
+/// assert!(f8e4m3::from_f32(500.0).is_nan());
+/// assert_eq!(f8e4m3::from_f32_saturating(500.0, Saturation::default()), f8e4m3::MAX);
+/// ```
+///
+/// [OCP 8-bit floating point specification]: https://www.opencompute.org/documents/ocp-8-bit-floating-point-specification-ofp8-revision-1-0-2023-12-01-pdf-1
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct f8e4m3(u8);
+
+/// An 8-bit floating point type implementing the OCP `E5M2` format
+///
+/// `E5M2` has 5 exponent bits with a bias of 15 and 2 fraction bits, for a precision of 3 bits and
+/// a range of ±57344. It follows IEEE 754 conventions, with ±∞, NaN and subnormals, and is the
+/// same as the upper byte of an [`f16`][crate::f16].
+///
+/// Like the 16-bit types, [`f8e5m2`] is intended for compact storage, such as of neural network
+/// gradients. No arithmetic is implemented; convert to [`f32`] to compute.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// let x = f8e5m2::from_f32(0.3);
+///
+/// assert_eq!(x.to_f32(), 0.3125);
+/// assert_eq!(x.to_string(), "0.3");
+/// assert_eq!(f8e5m2::from_f32(1e5), f8e5m2::INFINITY);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct f8e5m2(u8);
+
+/// An 8-bit floating point type implementing the `E4M3FNUZ` format
+///
+/// `E4M3FNUZ` is the variant of [`f8e4m3`] used by AMD and Graphcore hardware. It has 4 exponent
+/// bits with a bias of 8 and 3 fraction bits, for a range of ±240. There are no infinities and no
+/// negative zero: the pattern `0x80` is the only NaN. Values that overflow during conversion
+/// become NaN, or [`MAX`][f8e4m3fnuz::MAX] with the saturating conversions, and negative values
+/// that round to zero become `+0.0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// assert_eq!(f8e4m3fnuz::from_f32(240.0), f8e4m3fnuz::MAX);
+/// assert_eq!(f8e4m3fnuz::from_f32(-0.0).to_bits(), 0);
+/// assert_eq!(f8e4m3fnuz::NAN.to_bits(), 0x80);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct f8e4m3fnuz(u8);
+
+/// An 8-bit floating point type implementing the `E5M2FNUZ` format
+///
+/// `E5M2FNUZ` is the variant of [`f8e5m2`] used by AMD and Graphcore hardware. It has 5 exponent
+/// bits with a bias of 16 and 2 fraction bits, for a range of ±57344. There are no infinities and
+/// no negative zero: the pattern `0x80` is the only NaN. Values that overflow during conversion
+/// become NaN, or [`MAX`][f8e5m2fnuz::MAX] with the saturating conversions, and negative values
+/// that round to zero become `+0.0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// assert_eq!(f8e5m2fnuz::from_f32(57344.0), f8e5m2fnuz::MAX);
+/// assert!(f8e5m2fnuz::from_f32(f32::INFINITY).is_nan());
+/// assert_eq!(f8e5m2fnuz::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-17));
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct f8e5m2fnuz(u8);
+
+macro_rules! impl_fp8 {
+    ($ty:ident, $fmt:expr) => {
+        impl $ty {
+            /// Constructs a value from the raw bits
+            #[inline]
+            pub const fn from_bits(bits: u8) -> $ty {
+                $ty(bits)
+            }
+
+            /// Converts the value into its raw bits
+            #[inline]
+            pub const fn to_bits(self) -> u8 {
+                self.0
+            }
+
+            /// Constructs a value from a 32-bit floating point value
+            ///
+            /// The value is rounded to nearest, ties to even. Values too large in magnitude become
+            /// ±∞ if the format has infinities and NaN otherwise, as do infinite inputs. Use
+            /// [`from_f32_saturating`][Self::from_f32_saturating] to clamp them instead. NaN
+            /// values are preserved.
+            #[inline]
+            pub fn from_f32(value: f32) -> $ty {
+                $ty(softfloat::from_f32($fmt, value, RoundingMode::NearestTiesToEven) as u8)
+            }
+
+            /// Constructs a value from a 64-bit floating point value
+            ///
+            /// The value is rounded to nearest, ties to even, exactly once. Overflow is handled like
+            /// [`from_f32`][Self::from_f32].
+            #[inline]
+            pub fn from_f64(value: f64) -> $ty {
+                $ty(softfloat::from_f64($fmt, value, RoundingMode::NearestTiesToEven) as u8)
+            }
+
+            /// Constructs a value from a 32-bit floating point value, clamping values that are out
+            /// of range to the largest finite magnitude
+            ///
+            /// Finite values are rounded to nearest exactly like [`from_f32`][Self::from_f32],
+            /// except that results that would overflow become [`MAX`][Self::MAX] or
+            /// [`MIN`][Self::MIN]. Whether ±∞ is also clamped, and whether NaN is converted to
+            /// zero, is controlled by `saturation`. This matches the saturating conversion mode of
+            /// the OCP specification when `clamp_infinity` is set.
+            #[inline]
+            pub fn from_f32_saturating(value: f32, saturation: Saturation) -> $ty {
+                let bits = $ty::from_f32(value).0 as u32;
+                $ty(saturation.apply($fmt, value as f64, bits) as u8)
+            }
+
+            /// Constructs a value from a 64-bit floating point value, clamping values that are out
+            /// of range to the largest finite magnitude
+            ///
+            /// See [`from_f32_saturating`][Self::from_f32_saturating] for details.
+            #[inline]
+            pub fn from_f64_saturating(value: f64, saturation: Saturation) -> $ty {
+                let bits = $ty::from_f64(value).0 as u32;
+                $ty(saturation.apply($fmt, value, bits) as u8)
+            }
+
+            /// Converts the value into an [`f32`] value
+            ///
+            /// This conversion is lossless as all values can be represented exactly in [`f32`].
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                softfloat::to_f32($fmt, self.0 as u32)
+            }
+
+            /// Converts the value into an [`f64`] value
+            ///
+            /// This conversion is lossless as all values can be represented exactly in [`f64`].
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                softfloat::to_f32($fmt, self.0 as u32) as f64
+            }
+
+            /// Returns `true` if this value is NaN and `false` otherwise
+            #[inline]
+            pub const fn is_nan(self) -> bool {
+                $fmt.is_nan(self.0 as u32)
+            }
+
+            /// Returns `true` if this value is ±∞ and `false` otherwise
+            ///
+            /// This is always `false` for formats without infinities.
+            #[inline]
+            pub const fn is_infinite(self) -> bool {
+                $fmt.is_infinite(self.0 as u32)
+            }
+
+            /// Returns `true` if this number is neither infinite nor NaN
+            #[inline]
+            pub const fn is_finite(self) -> bool {
+                !self.is_nan() && !self.is_infinite()
+            }
+
+            /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN
+            #[inline]
+            pub const fn is_normal(self) -> bool {
+                self.0 as u32 & $fmt.exp_mask() != 0 && self.is_finite()
+            }
+
+            /// Returns the floating point category of the number
+            ///
+            /// If only one property is going to be tested, it is generally faster to use the
+            /// specific predicate instead.
+            pub fn classify(self) -> FpCategory {
+                if self.is_nan() {
+                    FpCategory::Nan
+                } else if self.is_infinite() {
+                    FpCategory::Infinite
+                } else if self.0 as u32 & $fmt.exp_mask() != 0 {
+                    FpCategory::Normal
+                } else if self.0 as u32 & $fmt.man_mask() != 0 {
+                    FpCategory::Subnormal
+                } else {
+                    FpCategory::Zero
+                }
+            }
+
+            /// Returns a number that represents the sign of `self`
+            ///
+            /// * 1.0 if the number is positive, +0.0 or +∞
+            /// * −1.0 if the number is negative, −0.0 or −∞
+            /// * NaN if the number is NaN
+            pub fn signum(self) -> $ty {
+                if self.is_nan() {
+                    self
+                } else {
+                    $ty((self.0 & 0x80) | $ty::ONE.0)
+                }
+            }
+
+            /// Returns `true` if and only if `self` has a positive sign, including +0.0, NaNs with
+            /// a positive sign bit and +∞
+            #[inline]
+            pub const fn is_sign_positive(self) -> bool {
+                self.0 & 0x80 == 0
+            }
+
+            /// Returns `true` if and only if `self` has a negative sign, including −0.0, NaNs with
+            /// a negative sign bit and −∞
+            #[inline]
+            pub const fn is_sign_negative(self) -> bool {
+                self.0 & 0x80 != 0
+            }
+        }
+
+        impl From<$ty> for f32 {
+            #[inline]
+            fn from(x: $ty) -> f32 {
+                x.to_f32()
+            }
+        }
+
+        impl From<$ty> for f64 {
+            #[inline]
+            fn from(x: $ty) -> f64 {
+                x.to_f64()
+            }
+        }
+
+        impl PartialEq for $ty {
+            fn eq(&self, other: &$ty) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    false
+                } else {
+                    (self.0 == other.0) || ((self.0 | other.0) & 0x7Fu8 == 0)
+                }
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, other: &$ty) -> Option<Ordering> {
+                if self.is_nan() || other.is_nan() {
+                    None
+                } else {
+                    let neg = self.0 & 0x80u8 != 0;
+                    let other_neg = other.0 & 0x80u8 != 0;
+                    match (neg, other_neg) {
+                        (false, false) => Some(self.0.cmp(&other.0)),
+                        (false, true) => {
+                            if (self.0 | other.0) & 0x7Fu8 == 0 {
+                                Some(Ordering::Equal)
+                            } else {
+                                Some(Ordering::Greater)
+                            }
+                        }
+                        (true, false) => {
+                            if (self.0 | other.0) & 0x7Fu8 == 0 {
+                                Some(Ordering::Equal)
+                            } else {
+                                Some(Ordering::Less)
+                            }
+                        }
+                        (true, true) => Some(other.0.cmp(&self.0)),
+                    }
+                }
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseHalfError;
+            fn from_str(src: &str) -> Result<$ty, ParseHalfError> {
+                parse::parse($fmt, src.as_bytes(), false).map(|bits| $ty(bits as u8))
+            }
+        }
+
+        impl Debug for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    Debug::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::debug($fmt, self.0 as u32, f)
+                }
+            }
+        }
+
+        impl Display for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    Display::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::display($fmt, self.0 as u32, f)
+                }
+            }
+        }
+
+        impl LowerExp for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    LowerExp::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::exp($fmt, self.0 as u32, false, f)
+                }
+            }
+        }
+
+        impl UpperExp for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if f.alternate() || f.precision().is_some() {
+                    UpperExp::fmt(&self.to_f32(), f)
+                } else {
+                    shortest::exp($fmt, self.0 as u32, true, f)
+                }
+            }
+        }
+
+        impl Binary for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:b}", self.0)
+            }
+        }
+
+        impl Octal for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:o}", self.0)
+            }
+        }
+
+        impl LowerHex for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:x}", self.0)
+            }
+        }
+
+        impl UpperHex for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:X}", self.0)
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self($fmt.neg(self.0 as u32) as u8)
+            }
+        }
+    };
+}
+
+impl_fp8!(f8e4m3, softfloat::E4M3);
+impl_fp8!(f8e5m2, softfloat::E5M2);
+impl_fp8!(f8e4m3fnuz, softfloat::E4M3FNUZ);
+impl_fp8!(f8e5m2fnuz, softfloat::E5M2FNUZ);
+
+impl f8e4m3 {
+    /// Approximate number of [`f8e4m3`] significant digits in base 10
+    pub const DIGITS: u32 = 0;
+    /// [`f8e4m3`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f8e4m3 = f8e4m3(0x20u8);
+    /// Number of [`f8e4m3`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 4;
+    /// Largest finite [`f8e4m3`] value
+    pub const MAX: f8e4m3 = f8e4m3(0x7Eu8);
+    /// Maximum possible [`f8e4m3`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 2;
+    /// Maximum possible [`f8e4m3`] power of 2 exponent
+    pub const MAX_EXP: i32 = 9;
+    /// Smallest finite [`f8e4m3`] value
+    pub const MIN: f8e4m3 = f8e4m3(0xFEu8);
+    /// Minimum possible normal [`f8e4m3`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -1;
+    /// One greater than the minimum possible normal [`f8e4m3`] power of 2 exponent
+    pub const MIN_EXP: i32 = -5;
+    /// Smallest positive normal [`f8e4m3`] value
+    pub const MIN_POSITIVE: f8e4m3 = f8e4m3(0x08u8);
+    /// [`f8e4m3`] Not a Number (NaN)
+    pub const NAN: f8e4m3 = f8e4m3(0x7Fu8);
+    /// The radix or base of the internal representation of [`f8e4m3`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f8e4m3`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f8e4m3 = f8e4m3(0x01u8);
+    /// Maximum subnormal [`f8e4m3`] value
+    pub const MAX_SUBNORMAL: f8e4m3 = f8e4m3(0x07u8);
+
+    /// [`f8e4m3`] 1
+    pub const ONE: f8e4m3 = f8e4m3(0x38u8);
+    /// [`f8e4m3`] 0
+    pub const ZERO: f8e4m3 = f8e4m3(0x00u8);
+    /// [`f8e4m3`] -0
+    pub const NEG_ZERO: f8e4m3 = f8e4m3(0x80u8);
+
+    /// [`f8e4m3`] Euler's number (ℯ)
+    pub const E: f8e4m3 = f8e4m3(0x43u8);
+    /// [`f8e4m3`] Archimedes' constant (π)
+    pub const PI: f8e4m3 = f8e4m3(0x45u8);
+    /// [`f8e4m3`] 1/π
+    pub const FRAC_1_PI: f8e4m3 = f8e4m3(0x2Au8);
+    /// [`f8e4m3`] 1/√2
+    pub const FRAC_1_SQRT_2: f8e4m3 = f8e4m3(0x33u8);
+    /// [`f8e4m3`] 2/π
+    pub const FRAC_2_PI: f8e4m3 = f8e4m3(0x32u8);
+    /// [`f8e4m3`] 2/√π
+    pub const FRAC_2_SQRT_PI: f8e4m3 = f8e4m3(0x39u8);
+    /// [`f8e4m3`] π/2
+    pub const FRAC_PI_2: f8e4m3 = f8e4m3(0x3Du8);
+    /// [`f8e4m3`] π/3
+    pub const FRAC_PI_3: f8e4m3 = f8e4m3(0x38u8);
+    /// [`f8e4m3`] π/4
+    pub const FRAC_PI_4: f8e4m3 = f8e4m3(0x35u8);
+    /// [`f8e4m3`] π/6
+    pub const FRAC_PI_6: f8e4m3 = f8e4m3(0x30u8);
+    /// [`f8e4m3`] π/8
+    pub const FRAC_PI_8: f8e4m3 = f8e4m3(0x2Du8);
+    /// [`f8e4m3`] 𝗅𝗇 10
+    pub const LN_10: f8e4m3 = f8e4m3(0x41u8);
+    /// [`f8e4m3`] 𝗅𝗇 2
+    pub const LN_2: f8e4m3 = f8e4m3(0x33u8);
+    /// [`f8e4m3`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: f8e4m3 = f8e4m3(0x2Eu8);
+    /// [`f8e4m3`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: f8e4m3 = f8e4m3(0x2Au8);
+    /// [`f8e4m3`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: f8e4m3 = f8e4m3(0x3Cu8);
+    /// [`f8e4m3`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: f8e4m3 = f8e4m3(0x45u8);
+    /// [`f8e4m3`] √2
+    pub const SQRT_2: f8e4m3 = f8e4m3(0x3Bu8);
+}
+
+impl f8e5m2 {
+    /// Approximate number of [`f8e5m2`] significant digits in base 10
+    pub const DIGITS: u32 = 0;
+    /// [`f8e5m2`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f8e5m2 = f8e5m2(0x34u8);
+    /// [`f8e5m2`] positive Infinity (+∞)
+    pub const INFINITY: f8e5m2 = f8e5m2(0x7Cu8);
+    /// Number of [`f8e5m2`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 3;
+    /// Largest finite [`f8e5m2`] value
+    pub const MAX: f8e5m2 = f8e5m2(0x7Bu8);
+    /// Maximum possible [`f8e5m2`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 4;
+    /// Maximum possible [`f8e5m2`] power of 2 exponent
+    pub const MAX_EXP: i32 = 16;
+    /// Smallest finite [`f8e5m2`] value
+    pub const MIN: f8e5m2 = f8e5m2(0xFBu8);
+    /// Minimum possible normal [`f8e5m2`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -4;
+    /// One greater than the minimum possible normal [`f8e5m2`] power of 2 exponent
+    pub const MIN_EXP: i32 = -13;
+    /// Smallest positive normal [`f8e5m2`] value
+    pub const MIN_POSITIVE: f8e5m2 = f8e5m2(0x04u8);
+    /// [`f8e5m2`] Not a Number (NaN)
+    pub const NAN: f8e5m2 = f8e5m2(0x7Eu8);
+    /// [`f8e5m2`] negative infinity (-∞).
+    pub const NEG_INFINITY: f8e5m2 = f8e5m2(0xFCu8);
+    /// The radix or base of the internal representation of [`f8e5m2`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f8e5m2`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f8e5m2 = f8e5m2(0x01u8);
+    /// Maximum subnormal [`f8e5m2`] value
+    pub const MAX_SUBNORMAL: f8e5m2 = f8e5m2(0x03u8);
+
+    /// [`f8e5m2`] 1
+    pub const ONE: f8e5m2 = f8e5m2(0x3Cu8);
+    /// [`f8e5m2`] 0
+    pub const ZERO: f8e5m2 = f8e5m2(0x00u8);
+    /// [`f8e5m2`] -0
+    pub const NEG_ZERO: f8e5m2 = f8e5m2(0x80u8);
+
+    /// [`f8e5m2`] Euler's number (ℯ)
+    pub const E: f8e5m2 = f8e5m2(0x41u8);
+    /// [`f8e5m2`] Archimedes' constant (π)
+    pub const PI: f8e5m2 = f8e5m2(0x42u8);
+    /// [`f8e5m2`] 1/π
+    pub const FRAC_1_PI: f8e5m2 = f8e5m2(0x35u8);
+    /// [`f8e5m2`] 1/√2
+    pub const FRAC_1_SQRT_2: f8e5m2 = f8e5m2(0x3Au8);
+    /// [`f8e5m2`] 2/π
+    pub const FRAC_2_PI: f8e5m2 = f8e5m2(0x39u8);
+    /// [`f8e5m2`] 2/√π
+    pub const FRAC_2_SQRT_PI: f8e5m2 = f8e5m2(0x3Du8);
+    /// [`f8e5m2`] π/2
+    pub const FRAC_PI_2: f8e5m2 = f8e5m2(0x3Eu8);
+    /// [`f8e5m2`] π/3
+    pub const FRAC_PI_3: f8e5m2 = f8e5m2(0x3Cu8);
+    /// [`f8e5m2`] π/4
+    pub const FRAC_PI_4: f8e5m2 = f8e5m2(0x3Au8);
+    /// [`f8e5m2`] π/6
+    pub const FRAC_PI_6: f8e5m2 = f8e5m2(0x38u8);
+    /// [`f8e5m2`] π/8
+    pub const FRAC_PI_8: f8e5m2 = f8e5m2(0x36u8);
+    /// [`f8e5m2`] 𝗅𝗇 10
+    pub const LN_10: f8e5m2 = f8e5m2(0x41u8);
+    /// [`f8e5m2`] 𝗅𝗇 2
+    pub const LN_2: f8e5m2 = f8e5m2(0x3Au8);
+    /// [`f8e5m2`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: f8e5m2 = f8e5m2(0x37u8);
+    /// [`f8e5m2`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: f8e5m2 = f8e5m2(0x35u8);
+    /// [`f8e5m2`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: f8e5m2 = f8e5m2(0x3Eu8);
+    /// [`f8e5m2`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: f8e5m2 = f8e5m2(0x43u8);
+    /// [`f8e5m2`] √2
+    pub const SQRT_2: f8e5m2 = f8e5m2(0x3Eu8);
+}
+
+impl f8e4m3fnuz {
+    /// Approximate number of [`f8e4m3fnuz`] significant digits in base 10
+    pub const DIGITS: u32 = 0;
+    /// [`f8e4m3fnuz`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f8e4m3fnuz = f8e4m3fnuz(0x28u8);
+    /// Number of [`f8e4m3fnuz`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 4;
+    /// Largest finite [`f8e4m3fnuz`] value
+    pub const MAX: f8e4m3fnuz = f8e4m3fnuz(0x7Fu8);
+    /// Maximum possible [`f8e4m3fnuz`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 2;
+    /// Maximum possible [`f8e4m3fnuz`] power of 2 exponent
+    pub const MAX_EXP: i32 = 8;
+    /// Smallest finite [`f8e4m3fnuz`] value
+    pub const MIN: f8e4m3fnuz = f8e4m3fnuz(0xFFu8);
+    /// Minimum possible normal [`f8e4m3fnuz`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -2;
+    /// One greater than the minimum possible normal [`f8e4m3fnuz`] power of 2 exponent
+    pub const MIN_EXP: i32 = -6;
+    /// Smallest positive normal [`f8e4m3fnuz`] value
+    pub const MIN_POSITIVE: f8e4m3fnuz = f8e4m3fnuz(0x08u8);
+    /// [`f8e4m3fnuz`] Not a Number (NaN)
+    pub const NAN: f8e4m3fnuz = f8e4m3fnuz(0x80u8);
+    /// The radix or base of the internal representation of [`f8e4m3fnuz`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f8e4m3fnuz`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f8e4m3fnuz = f8e4m3fnuz(0x01u8);
+    /// Maximum subnormal [`f8e4m3fnuz`] value
+    pub const MAX_SUBNORMAL: f8e4m3fnuz = f8e4m3fnuz(0x07u8);
+
+    /// [`f8e4m3fnuz`] 1
+    pub const ONE: f8e4m3fnuz = f8e4m3fnuz(0x40u8);
+    /// [`f8e4m3fnuz`] 0
+    pub const ZERO: f8e4m3fnuz = f8e4m3fnuz(0x00u8);
+
+    /// [`f8e4m3fnuz`] Euler's number (ℯ)
+    pub const E: f8e4m3fnuz = f8e4m3fnuz(0x4Bu8);
+    /// [`f8e4m3fnuz`] Archimedes' constant (π)
+    pub const PI: f8e4m3fnuz = f8e4m3fnuz(0x4Du8);
+    /// [`f8e4m3fnuz`] 1/π
+    pub const FRAC_1_PI: f8e4m3fnuz = f8e4m3fnuz(0x32u8);
+    /// [`f8e4m3fnuz`] 1/√2
+    pub const FRAC_1_SQRT_2: f8e4m3fnuz = f8e4m3fnuz(0x3Bu8);
+    /// [`f8e4m3fnuz`] 2/π
+    pub const FRAC_2_PI: f8e4m3fnuz = f8e4m3fnuz(0x3Au8);
+    /// [`f8e4m3fnuz`] 2/√π
+    pub const FRAC_2_SQRT_PI: f8e4m3fnuz = f8e4m3fnuz(0x41u8);
+    /// [`f8e4m3fnuz`] π/2
+    pub const FRAC_PI_2: f8e4m3fnuz = f8e4m3fnuz(0x45u8);
+    /// [`f8e4m3fnuz`] π/3
+    pub const FRAC_PI_3: f8e4m3fnuz = f8e4m3fnuz(0x40u8);
+    /// [`f8e4m3fnuz`] π/4
+    pub const FRAC_PI_4: f8e4m3fnuz = f8e4m3fnuz(0x3Du8);
+    /// [`f8e4m3fnuz`] π/6
+    pub const FRAC_PI_6: f8e4m3fnuz = f8e4m3fnuz(0x38u8);
+    /// [`f8e4m3fnuz`] π/8
+    pub const FRAC_PI_8: f8e4m3fnuz = f8e4m3fnuz(0x35u8);
+    /// [`f8e4m3fnuz`] 𝗅𝗇 10
+    pub const LN_10: f8e4m3fnuz = f8e4m3fnuz(0x49u8);
+    /// [`f8e4m3fnuz`] 𝗅𝗇 2
+    pub const LN_2: f8e4m3fnuz = f8e4m3fnuz(0x3Bu8);
+    /// [`f8e4m3fnuz`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: f8e4m3fnuz = f8e4m3fnuz(0x36u8);
+    /// [`f8e4m3fnuz`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: f8e4m3fnuz = f8e4m3fnuz(0x32u8);
+    /// [`f8e4m3fnuz`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: f8e4m3fnuz = f8e4m3fnuz(0x44u8);
+    /// [`f8e4m3fnuz`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: f8e4m3fnuz = f8e4m3fnuz(0x4Du8);
+    /// [`f8e4m3fnuz`] √2
+    pub const SQRT_2: f8e4m3fnuz = f8e4m3fnuz(0x43u8);
+}
+
+impl f8e5m2fnuz {
+    /// Approximate number of [`f8e5m2fnuz`] significant digits in base 10
+    pub const DIGITS: u32 = 0;
+    /// [`f8e5m2fnuz`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f8e5m2fnuz = f8e5m2fnuz(0x38u8);
+    /// Number of [`f8e5m2fnuz`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 3;
+    /// Largest finite [`f8e5m2fnuz`] value
+    pub const MAX: f8e5m2fnuz = f8e5m2fnuz(0x7Fu8);
+    /// Maximum possible [`f8e5m2fnuz`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 4;
+    /// Maximum possible [`f8e5m2fnuz`] power of 2 exponent
+    pub const MAX_EXP: i32 = 16;
+    /// Smallest finite [`f8e5m2fnuz`] value
+    pub const MIN: f8e5m2fnuz = f8e5m2fnuz(0xFFu8);
+    /// Minimum possible normal [`f8e5m2fnuz`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -4;
+    /// One greater than the minimum possible normal [`f8e5m2fnuz`] power of 2 exponent
+    pub const MIN_EXP: i32 = -14;
+    /// Smallest positive normal [`f8e5m2fnuz`] value
+    pub const MIN_POSITIVE: f8e5m2fnuz = f8e5m2fnuz(0x04u8);
+    /// [`f8e5m2fnuz`] Not a Number (NaN)
+    pub const NAN: f8e5m2fnuz = f8e5m2fnuz(0x80u8);
+    /// The radix or base of the internal representation of [`f8e5m2fnuz`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f8e5m2fnuz`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f8e5m2fnuz = f8e5m2fnuz(0x01u8);
+    /// Maximum subnormal [`f8e5m2fnuz`] value
+    pub const MAX_SUBNORMAL: f8e5m2fnuz = f8e5m2fnuz(0x03u8);
+
+    /// [`f8e5m2fnuz`] 1
+    pub const ONE: f8e5m2fnuz = f8e5m2fnuz(0x40u8);
+    /// [`f8e5m2fnuz`] 0
+    pub const ZERO: f8e5m2fnuz = f8e5m2fnuz(0x00u8);
+
+    /// [`f8e5m2fnuz`] Euler's number (ℯ)
+    pub const E: f8e5m2fnuz = f8e5m2fnuz(0x45u8);
+    /// [`f8e5m2fnuz`] Archimedes' constant (π)
+    pub const PI: f8e5m2fnuz = f8e5m2fnuz(0x46u8);
+    /// [`f8e5m2fnuz`] 1/π
+    pub const FRAC_1_PI: f8e5m2fnuz = f8e5m2fnuz(0x39u8);
+    /// [`f8e5m2fnuz`] 1/√2
+    pub const FRAC_1_SQRT_2: f8e5m2fnuz = f8e5m2fnuz(0x3Eu8);
+    /// [`f8e5m2fnuz`] 2/π
+    pub const FRAC_2_PI: f8e5m2fnuz = f8e5m2fnuz(0x3Du8);
+    /// [`f8e5m2fnuz`] 2/√π
+    pub const FRAC_2_SQRT_PI: f8e5m2fnuz = f8e5m2fnuz(0x41u8);
+    /// [`f8e5m2fnuz`] π/2
+    pub const FRAC_PI_2: f8e5m2fnuz = f8e5m2fnuz(0x42u8);
+    /// [`f8e5m2fnuz`] π/3
+    pub const FRAC_PI_3: f8e5m2fnuz = f8e5m2fnuz(0x40u8);
+    /// [`f8e5m2fnuz`] π/4
+    pub const FRAC_PI_4: f8e5m2fnuz = f8e5m2fnuz(0x3Eu8);
+    /// [`f8e5m2fnuz`] π/6
+    pub const FRAC_PI_6: f8e5m2fnuz = f8e5m2fnuz(0x3Cu8);
+    /// [`f8e5m2fnuz`] π/8
+    pub const FRAC_PI_8: f8e5m2fnuz = f8e5m2fnuz(0x3Au8);
+    /// [`f8e5m2fnuz`] 𝗅𝗇 10
+    pub const LN_10: f8e5m2fnuz = f8e5m2fnuz(0x45u8);
+    /// [`f8e5m2fnuz`] 𝗅𝗇 2
+    pub const LN_2: f8e5m2fnuz = f8e5m2fnuz(0x3Eu8);
+    /// [`f8e5m2fnuz`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: f8e5m2fnuz = f8e5m2fnuz(0x3Bu8);
+    /// [`f8e5m2fnuz`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: f8e5m2fnuz = f8e5m2fnuz(0x39u8);
+    /// [`f8e5m2fnuz`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: f8e5m2fnuz = f8e5m2fnuz(0x42u8);
+    /// [`f8e5m2fnuz`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: f8e5m2fnuz = f8e5m2fnuz(0x47u8);
+    /// [`f8e5m2fnuz`] √2
+    pub const SQRT_2: f8e5m2fnuz = f8e5m2fnuz(0x42u8);
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::softfloat::{to_f32, Encoding, Format, E4M3, E4M3FNUZ, E5M2, E5M2FNUZ};
+    use core::f64::consts;
+    use std::format;
+
+    const FORMATS: [Format; 4] = [E4M3, E5M2, E4M3FNUZ, E5M2FNUZ];
+
+    fn from_f64(fmt: Format, x: f64) -> u32 {
+        softfloat::from_f64(fmt, x, RoundingMode::NearestTiesToEven)
+    }
+
+    /// The result of overflowing with `sign`, which is NaN in formats without ±∞
+    fn overflow(fmt: Format, sign: bool) -> u32 {
+        fmt.zero(sign) | fmt.infinity()
+    }
+
+    fn next_up(x: f64) -> f64 {
+        f64::from_bits(x.to_bits() + 1)
+    }
+
+    fn next_down(x: f64) -> f64 {
+        f64::from_bits(x.to_bits() - 1)
+    }
+
+    #[test]
+    fn exhaustive_round_trip() {
+        for &fmt in &FORMATS {
+            for bits in 0..=0xFFu32 {
+                let x = to_f32(fmt, bits);
+                if fmt.is_nan(bits) {
+                    assert!(x.is_nan());
+                    assert!(fmt.is_nan(from_f64(fmt, x as f64)));
+                } else {
+                    assert_eq!(from_f64(fmt, x as f64), bits, "{:?} {:02X}", fmt, bits);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rounding_between_neighbours() {
+        for &fmt in &FORMATS {
+            for bits in 0..fmt.max() {
+                let lo = to_f32(fmt, bits) as f64;
+                let hi = to_f32(fmt, bits + 1) as f64;
+                let mid = (lo + hi) / 2.0;
+                let even = if bits & 1 == 0 { bits } else { bits + 1 };
+                assert_eq!(from_f64(fmt, mid), even, "{:?} {}", fmt, mid);
+                assert_eq!(from_f64(fmt, next_down(mid)), bits);
+                assert_eq!(from_f64(fmt, next_up(mid)), bits + 1);
+                assert_eq!(from_f64(fmt, -next_up(mid)), fmt.neg(bits + 1));
+                assert_eq!(from_f64(fmt, -next_down(mid)), fmt.neg(bits));
+            }
+
+            // Values past the largest finite value by half an ulp or more overflow
+            let max = to_f32(fmt, fmt.max()) as f64;
+            let ulp = max - to_f32(fmt, fmt.max() - 1) as f64;
+            let limit = max + ulp / 2.0;
+            let tie = if fmt.max() & 1 == 0 {
+                fmt.max()
+            } else {
+                overflow(fmt, false)
+            };
+            assert_eq!(from_f64(fmt, limit), tie, "{:?}", fmt);
+            assert_eq!(from_f64(fmt, next_down(limit)), fmt.max());
+            assert_eq!(from_f64(fmt, next_up(limit)), overflow(fmt, false));
+            assert_eq!(from_f64(fmt, -1e300), overflow(fmt, true));
+            assert_eq!(from_f64(fmt, f64::NEG_INFINITY), overflow(fmt, true));
+            assert_eq!(from_f64(fmt, -1e-300), fmt.zero(true));
+            assert_eq!(from_f64(fmt, -0.0), fmt.zero(true));
+        }
+    }
+
+    #[test]
+    fn encodings() {
+        assert_eq!(f8e4m3::MAX.to_f32(), 448.0);
+        assert_eq!(f8e4m3::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-9));
+        assert!(f8e4m3::from_bits(0xFF).is_nan());
+        assert!(!f8e4m3::from_bits(0x7E).is_nan());
+        assert!(f8e4m3::from_f32(f32::INFINITY).is_nan());
+        assert!(!f8e4m3::NAN.is_infinite());
+
+        assert_eq!(f8e5m2::MAX.to_f32(), 57344.0);
+        assert_eq!(f8e5m2::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-16));
+        assert_eq!(f8e5m2::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+        assert_eq!(f8e5m2::from_f32(1e5), f8e5m2::INFINITY);
+        // E5M2 is the upper byte of binary16
+        for bits in 0..=0xFFu8 {
+            let x = crate::f16::from_bits((bits as u16) << 8);
+            if x.is_nan() {
+                assert!(f8e5m2::from_bits(bits).is_nan());
+            } else {
+                assert_eq!(x.to_f32(), f8e5m2::from_bits(bits).to_f32());
+            }
+        }
+
+        assert_eq!(f8e4m3fnuz::MAX.to_f32(), 240.0);
+        assert_eq!(f8e4m3fnuz::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-10));
+        assert_eq!(f8e5m2fnuz::MAX.to_f32(), 57344.0);
+        assert_eq!(f8e5m2fnuz::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-17));
+        assert!(f8e4m3fnuz::from_bits(0x80).is_nan());
+        assert_eq!(f8e4m3fnuz::from_f32(-0.0).to_bits(), 0);
+        assert_eq!((-f8e4m3fnuz::ZERO).to_bits(), 0);
+        assert!((-f8e5m2fnuz::NAN).is_nan());
+        assert_eq!(-f8e4m3fnuz::ONE, f8e4m3fnuz::from_f32(-1.0));
+        assert_eq!(f8e4m3fnuz::from_f32(-1e-10).to_bits(), 0);
+        assert!(f8e4m3fnuz::from_f32(-1e10).is_nan());
+
+        for &fmt in &FORMATS {
+            assert_eq!(
+                fmt.encoding == Encoding::Ieee,
+                fmt.is_infinite(overflow(fmt, false))
+            );
+        }
+    }
+
+    #[test]
+    fn constants() {
+        macro_rules! check {
+            ($ty:ident) => {
+                assert_eq!($ty::ONE.to_f32(), 1.0);
+                assert_eq!($ty::ZERO.to_f32(), 0.0);
+                assert_eq!(
+                    $ty::ONE.to_f32() + $ty::EPSILON.to_f32(),
+                    $ty::from_bits($ty::ONE.to_bits() + 1).to_f32()
+                );
+                assert_eq!($ty::MIN, -$ty::MAX);
+                assert_eq!($ty::MIN_POSITIVE.to_f32(), 2f32.powi($ty::MIN_EXP - 1));
+                assert_eq!($ty::MAX_EXP - 1, $ty::MAX.to_f32().log2().floor() as i32);
+                let above_max = $ty::from_bits($ty::MAX.to_bits() + 1);
+                assert!(above_max.is_nan() || above_max.is_infinite());
+                assert_eq!($ty::MAX_10_EXP, $ty::MAX.to_f32().log10().floor() as i32);
+                assert_eq!(
+                    $ty::MIN_10_EXP,
+                    $ty::MIN_POSITIVE.to_f32().log10().ceil() as i32
+                );
+                assert!($ty::MAX_SUBNORMAL < $ty::MIN_POSITIVE);
+                assert!($ty::from_bits($ty::MAX_SUBNORMAL.to_bits() + 1) == $ty::MIN_POSITIVE);
+                assert!($ty::NAN.is_nan());
+
+                assert_eq!($ty::E, $ty::from_f64(consts::E));
+                assert_eq!($ty::PI, $ty::from_f64(consts::PI));
+                assert_eq!($ty::FRAC_1_PI, $ty::from_f64(consts::FRAC_1_PI));
+                assert_eq!($ty::FRAC_1_SQRT_2, $ty::from_f64(consts::FRAC_1_SQRT_2));
+                assert_eq!($ty::FRAC_2_PI, $ty::from_f64(consts::FRAC_2_PI));
+                assert_eq!($ty::FRAC_2_SQRT_PI, $ty::from_f64(consts::FRAC_2_SQRT_PI));
+                assert_eq!($ty::FRAC_PI_2, $ty::from_f64(consts::FRAC_PI_2));
+                assert_eq!($ty::FRAC_PI_3, $ty::from_f64(consts::FRAC_PI_3));
+                assert_eq!($ty::FRAC_PI_4, $ty::from_f64(consts::FRAC_PI_4));
+                assert_eq!($ty::FRAC_PI_6, $ty::from_f64(consts::FRAC_PI_6));
+                assert_eq!($ty::FRAC_PI_8, $ty::from_f64(consts::FRAC_PI_8));
+                assert_eq!($ty::LN_10, $ty::from_f64(consts::LN_10));
+                assert_eq!($ty::LN_2, $ty::from_f64(consts::LN_2));
+                assert_eq!($ty::LOG10_E, $ty::from_f64(consts::LOG10_E));
+                assert_eq!($ty::LOG10_2, $ty::from_f64(consts::LOG10_2));
+                assert_eq!($ty::LOG2_E, $ty::from_f64(consts::LOG2_E));
+                assert_eq!($ty::LOG2_10, $ty::from_f64(consts::LOG2_10));
+                assert_eq!($ty::SQRT_2, $ty::from_f64(consts::SQRT_2));
+            };
+        }
+        check!(f8e4m3);
+        check!(f8e5m2);
+        check!(f8e4m3fnuz);
+        check!(f8e5m2fnuz);
+    }
+
+    #[test]
+    fn saturating() {
+        let keep = Saturation::default();
+        let clamp = Saturation {
+            clamp_infinity: true,
+            nan_to_zero: true,
+        };
+        assert_eq!(f8e4m3::from_f32_saturating(1000.0, keep), f8e4m3::MAX);
+        assert_eq!(f8e4m3::from_f64_saturating(-1e300, keep), f8e4m3::MIN);
+        assert!(f8e4m3::from_f32_saturating(f32::INFINITY, keep).is_nan());
+        assert_eq!(
+            f8e4m3::from_f32_saturating(f32::INFINITY, clamp),
+            f8e4m3::MAX
+        );
+        assert_eq!(f8e4m3::from_f32_saturating(f32::NAN, clamp), f8e4m3::ZERO);
+        assert_eq!(
+            f8e5m2::from_f32_saturating(f32::NEG_INFINITY, keep),
+            f8e5m2::NEG_INFINITY
+        );
+        assert_eq!(
+            f8e5m2::from_f32_saturating(f32::NEG_INFINITY, clamp),
+            f8e5m2::MIN
+        );
+        assert_eq!(f8e5m2::from_f32_saturating(-1e6, keep), f8e5m2::MIN);
+        assert_eq!(
+            f8e4m3fnuz::from_f32_saturating(-1000.0, keep),
+            f8e4m3fnuz::MIN
+        );
+        assert_eq!(
+            f8e5m2fnuz::from_f64_saturating(f64::NEG_INFINITY, clamp),
+            f8e5m2fnuz::MIN
+        );
+        assert!(f8e5m2fnuz::from_f64_saturating(f64::NAN, keep).is_nan());
+    }
+
+    #[test]
+    fn classify_and_compare() {
+        assert_eq!(f8e4m3::MAX.classify(), FpCategory::Normal);
+        assert_eq!(f8e4m3::NAN.classify(), FpCategory::Nan);
+        assert_eq!(f8e4m3::MAX_SUBNORMAL.classify(), FpCategory::Subnormal);
+        assert_eq!(f8e4m3::NEG_ZERO.classify(), FpCategory::Zero);
+        assert_eq!(f8e5m2::INFINITY.classify(), FpCategory::Infinite);
+        assert_eq!(f8e5m2fnuz::NAN.classify(), FpCategory::Nan);
+        assert!(f8e4m3::MAX.is_normal() && !f8e4m3::NAN.is_normal());
+        assert!(!f8e4m3::NAN.is_finite() && f8e4m3::MAX.is_finite());
+        assert_eq!(f8e4m3::from_f32(-3.0).signum(), -f8e4m3::ONE);
+        assert_eq!(f8e4m3fnuz::ZERO.signum(), f8e4m3fnuz::ONE);
+
+        assert_eq!(f8e4m3::ZERO, f8e4m3::NEG_ZERO);
+        assert!(f8e4m3::NAN != f8e4m3::NAN);
+        assert!(f8e4m3::MIN < f8e4m3::MAX);
+        assert!(f8e4m3::from_f32(-1.0) < f8e4m3::from_f32(-0.5));
+        assert!(f8e5m2::NEG_INFINITY < f8e5m2::MIN);
+        assert_eq!(f8e4m3fnuz::NAN.partial_cmp(&f8e4m3fnuz::ZERO), None);
+        assert!(f8e4m3fnuz::from_f32(-0.5) < f8e4m3fnuz::ZERO);
+    }
+
+    #[test]
+    fn format_and_parse() {
+        assert_eq!(format!("{}", f8e4m3::from_f32(0.3)), "0.3");
+        // 450 is the shortest decimal that rounds to 448
+        assert_eq!(format!("{:?}", f8e4m3::MAX), "450.0");
+        assert_eq!(format!("{:e}", f8e5m2::MAX), "6e4");
+        assert_eq!(format!("{:?}", f8e5m2::NEG_INFINITY), "-inf");
+        assert_eq!(format!("{:?}", f8e4m3fnuz::NAN), "NaN");
+        assert_eq!(format!("{:?}", f8e4m3::NEG_ZERO), "-0.0");
+        assert_eq!(format!("{:#}", f8e4m3::from_f32(0.3)), "0.3125");
+        assert_eq!(format!("{:x}", f8e4m3::ONE), "38");
+
+        assert_eq!("448".parse(), Ok(f8e4m3::MAX));
+        assert!("464.0001".parse::<f8e4m3>().unwrap().is_nan());
+        assert_eq!("-inf".parse(), Ok(f8e5m2::NEG_INFINITY));
+        assert!("inf".parse::<f8e4m3fnuz>().unwrap().is_nan());
+        assert_eq!("-0".parse::<f8e5m2fnuz>().unwrap().to_bits(), 0);
+        assert!("0.5x".parse::<f8e4m3>().is_err());
+
+        macro_rules! round_trip {
+            ($ty:ident) => {
+                for bits in 0..=0xFFu8 {
+                    let x = $ty::from_bits(bits);
+                    if x.is_nan() {
+                        continue;
+                    }
+                    for s in &[format!("{}", x), format!("{:?}", x), format!("{:e}", x)] {
+                        assert_eq!(s.parse::<$ty>().unwrap().to_bits(), bits, "{}", s);
+                    }
+                }
+            };
+        }
+        round_trip!(f8e4m3);
+        round_trip!(f8e5m2);
+        round_trip!(f8e4m3fnuz);
+        round_trip!(f8e5m2fnuz);
+    }
+}
@@ -0,0 +1,338 @@
+//! Hexadecimal floating point formatting and parsing, as with `%a` and `strtod` in C
+//!
+//! Hexadecimal output is exact for every value, so it is convenient for exchanging test vectors
+//! with other languages. Normal values are written as `0x1.<fraction>p<exponent>` and subnormal
+//! values as `0x0.<fraction>p<minimum exponent>`, with trailing zeros of the fraction removed.
+
+use crate::{
+    bf16, f16,
+    parse::{ParseHalfError, ParseHalfErrorKind},
+    softfloat::{self, Format, RoundingMode, Status},
+};
+use core::fmt::{self, Formatter};
+
+/// Formats a [`f16`] or [`bf16`] value as a hexadecimal floating point
+/// number
+///
+/// This is returned by the `to_hex` methods of both types. The output matches the `%a` format of C
+/// when written with `{}` or `{:x}`, and `%A` when written with `{:X}`. The `+` flag, width and fill
+/// are respected. Every value, including subnormals, is written exactly, and can be parsed back
+/// with `from_hex_str`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// assert_eq!(f16::from_f32(12.0).to_hex().to_string(), "0x1.8p+3");
+/// assert_eq!(format!("{:X}", bf16::from_f32(-0.75).to_hex()), "-0X1.8P-1");
+/// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_hex().to_string(), "0x0.004p-14");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HexFloat<T>(pub(crate) T);
+
+/// Writes the value `bits` of `fmt` in hexadecimal
+pub(crate) fn write(fmt: Format, bits: u32, upper: bool, f: &mut Formatter<'_>) -> fmt::Result {
+    let abs = bits & !fmt.sign_mask();
+    let nonnegative = bits & fmt.sign_mask() == 0;
+    let (nan, inf) = if upper {
+        ("NAN", "INF")
+    } else {
+        ("nan", "inf")
+    };
+    if abs > fmt.exp_mask() {
+        return f.pad(nan);
+    }
+    if abs == fmt.exp_mask() {
+        return f.pad_integral(nonnegative, "", inf);
+    }
+
+    let exp = (abs >> fmt.man_bits) as i32;
+    let man = abs & fmt.man_mask();
+    let (lead, exp) = match (exp, man) {
+        (0, 0) => (b'0', 0),
+        (0, _) => (b'0', fmt.emin()),
+        _ => (b'1', exp - fmt.bias),
+    };
+
+    // Widen the fraction to whole hex digits
+    let pad = (4 - fmt.man_bits % 4) % 4;
+    let digits = (fmt.man_bits + pad) / 4;
+    let mut frac = man << pad;
+    let mut digits = if frac == 0 { 0 } else { digits };
+    while frac != 0 && frac & 0xF == 0 {
+        frac >>= 4;
+        digits -= 1;
+    }
+
+    let hex = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let mut buf = [0u8; 32];
+    let mut len = 0;
+    let mut push = |byte: u8| {
+        buf[len] = byte;
+        len += 1;
+    };
+    push(b'0');
+    push(if upper { b'X' } else { b'x' });
+    push(lead);
+    if digits > 0 {
+        push(b'.');
+        for i in (0..digits).rev() {
+            push(hex[((frac >> (i * 4)) & 0xF) as usize]);
+        }
+    }
+    push(if upper { b'P' } else { b'p' });
+    let exp = if exp < 0 {
+        push(b'-');
+        -exp as u32
+    } else {
+        push(b'+');
+        exp as u32
+    };
+    if exp >= 100 {
+        push(b'0' + (exp / 100) as u8);
+    }
+    if exp >= 10 {
+        push(b'0' + (exp / 10 % 10) as u8);
+    }
+    push(b'0' + (exp % 10) as u8);
+
+    // Only ASCII is ever written
+    f.pad_integral(nonnegative, "", core::str::from_utf8(&buf[..len]).unwrap())
+}
+
+macro_rules! impl_hex_float {
+    ($ty:ty, $fmt:expr) => {
+        impl fmt::Display for HexFloat<$ty> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write($fmt, self.0.to_bits() as u32, false, f)
+            }
+        }
+
+        impl fmt::LowerHex for HexFloat<$ty> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write($fmt, self.0.to_bits() as u32, false, f)
+            }
+        }
+
+        impl fmt::UpperHex for HexFloat<$ty> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write($fmt, self.0.to_bits() as u32, true, f)
+            }
+        }
+    };
+}
+
+impl_hex_float!(f16, softfloat::F16);
+impl_hex_float!(bf16, softfloat::BF16);
+
+/// Number of significant hex digits kept exactly; anything beyond only affects rounding
+const MAX_DIGITS: u32 = 15;
+
+/// Parses a hexadecimal floating point number and rounds it to the nearest value of `fmt`, ties to
+/// even
+///
+/// The syntax is an optional sign, `0x` or `0X`, hex digits with an optional point, and an
+/// optional binary exponent introduced by `p` or `P`. `inf`, `infinity` and `nan` are accepted
+/// like in decimal parsing.
+pub(crate) fn parse(fmt: Format, src: &[u8]) -> Result<u32, ParseHalfError> {
+    let invalid = ParseHalfError::new(ParseHalfErrorKind::Invalid);
+    if src.is_empty() {
+        return Err(ParseHalfError::new(ParseHalfErrorKind::Empty));
+    }
+    let (sign, src) = match src[0] {
+        b'-' => (true, &src[1..]),
+        b'+' => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let sign_bits = if sign { fmt.sign_mask() } else { 0 };
+    if src.eq_ignore_ascii_case(b"inf") || src.eq_ignore_ascii_case(b"infinity") {
+        return Ok(sign_bits | fmt.exp_mask());
+    }
+    if src.eq_ignore_ascii_case(b"nan") {
+        return Ok(sign_bits | fmt.default_nan());
+    }
+    let src = match src {
+        [b'0', b'x', rest @ ..] | [b'0', b'X', rest @ ..] => rest,
+        _ => return Err(invalid),
+    };
+
+    let mut man = 0u64;
+    let mut count = 0;
+    let mut sticky = false;
+    // Binary exponent adjustment from dropped integer digits and kept fraction digits
+    let mut exp = 0i32;
+    let mut seen_digit = false;
+    let mut seen_point = false;
+    let mut i = 0;
+    while i < src.len() {
+        let c = src[i];
+        if c == b'.' && !seen_point {
+            seen_point = true;
+        } else if let Some(d) = (c as char).to_digit(16) {
+            seen_digit = true;
+            if count == 0 && d == 0 {
+                // Leading zeros are not significant
+                if seen_point {
+                    exp = exp.saturating_sub(4);
+                }
+            } else if count < MAX_DIGITS {
+                man = (man << 4) | d as u64;
+                count += 1;
+                if seen_point {
+                    exp = exp.saturating_sub(4);
+                }
+            } else {
+                sticky |= d != 0;
+                if !seen_point {
+                    exp = exp.saturating_add(4);
+                }
+            }
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    if !seen_digit {
+        return Err(invalid);
+    }
+
+    if i < src.len() {
+        if src[i] != b'p' && src[i] != b'P' {
+            return Err(invalid);
+        }
+        let (exp_sign, digits) = match &src[i + 1..] {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            rest => (false, rest),
+        };
+        if digits.is_empty() {
+            return Err(invalid);
+        }
+        let mut e = 0i32;
+        for &c in digits {
+            if !c.is_ascii_digit() {
+                return Err(invalid);
+            }
+            e = e.saturating_mul(10).saturating_add((c - b'0') as i32);
+        }
+        exp = if exp_sign {
+            exp.saturating_sub(e)
+        } else {
+            exp.saturating_add(e)
+        };
+    }
+
+    if man == 0 {
+        return Ok(sign_bits);
+    }
+    let man = man << 2 | sticky as u64;
+    Ok(softfloat::round_pack(
+        fmt,
+        RoundingMode::NearestTiesToEven,
+        sign,
+        man,
+        exp.saturating_sub(2),
+        &mut Status::default(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::softfloat::{BF16, F16};
+    use std::format;
+
+    #[test]
+    fn format() {
+        let cases: &[(f16, &str)] = &[
+            (f16::ONE, "0x1p+0"),
+            (f16::from_f32(12.0), "0x1.8p+3"),
+            (f16::from_f32(-0.1), "-0x1.998p-4"),
+            (f16::MAX, "0x1.ffcp+15"),
+            (f16::MIN_POSITIVE, "0x1p-14"),
+            (f16::MAX_SUBNORMAL, "0x0.ffcp-14"),
+            (f16::MIN_POSITIVE_SUBNORMAL, "0x0.004p-14"),
+            (f16::ZERO, "0x0p+0"),
+            (f16::NEG_ZERO, "-0x0p+0"),
+            (f16::INFINITY, "inf"),
+            (f16::NAN, "nan"),
+        ];
+        for &(x, s) in cases {
+            assert_eq!(format!("{}", x.to_hex()), s);
+        }
+        assert_eq!(format!("{:X}", f16::from_f32(-0.1).to_hex()), "-0X1.998P-4");
+        assert_eq!(format!("{:+}", f16::ONE.to_hex()), "+0x1p+0");
+        assert_eq!(format!("{:>8}", f16::ONE.to_hex()), "  0x1p+0");
+        assert_eq!(format!("{}", bf16::MAX.to_hex()), "0x1.fep+127");
+        assert_eq!(
+            format!("{}", bf16::MIN_POSITIVE_SUBNORMAL.to_hex()),
+            "0x0.02p-126"
+        );
+    }
+
+    #[test]
+    fn parse_syntax_and_rounding() {
+        let ok = |src: &str| parse(F16, src.as_bytes()).unwrap();
+        assert_eq!(ok("0x1p0"), 0x3C00);
+        assert_eq!(ok("0X1.8P+3"), 0x4A00);
+        assert_eq!(ok("-0x.8p1"), 0xBC00);
+        assert_eq!(ok("0x10"), 0x4C00);
+        assert_eq!(ok("0x0.0000000000000000001p+76"), 0x3C00);
+        assert_eq!(ok("+0x0p-99999999999"), 0x0000);
+        assert_eq!(ok("-inf"), 0xFC00);
+        assert!(F16.is_nan(ok("NaN")));
+        // Halfway between 1 and the next f16, and just above it
+        assert_eq!(ok("0x1.002p0"), 0x3C00);
+        assert_eq!(ok("0x1.0020000000000000000001p0"), 0x3C01);
+        assert_eq!(ok("0x1.006p0"), 0x3C02);
+        assert_eq!(ok("0x1p16"), 0x7C00);
+        assert_eq!(ok("0x1p-25"), 0x0000);
+        assert_eq!(ok("0x1.000001p-25"), 0x0001);
+        assert_eq!(parse(BF16, b"0x1.01p0"), Ok(0x3F80));
+        assert_eq!(parse(BF16, b"0x1p-133"), Ok(0x0001));
+
+        assert_eq!(
+            parse(F16, b"").unwrap_err().kind(),
+            ParseHalfErrorKind::Empty
+        );
+        for src in &[
+            "1.5", "0x", "0x.", "0xp1", "0x1p", "0x1p+", "0x1g", "0x1.0.0", "x1", "0x1p1.5",
+        ] {
+            assert_eq!(
+                parse(F16, src.as_bytes()).unwrap_err().kind(),
+                ParseHalfErrorKind::Invalid,
+                "{}",
+                src
+            );
+        }
+    }
+
+    #[test]
+    fn exhaustive_round_trip() {
+        for bits in 0..=0xFFFFu16 {
+            for &fmt in &[F16, BF16] {
+                // Uppercase for bf16 to cover both spellings
+                let s = if fmt == F16 {
+                    format!("{}", f16::from_bits(bits).to_hex())
+                } else {
+                    format!("{:X}", bf16::from_bits(bits).to_hex())
+                };
+                let parsed = parse(fmt, s.as_bytes()).unwrap();
+                if fmt.is_nan(bits as u32) {
+                    assert!(fmt.is_nan(parsed), "{}", s);
+                } else {
+                    assert_eq!(parsed, bits as u32, "{}", s);
+                }
+            }
+        }
+    }
+}
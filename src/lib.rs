@@ -8,11 +8,28 @@
 //! This crate also provides a [`bf16`] type, an alternative 16-bit floating point format. The
 //! [`bfloat16`] format is a truncated IEEE 754 standard `binary32` float that preserves the
 //! exponent to allow the same range as [`f32`] but with only 8 bits of precision (instead of 11
-//! bits for [`f16`]). See the [`bf16`] type for details.
+//! bits for [`f16`]). See the [`bf16`] type for details. Two further 16-bit formats are supported
+//! for conversion and storage: [`f16ahp`] implements the Arm alternative half-precision format,
+//! which has no infinities or NaN, and [`dlfloat16`] implements IBM's DLFloat16 format.
 //!
-//! Because [`f16`] and [`bf16`] are primarily for efficient storage, floating point operations such
-//! as addition, multiplication, etc. are not implemented. Operations should be performed with
-//! [`f32`] or higher-precision types and converted to/from [`f16`] or [`bf16`] as necessary.
+//! For machine learning workloads, the 8-bit floating point types [`f8e4m3`] and [`f8e5m2`]
+//! implement the `E4M3` and `E5M2` formats of the OCP 8-bit floating point specification, and
+//! [`f8e4m3fnuz`] and [`f8e5m2fnuz`] implement the variants without infinities or negative zero
+//! used by AMD and Graphcore hardware. These support conversions, comparisons and formatting, but
+//! no arithmetic. The [`mx`] module encodes and decodes the OCP Microscaling block formats, which
+//! store blocks of 8, 6 or 4-bit elements with a shared power-of-two scale. Other formats of up to
+//! 16 bits can be described with the const generic [`Minifloat`][minifloat::Minifloat] type.
+//! The [`posit`] module provides the tapered-precision posit types [`p8`], [`p16`] and [`p32`],
+//! with quire accumulators for exact dot products.
+//! The [`tf32`] type rounds [`f32`] values to NVIDIA's TensorFloat-32 format, to reproduce the
+//! inputs of tensor core matrix multiplications on the CPU.
+//!
+//! Because [`f16`] and [`bf16`] are primarily for efficient storage, only basic arithmetic is
+//! provided. Addition, subtraction, multiplication, division, remainder, square root and fused
+//! multiply-add are computed directly on the 16-bit representation in software and are correctly
+//! rounded, so they give bit-identical results on every platform. Other operations should be
+//! performed with [`f32`] or higher-precision types and converted to/from [`f16`] or [`bf16`] as
+//! necessary.
 //!
 //! This crate also provides a [`mod@slice`] module for zero-copy in-place conversions of [`u16`]
 //! slices to both [`f16`] and [`bf16`], as well as efficient vectorized conversions of larger
@@ -27,6 +44,14 @@
 //! supports it, the [`mod@slice`] trait conversions will use vectorized SIMD intructions for
 //! increased efficiency.
 //!
+//! NaN payloads are handled the same way by the software and hardware conversions. Converting a
+//! NaN between [`f16`], [`bf16`], [`f32`] and [`f64`], including with the rounding and
+//! [`mod@slice`] conversions, gives a quiet NaN with the same sign. Its payload is the most
+//! significant bits of the source payload, truncated when narrowing and padded with zeros when
+//! widening, so a NaN converted to a wider format and back is unchanged apart from being made
+//! quiet. A narrowing conversion whose discarded payload bits were the only nonzero ones gives
+//! the quiet NaN with a zero payload. See [`f16::payload`] for what the payload consists of.
+//!
 //! Support for [`serde`] crate `Serialize` and `Deserialize` traits is provided when the `serde`
 //! feature is enabled. This adds a dependency on [`serde`] crate so is an optional cargo feature.
 //! Support for [`bytemuck`] crate `Zeroable` and `Pod` traits is provided with the `bytemuck`
@@ -68,21 +93,40 @@
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 
+mod alt16;
 mod bfloat;
 mod binary16;
+mod fp8;
+mod hex;
 #[cfg(feature = "num-traits")]
 mod num_traits;
+mod ord;
+mod parse;
+mod shortest;
+mod softfloat;
+mod tfloat32;
 
+pub mod minifloat;
+pub mod mx;
+pub mod posit;
 pub mod slice;
 #[cfg(any(feature = "alloc", feature = "std"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub mod vec;
 
+pub use alt16::{dlfloat16, f16ahp};
 pub use bfloat::bf16;
 #[doc(hidden)]
 #[allow(deprecated)]
 pub use binary16::consts;
 pub use binary16::f16;
+pub use fp8::{f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz};
+pub use hex::HexFloat;
+pub use ord::{Canonical, TotalOrd};
+pub use parse::{ParseHalfError, ParseHalfErrorKind};
+pub use posit::{p16, p32, p8};
+pub use softfloat::{FpClass, RoundingMode, Saturation, Status};
+pub use tfloat32::tf32;
 
 /// A collection of the most used items and traits in this crate for easy importing.
 ///
@@ -94,8 +138,12 @@ pub use binary16::f16;
 pub mod prelude {
     #[doc(no_inline)]
     pub use crate::{
-        bf16, f16,
-        slice::{HalfBitsSliceExt, HalfFloatSliceExt},
+        bf16, dlfloat16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz, p16, p32, p8,
+        slice::{
+            Fp8BitsSliceExt, Fp8FloatSliceExt, HalfBitsSliceExt, HalfFloatSliceExt, PositSliceExt,
+            Tf32FloatSliceExt, Tf32SliceExt,
+        },
+        tf32, Canonical, FpClass, RoundingMode, Saturation, Status, TotalOrd,
     };
 
     #[cfg(any(feature = "alloc", feature = "std"))]
@@ -106,10 +154,33 @@ pub mod prelude {
 
 // Keep this module private to crate
 pub(crate) mod private {
-    use crate::{bf16, f16};
+    use crate::{
+        bf16, dlfloat16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz,
+        posit::{Posit16, Posit8},
+    };
 
     pub trait SealedHalf {}
 
     impl SealedHalf for f16 {}
     impl SealedHalf for bf16 {}
+    impl SealedHalf for f16ahp {}
+    impl SealedHalf for dlfloat16 {}
+    impl<const ES: u32> SealedHalf for Posit16<ES> {}
+
+    pub trait SealedFp8 {}
+
+    impl SealedFp8 for f8e4m3 {}
+    impl SealedFp8 for f8e5m2 {}
+    impl SealedFp8 for f8e4m3fnuz {}
+    impl SealedFp8 for f8e5m2fnuz {}
+    impl<const ES: u32> SealedFp8 for Posit8<ES> {}
+}
+
+/// Fails const evaluation if `condition` is false
+///
+/// `assert!` is not usable in constants on the minimum supported Rust version, so an out of
+/// bounds index reports the failure instead.
+#[inline]
+pub(crate) const fn const_assert(condition: bool) {
+    [()][!condition as usize]
 }
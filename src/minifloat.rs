@@ -0,0 +1,705 @@
+//! A floating point type with a configurable layout
+//!
+//! [`Minifloat`] covers binary formats of up to 16 bits that are not worth a dedicated type, such as
+//! the IEEE P3109 8-bit formats or experimental layouts. The exponent width, fraction width, bias
+//! and the encoding of infinities and NaN are const generic parameters, and all conversions,
+//! comparisons, formatting and parsing are correctly rounded for every supported layout.
+//!
+//! The encoding is one of the constants [`IEEE`], [`FN`], [`FNUZ`] and [`FINITE`]:
+//!
+//! | Encoding   | Infinities | NaN                        | Negative zero | Overflow becomes |
+//! |------------|------------|----------------------------|---------------|------------------|
+//! | [`IEEE`]   | yes        | all-ones exponent          | yes           | ±∞               |
+//! | [`FN`]     | no         | all-ones exponent and fraction | yes       | NaN              |
+//! | [`FNUZ`]   | no         | the negative zero pattern  | no            | NaN              |
+//! | [`FINITE`] | no         | no                         | yes           | ±[`MAX`][Minifloat::MAX] |
+//!
+//! With these, [`f16`][struct@f16] is `Minifloat<5, 10, 15, IEEE>`, [`bf16`] is `Minifloat<8, 7,
+//! 127, IEEE>`, [`f8e4m3`] is `Minifloat<4, 3, 7, FN>`, and [`f16ahp`] is `Minifloat<5, 10, 15,
+//! FINITE>`. The concrete types convert losslessly
+//! to and from their equivalent [`Minifloat`] with [`From`].
+//!
+//! # Supported layouts
+//!
+//! A layout must have at least one exponent bit, at most 10 fraction bits and at most 16 bits in
+//! total including the sign. Every finite value must be within the range of [`f32`], so the
+//! largest must be below 2<sup>128</sup> and the smallest positive value must be at least
+//! 2<sup>-149</sup>. [`IEEE`] layouts need a fraction bit to tell NaN from ∞. Using a type with an
+//! unsupported layout fails to compile:
+//!
+//! ```compile_fail
+//! use half::minifloat::{Minifloat, IEEE};
+//!
+//! // The largest finite value would be 2^255
+//! let x = Minifloat::<8, 7, 0, IEEE>::from_f32(1.0);
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use half::minifloat::{Minifloat, FINITE, IEEE};
+//!
+//! // A 6-bit format with 3 exponent bits and 2 fraction bits, without infinities or NaN
+//! type E3M2 = Minifloat<3, 2, 3, FINITE>;
+//!
+//! let x = E3M2::from_f32(11.0);
+//! assert_eq!(x.to_f32(), 12.0);
+//! assert_eq!(x.to_string(), "12");
+//! assert_eq!(E3M2::from_f32(1000.0), E3M2::MAX);
+//! assert_eq!(E3M2::MAX.to_f32(), 28.0);
+//!
+//! // The same engine reproduces the standard types bit for bit
+//! let y = Minifloat::<5, 10, 15, IEEE>::from_f32(0.1);
+//! assert_eq!(y.to_bits(), half::f16::from_f32(0.1).to_bits());
+//! ```
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+    },
+    num::FpCategory,
+    ops::Neg,
+    str::FromStr,
+};
+
+use crate::{
+    bf16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz, parse, shortest,
+    softfloat::{self, Encoding, Format, RoundingMode},
+    ParseHalfError, Saturation,
+};
+
+/// IEEE 754 style encoding: the all-ones exponent holds ±∞ and NaN
+pub const IEEE: u8 = 0;
+/// Finite encoding with NaN: the all-ones exponent holds normal values, except that an all-ones
+/// exponent and fraction is NaN, as in [`f8e4m3`]
+pub const FN: u8 = 1;
+/// Finite encoding with NaN and an unsigned zero: the negative zero pattern is the only NaN, as in
+/// [`f8e4m3fnuz`]
+pub const FNUZ: u8 = 2;
+/// Finite encoding without NaN: every pattern is a number, overflow saturates to the largest
+/// finite magnitude, and NaN converts to zero
+pub const FINITE: u8 = 3;
+
+/// Returns the format of a [`Minifloat`] layout, failing const evaluation if it is not supported
+const fn format(exp_bits: u32, man_bits: u32, bias: i32, encoding: u8) -> Format {
+    let fmt = Format {
+        exp_bits,
+        man_bits,
+        bias,
+        encoding: match encoding {
+            IEEE => Encoding::Ieee,
+            FN => Encoding::Fn,
+            FNUZ => Encoding::Fnuz,
+            _ => Encoding::Alternative,
+        },
+    };
+    crate::const_assert(encoding <= FINITE && is_supported(fmt));
+    fmt
+}
+
+const fn is_supported(fmt: Format) -> bool {
+    let Format {
+        exp_bits, man_bits, ..
+    } = fmt;
+    if exp_bits == 0 || man_bits > 10 || exp_bits + man_bits > 15 {
+        return false;
+    }
+    if let Encoding::Ieee = fmt.encoding {
+        if man_bits == 0 {
+            return false;
+        }
+    }
+    fmt.max() != 0 && fmt.emax() <= 127 && fmt.emin() - man_bits as i32 >= -149
+}
+
+/// A binary floating point type with `EXP_BITS` exponent bits biased by `BIAS`, `MAN_BITS`
+/// fraction bits and a sign bit
+///
+/// `ENCODING` is one of [`IEEE`], [`FN`], [`FNUZ`] or [`FINITE`], and selects how infinities and
+/// NaN are represented. See the [module documentation][self] for the supported layouts.
+///
+/// The value is stored in the low `1 + EXP_BITS + MAN_BITS` bits of a [`u16`], with the sign bit
+/// at the top. Like the other types of this crate, [`Minifloat`] is intended for storage: it
+/// provides conversions, classification, comparisons, formatting and parsing, but no arithmetic.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::minifloat::{Minifloat, FN};
+///
+/// // IEEE P3109 binary8p3 without infinities: 5 exponent bits and 2 fraction bits, bias 16
+/// type P3 = Minifloat<5, 2, 16, FN>;
+///
+/// assert_eq!(P3::MAX.to_f32(), 49152.0);
+/// assert_eq!(P3::from_f32(1.2).to_f32(), 1.25);
+/// assert!(P3::from_f32(1e6).is_nan());
+/// assert_eq!("0.3".parse::<P3>().unwrap().to_f32(), 0.3125);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Minifloat<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8>(
+    u16,
+);
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8>
+    Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    const FORMAT: Format = format(EXP_BITS, MAN_BITS, BIAS, ENCODING);
+
+    /// Number of significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = MAN_BITS + 1;
+    /// Maximum possible power of 2 exponent
+    pub const MAX_EXP: i32 = Self::FORMAT.emax() + 1;
+    /// One greater than the minimum possible normal power of 2 exponent
+    pub const MIN_EXP: i32 = Self::FORMAT.emin() + 1;
+    /// The radix or base of the internal representation
+    pub const RADIX: u32 = 2;
+
+    /// Zero, which is positive
+    pub const ZERO: Self = Self(0);
+    /// Largest finite value
+    pub const MAX: Self = Self(Self::FORMAT.max() as u16);
+    /// Smallest finite value
+    pub const MIN: Self = Self((Self::FORMAT.sign_mask() | Self::FORMAT.max()) as u16);
+    /// Smallest positive normal value
+    pub const MIN_POSITIVE: Self = Self((1 << MAN_BITS) as u16);
+
+    /// Constructs a value from the raw bits
+    ///
+    /// Bits above the width of the format are ignored.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits & (Self::FORMAT.sign_mask() * 2 - 1) as u16)
+    }
+
+    /// Converts the value into its raw bits
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Constructs a value from a 32-bit floating point value
+    ///
+    /// The value is rounded to nearest, ties to even. Values too large in magnitude, and infinite
+    /// inputs, become whatever the encoding uses for overflow: ±∞ for [`IEEE`], NaN for [`FN`] and
+    /// [`FNUZ`], and ±[`MAX`][Self::MAX] for [`FINITE`]. NaN inputs become NaN, or zero for
+    /// [`FINITE`].
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        Self(softfloat::from_f32(Self::FORMAT, value, RoundingMode::NearestTiesToEven) as u16)
+    }
+
+    /// Constructs a value from a 64-bit floating point value
+    ///
+    /// The value is rounded to nearest, ties to even, exactly once. Overflow is handled like
+    /// [`from_f32`][Self::from_f32].
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self(softfloat::from_f64(Self::FORMAT, value, RoundingMode::NearestTiesToEven) as u16)
+    }
+
+    /// Constructs a value from a 32-bit floating point value, clamping values that are out of
+    /// range to the largest finite magnitude
+    ///
+    /// Finite values are rounded to nearest exactly like [`from_f32`][Self::from_f32], except that
+    /// results that would overflow become [`MAX`][Self::MAX] or [`MIN`][Self::MIN]. Whether ±∞ is
+    /// also clamped, and whether NaN is converted to zero, is controlled by `saturation`.
+    #[inline]
+    pub fn from_f32_saturating(value: f32, saturation: Saturation) -> Self {
+        Self::from_f64_saturating(value as f64, saturation)
+    }
+
+    /// Constructs a value from a 64-bit floating point value, clamping values that are out of
+    /// range to the largest finite magnitude
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating] for details.
+    #[inline]
+    pub fn from_f64_saturating(value: f64, saturation: Saturation) -> Self {
+        let bits = Self::from_f64(value).0 as u32;
+        Self(saturation.apply(Self::FORMAT, value, bits) as u16)
+    }
+
+    /// Converts the value into an [`f32`] value
+    ///
+    /// This conversion is lossless as all values can be represented exactly in [`f32`].
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        softfloat::to_f32(Self::FORMAT, self.0 as u32)
+    }
+
+    /// Converts the value into an [`f64`] value
+    ///
+    /// This conversion is lossless as all values can be represented exactly in [`f64`].
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        softfloat::to_f64(Self::FORMAT, self.0 as u32)
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        Self::FORMAT.is_nan(self.0 as u32)
+    }
+
+    /// Returns `true` if this value is ±∞ and `false` otherwise
+    ///
+    /// This is always `false` for encodings without infinities.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        Self::FORMAT.is_infinite(self.0 as u32)
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        !self.is_nan() && !self.is_infinite()
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN
+    #[inline]
+    pub const fn is_normal(self) -> bool {
+        self.0 as u32 & Self::FORMAT.exp_mask() != 0 && self.is_finite()
+    }
+
+    /// Returns the floating point category of the number
+    ///
+    /// If only one property is going to be tested, it is generally faster to use the specific
+    /// predicate instead.
+    pub fn classify(self) -> FpCategory {
+        if self.is_nan() {
+            FpCategory::Nan
+        } else if self.is_infinite() {
+            FpCategory::Infinite
+        } else if self.0 as u32 & Self::FORMAT.exp_mask() != 0 {
+            FpCategory::Normal
+        } else if self.0 as u32 & Self::FORMAT.man_mask() != 0 {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Zero
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including +0.0, NaNs with a
+    /// positive sign bit and +∞
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 as u32 & Self::FORMAT.sign_mask() == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including −0.0, NaNs with a
+    /// negative sign bit and −∞
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 as u32 & Self::FORMAT.sign_mask() != 0
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8>
+    From<Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>> for f32
+{
+    #[inline]
+    fn from(x: Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8>
+    From<Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>> for f64
+{
+    #[inline]
+    fn from(x: Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> PartialEq
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> PartialOrd
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            let key = |bits: u16| softfloat::signed_magnitude(Self::FORMAT, bits as u32);
+            Some(key(self.0).cmp(&key(other.0)))
+        }
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> FromStr
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    type Err = ParseHalfError;
+    fn from_str(src: &str) -> Result<Self, ParseHalfError> {
+        parse::parse(Self::FORMAT, src.as_bytes(), false).map(|bits| Self(bits as u16))
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> Debug
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            Debug::fmt(&self.to_f32(), f)
+        } else {
+            shortest::debug(Self::FORMAT, self.0 as u32, f)
+        }
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> Display
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            Display::fmt(&self.to_f32(), f)
+        } else {
+            shortest::display(Self::FORMAT, self.0 as u32, f)
+        }
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> LowerExp
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            LowerExp::fmt(&self.to_f32(), f)
+        } else {
+            shortest::exp(Self::FORMAT, self.0 as u32, false, f)
+        }
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> UpperExp
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            UpperExp::fmt(&self.to_f32(), f)
+        } else {
+            shortest::exp(Self::FORMAT, self.0 as u32, true, f)
+        }
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> Binary
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:b}", self.0)
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> Octal
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:o}", self.0)
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> LowerHex
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> UpperHex
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+impl<const EXP_BITS: u32, const MAN_BITS: u32, const BIAS: i32, const ENCODING: u8> Neg
+    for Minifloat<EXP_BITS, MAN_BITS, BIAS, ENCODING>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(Self::FORMAT.neg(self.0 as u32) as u16)
+    }
+}
+
+macro_rules! impl_from_concrete {
+    ($ty:ident, $bits:ty, $fmt:expr, $exp_bits:expr, $man_bits:expr, $bias:expr, $encoding:ident) => {
+        impl From<$ty> for Minifloat<$exp_bits, $man_bits, $bias, $encoding> {
+            #[inline]
+            fn from(x: $ty) -> Self {
+                // The bits are copied as they are, so both types must share the same format
+                const SAME_FORMAT: () = crate::const_assert(
+                    Minifloat::<$exp_bits, $man_bits, $bias, $encoding>::FORMAT.same($fmt),
+                );
+                let () = SAME_FORMAT;
+                Self(u16::from(x.to_bits()))
+            }
+        }
+
+        impl From<Minifloat<$exp_bits, $man_bits, $bias, $encoding>> for $ty {
+            #[allow(trivial_numeric_casts)]
+            #[inline]
+            fn from(x: Minifloat<$exp_bits, $man_bits, $bias, $encoding>) -> $ty {
+                $ty::from_bits(x.to_bits() as $bits)
+            }
+        }
+    };
+}
+
+impl_from_concrete!(f16, u16, softfloat::F16, 5, 10, 15, IEEE);
+impl_from_concrete!(bf16, u16, softfloat::BF16, 8, 7, 127, IEEE);
+impl_from_concrete!(f16ahp, u16, softfloat::AHP, 5, 10, 15, FINITE);
+impl_from_concrete!(f8e4m3, u8, softfloat::E4M3, 4, 3, 7, FN);
+impl_from_concrete!(f8e5m2, u8, softfloat::E5M2, 5, 2, 15, IEEE);
+impl_from_concrete!(f8e4m3fnuz, u8, softfloat::E4M3FNUZ, 4, 3, 8, FNUZ);
+impl_from_concrete!(f8e5m2fnuz, u8, softfloat::E5M2FNUZ, 5, 2, 16, FNUZ);
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use quickcheck_macros::quickcheck;
+    use std::{format, string::ToString};
+
+    type F16 = Minifloat<5, 10, 15, IEEE>;
+    type BF16 = Minifloat<8, 7, 127, IEEE>;
+    type F16AHP = Minifloat<5, 10, 15, FINITE>;
+    type E4M3 = Minifloat<4, 3, 7, FN>;
+    type E5M2 = Minifloat<5, 2, 15, IEEE>;
+    type E4M3FNUZ = Minifloat<4, 3, 8, FNUZ>;
+    type E5M2FNUZ = Minifloat<5, 2, 16, FNUZ>;
+    type E2M1 = Minifloat<2, 1, 1, FINITE>;
+    // Reaches the smallest f32 subnormal
+    type Tiny = Minifloat<8, 7, 143, IEEE>;
+    // Reaches the largest f32 binade, with no fraction bits
+    type Wide = Minifloat<7, 0, 0, FINITE>;
+
+    fn next_up(x: f64) -> f64 {
+        f64::from_bits(x.to_bits() + 1)
+    }
+
+    fn next_down(x: f64) -> f64 {
+        f64::from_bits(x.to_bits() - 1)
+    }
+
+    /// Checks every pattern of a layout: conversions, rounding between neighbours and formatting
+    fn check_layout<const E: u32, const M: u32, const B: i32, const N: u8>() {
+        let width = 1 + E + M;
+        let fmt = Minifloat::<E, M, B, N>::FORMAT;
+        for bits in 0..(1u32 << width) {
+            let x = Minifloat::<E, M, B, N>::from_bits(bits as u16);
+            let value = x.to_f64();
+            if x.is_nan() {
+                assert!(value.is_nan());
+                assert!(Minifloat::<E, M, B, N>::from_f64(value).is_nan());
+                assert_eq!(x.to_string(), "NaN");
+                continue;
+            }
+            assert_eq!(x.to_f32() as f64, value);
+            let string = x.to_string();
+            let parsed: Minifloat<E, M, B, N> = string.parse().unwrap();
+            if N == FNUZ && bits == 0 {
+                continue;
+            }
+            assert_eq!(Minifloat::<E, M, B, N>::from_f64(value).0, x.0, "{}", value);
+            assert_eq!(parsed.0, x.0, "{}", string);
+
+            // Halfway to the next larger magnitude rounds to the even pattern
+            let abs = bits & !fmt.sign_mask();
+            if abs < fmt.max() {
+                let next = Minifloat::<E, M, B, N>::from_bits((bits + 1) as u16).to_f64();
+                let mid = (value + next) / 2.0;
+                let even = if abs & 1 == 0 { bits } else { bits + 1 };
+                let round = |v| Minifloat::<E, M, B, N>::from_f64(v).0 as u32;
+                if abs != 0 || N != FNUZ {
+                    assert_eq!(round(mid), even, "{}", mid);
+                    assert_eq!(round(next_down(mid.abs()).copysign(mid)), bits);
+                }
+                assert_eq!(round(next_up(mid.abs()).copysign(mid)), bits + 1);
+            }
+        }
+
+        let max = Minifloat::<E, M, B, N>::MAX;
+        assert_eq!(max.to_bits() as u32, fmt.max());
+        assert!(max.is_finite());
+        assert_eq!(Minifloat::<E, M, B, N>::MIN, -max);
+        let overflow = Minifloat::<E, M, B, N>::from_f64(max.to_f64() * 2.0);
+        assert_eq!(overflow.0 as u32, fmt.infinity());
+        let saturated = Minifloat::<E, M, B, N>::from_f64_saturating(
+            max.to_f64() * -2.0,
+            Saturation::default(),
+        );
+        assert_eq!(saturated, Minifloat::<E, M, B, N>::MIN);
+    }
+
+    #[test]
+    fn exhaustive_layouts() {
+        check_layout::<5, 10, 15, IEEE>();
+        check_layout::<8, 7, 127, IEEE>();
+        check_layout::<4, 3, 7, FN>();
+        check_layout::<5, 2, 16, FNUZ>();
+        check_layout::<2, 3, 1, FINITE>();
+        check_layout::<2, 1, 1, FINITE>();
+        check_layout::<3, 0, 3, FN>();
+        check_layout::<8, 7, 143, IEEE>();
+        check_layout::<7, 0, 0, FINITE>();
+    }
+
+    #[test]
+    fn matches_concrete_types() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let y = F16::from(x);
+            assert_eq!(f16::from(y).to_bits(), bits);
+            assert_eq!(y.to_f32().to_bits(), x.to_f32().to_bits());
+            assert_eq!(y.classify(), x.classify());
+            if !x.is_nan() {
+                assert_eq!(y.to_string(), x.to_string());
+                assert_eq!(format!("{:e}", y), format!("{:e}", x));
+                assert_eq!(format!("{:?}", y), format!("{:?}", x));
+            }
+
+            let x = bf16::from_bits(bits);
+            let y = BF16::from(x);
+            assert_eq!(y.to_f32().to_bits(), x.to_f32().to_bits());
+            assert_eq!(y.classify(), x.classify());
+            if !x.is_nan() {
+                assert_eq!(y.to_string(), x.to_string());
+            }
+
+            let x = f16ahp::from_bits(bits);
+            let y = F16AHP::from(x);
+            assert_eq!(y.to_f32().to_bits(), x.to_f32().to_bits());
+            assert_eq!(y.to_string(), x.to_string());
+        }
+
+        for bits in 0..=u8::MAX {
+            let check = |a: f32, b: f32| assert!(a == b || a.is_nan() && b.is_nan());
+            check(
+                E4M3::from(f8e4m3::from_bits(bits)).to_f32(),
+                f8e4m3::from_bits(bits).to_f32(),
+            );
+            check(
+                E5M2::from(f8e5m2::from_bits(bits)).to_f32(),
+                f8e5m2::from_bits(bits).to_f32(),
+            );
+            check(
+                E4M3FNUZ::from(f8e4m3fnuz::from_bits(bits)).to_f32(),
+                f8e4m3fnuz::from_bits(bits).to_f32(),
+            );
+            check(
+                E5M2FNUZ::from(f8e5m2fnuz::from_bits(bits)).to_f32(),
+                f8e5m2fnuz::from_bits(bits).to_f32(),
+            );
+        }
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_matches_concrete_types(x: f32) -> bool {
+        let same = |a: u16, b: u16, nan: bool| a == b || nan;
+        same(
+            F16::from_f32(x).to_bits(),
+            f16::from_f32(x).to_bits(),
+            x.is_nan(),
+        ) && same(
+            BF16::from_f64(x as f64).to_bits(),
+            bf16::from_f64(x as f64).to_bits(),
+            x.is_nan(),
+        ) && same(
+            F16AHP::from_f32(x).to_bits(),
+            f16ahp::from_f32(x).to_bits(),
+            x.is_nan(),
+        ) && same(
+            E4M3::from_f32(x).to_bits(),
+            f8e4m3::from_f32(x).to_bits() as u16,
+            x.is_nan(),
+        ) && same(
+            E5M2FNUZ::from_f32(x).to_bits(),
+            f8e5m2fnuz::from_f32(x).to_bits() as u16,
+            false,
+        )
+    }
+
+    #[test]
+    fn encodings() {
+        assert_eq!(E2M1::from_f32(f32::NAN).to_bits(), 0);
+        assert_eq!(E2M1::from_f32(f32::NEG_INFINITY), E2M1::MIN);
+        assert_eq!(E2M1::MAX.to_f32(), 6.0);
+        assert!(E2M1::from_bits(0xFF).is_sign_negative());
+        assert_eq!(E2M1::from_bits(0xFF).to_bits(), 0xF);
+
+        assert_eq!(E5M2::from_f32(f32::INFINITY).to_f32(), f32::INFINITY);
+        assert!(E4M3::from_f32(f32::INFINITY).is_nan());
+        assert_eq!((-E4M3FNUZ::ZERO).to_bits(), 0);
+        assert_eq!(E4M3FNUZ::from_f32(-0.0).to_bits(), 0);
+
+        assert_eq!(Tiny::from_bits(1).to_f32(), f32::from_bits(1));
+        assert_eq!(Tiny::from_f32(f32::from_bits(1)).to_bits(), 1);
+        assert_eq!(Wide::MAX.to_f32(), 2f32.powi(127));
+        assert_eq!(Wide::from_f32(f32::MAX), Wide::MAX);
+        assert_eq!(Wide::from_f32(3.0).to_f32(), 4.0);
+        assert_eq!(Wide::from_f32(5.0).to_f32(), 4.0);
+    }
+
+    #[test]
+    fn constants_and_classify() {
+        assert_eq!(F16::MANTISSA_DIGITS, f16::MANTISSA_DIGITS);
+        assert_eq!(F16::MAX_EXP, f16::MAX_EXP);
+        assert_eq!(F16::MIN_EXP, f16::MIN_EXP);
+        assert_eq!(BF16::MAX_EXP, bf16::MAX_EXP);
+        assert_eq!(BF16::MIN_EXP, bf16::MIN_EXP);
+        assert_eq!(F16::MIN_POSITIVE.to_f32(), f16::MIN_POSITIVE.to_f32());
+        assert_eq!(E4M3::MAX_EXP, f8e4m3::MAX_EXP);
+        assert_eq!(E4M3::MIN.to_f32(), -448.0);
+        assert_eq!(E5M2FNUZ::MIN_EXP, f8e5m2fnuz::MIN_EXP);
+
+        assert_eq!(E2M1::from_f32(0.5).classify(), FpCategory::Subnormal);
+        assert_eq!(E2M1::from_f32(-0.0).classify(), FpCategory::Zero);
+        assert!(E2M1::MAX.is_normal());
+        assert!(!E4M3::from_f32(f32::NAN).is_normal());
+        assert!(E5M2::from_f32(1e9).is_infinite());
+    }
+
+    #[test]
+    fn compare_and_format() {
+        let values = [-6.0, -1.5, -0.5, 0.0, 0.5, 2.0, 6.0];
+        for (i, &a) in values.iter().enumerate() {
+            for (j, &b) in values.iter().enumerate() {
+                let (a, b) = (E2M1::from_f32(a), E2M1::from_f32(b));
+                assert_eq!(a.partial_cmp(&b), i.partial_cmp(&j));
+            }
+        }
+        assert_eq!(E2M1::from_f32(-0.0), E2M1::ZERO);
+        let nan = E4M3::from_f32(f32::NAN);
+        assert_ne!(nan, nan);
+        assert_eq!(nan.partial_cmp(&E4M3::ZERO), None);
+
+        assert_eq!(format!("{}", E4M3::MAX), "450");
+        assert_eq!(format!("{:?}", E4M3::MAX), "450.0");
+        assert_eq!(format!("{:.1}", E4M3::MAX), "448.0");
+        assert_eq!(format!("{:e}", E5M2::MAX), "6e4");
+        assert_eq!(format!("{:E}", E2M1::MIN), "-6E0");
+        assert_eq!(format!("{:x}", E2M1::MIN), "f");
+        assert_eq!(format!("{:b}", E4M3::MAX), "1111110");
+        assert_eq!(E5M2::from_f32(f32::NEG_INFINITY).to_string(), "-inf");
+        assert!("x".parse::<E2M1>().is_err());
+        assert_eq!("inf".parse::<E2M1>().unwrap(), E2M1::MAX);
+    }
+}
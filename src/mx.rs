@@ -0,0 +1,744 @@
+//! Encoding and decoding of the OCP Microscaling (MX) block formats
+//!
+//! The [OCP Microscaling Formats specification] stores a tensor as blocks of [`BLOCK_SIZE`]
+//! narrow elements that share one power-of-two scale. The scale is an [`E8M0`] value, and the
+//! elements are one of the types listed in [`MxFormat`]. A value is recovered by multiplying its
+//! element by the scale of its block.
+//!
+//! Encoded data is kept in two slices: one scale per block, and the packed element bits of all
+//! blocks. Elements narrower than a byte are packed least significant bits first, so for MXFP4 the
+//! first element of each byte is in its low nibble, and for MXFP6 every three bytes hold four
+//! elements. Use [`MxFormat::scales_len`] and [`MxFormat::data_len`] to size the slices. The last
+//! block may be shorter than [`BLOCK_SIZE`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use half::mx::{self, MxFormat, E8M0};
+//!
+//! let values = [0.5f32, -1.0, 3.0, 6.0, 0.1];
+//! let format = MxFormat::Fp4E2M1;
+//! let mut scales = [E8M0::ONE; 1];
+//! let mut data = [0u8; 3];
+//! assert_eq!(format.scales_len(values.len()), scales.len());
+//! assert_eq!(format.data_len(values.len()), data.len());
+//!
+//! mx::encode_f32(format, &values, &mut scales, &mut data);
+//! assert_eq!(scales[0], E8M0::ONE);
+//!
+//! let mut decoded = [0f32; 5];
+//! mx::decode_f32(format, &scales, &data, &mut decoded);
+//! assert_eq!(decoded, [0.5, -1.0, 3.0, 6.0, 0.0]);
+//! ```
+//!
+//! [OCP Microscaling Formats specification]: https://www.opencompute.org/documents/ocp-microscaling-formats-mx-v1-0-spec-final-pdf
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bf16, f16,
+    softfloat::{self, Format, RoundingMode, Status},
+    Saturation,
+};
+
+/// Number of elements that share one scale
+pub const BLOCK_SIZE: usize = 32;
+
+/// Element types of the microscaling formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MxFormat {
+    /// MXFP8 with [`f8e4m3`][crate::f8e4m3] elements
+    Fp8E4M3,
+    /// MXFP8 with [`f8e5m2`][crate::f8e5m2] elements
+    Fp8E5M2,
+    /// MXFP6 with `E2M3` elements: 2 exponent bits and 3 fraction bits, with a range of ±7.5
+    Fp6E2M3,
+    /// MXFP6 with `E3M2` elements: 3 exponent bits and 2 fraction bits, with a range of ±28
+    Fp6E3M2,
+    /// MXFP4 with `E2M1` elements: 2 exponent bits and 1 fraction bit, with a range of ±6
+    Fp4E2M1,
+    /// MXINT8 with two's complement 8-bit integer elements scaled by 2<sup>-6</sup>, with a range
+    /// of -2 to 1.984375
+    Int8,
+}
+
+impl MxFormat {
+    /// Returns the width of one element in bits
+    #[inline]
+    pub const fn element_bits(self) -> usize {
+        match self {
+            MxFormat::Fp8E4M3 | MxFormat::Fp8E5M2 | MxFormat::Int8 => 8,
+            MxFormat::Fp6E2M3 | MxFormat::Fp6E3M2 => 6,
+            MxFormat::Fp4E2M1 => 4,
+        }
+    }
+
+    /// Returns the number of scales needed for `len` elements
+    #[inline]
+    pub const fn scales_len(self, len: usize) -> usize {
+        match len % BLOCK_SIZE {
+            0 => len / BLOCK_SIZE,
+            _ => len / BLOCK_SIZE + 1,
+        }
+    }
+
+    /// Returns the number of bytes of packed element data needed for `len` elements
+    #[inline]
+    pub const fn data_len(self, len: usize) -> usize {
+        let bits = len * self.element_bits();
+        match bits % 8 {
+            0 => bits / 8,
+            _ => bits / 8 + 1,
+        }
+    }
+
+    /// The floating point element format, or `None` for integers
+    fn element(self) -> Option<Format> {
+        match self {
+            MxFormat::Fp8E4M3 => Some(softfloat::E4M3),
+            MxFormat::Fp8E5M2 => Some(softfloat::E5M2),
+            MxFormat::Fp6E2M3 => Some(softfloat::E2M3),
+            MxFormat::Fp6E3M2 => Some(softfloat::E3M2),
+            MxFormat::Fp4E2M1 => Some(softfloat::E2M1),
+            MxFormat::Int8 => None,
+        }
+    }
+
+    /// Unbiased exponent of the largest power of two an element can hold
+    fn emax(self) -> i32 {
+        match self.element() {
+            Some(fmt) => fmt.emax(),
+            None => 0,
+        }
+    }
+}
+
+/// An 8-bit power-of-two scale in the `E8M0` format of the microscaling specification
+///
+/// `E8M0` is an unsigned 8-bit exponent with a bias of 127, representing the values
+/// 2<sup>-127</sup> to 2<sup>127</sup>. The pattern `0xFF` is NaN. There is no zero, sign or
+/// fraction.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::mx::E8M0;
+///
+/// assert_eq!(E8M0::from_exponent(-3).to_f32(), 0.125);
+/// assert_eq!(E8M0::from_exponent(500), E8M0::MAX);
+/// assert_eq!(E8M0::NAN.exponent(), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct E8M0(u8);
+
+impl E8M0 {
+    /// The scale 1
+    pub const ONE: E8M0 = E8M0(127);
+    /// The largest scale, 2<sup>127</sup>
+    pub const MAX: E8M0 = E8M0(0xFE);
+    /// The smallest scale, 2<sup>-127</sup>
+    pub const MIN: E8M0 = E8M0(0);
+    /// Not a Number (NaN), which makes every value of its block NaN
+    pub const NAN: E8M0 = E8M0(0xFF);
+
+    /// Constructs a scale from the raw bits
+    #[inline]
+    pub const fn from_bits(bits: u8) -> E8M0 {
+        E8M0(bits)
+    }
+
+    /// Converts the scale into its raw bits
+    #[inline]
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    /// Constructs the scale 2<sup>`exp`</sup>, clamping `exp` to the range -127 to 127
+    #[inline]
+    pub fn from_exponent(exp: i32) -> E8M0 {
+        E8M0((clamp_exponent(exp) + 127) as u8)
+    }
+
+    /// Returns the power of two of the scale, or `None` if it is NaN
+    #[inline]
+    pub const fn exponent(self) -> Option<i32> {
+        if self.is_nan() {
+            None
+        } else {
+            Some(self.0 as i32 - 127)
+        }
+    }
+
+    /// Returns `true` if this scale is NaN and `false` otherwise
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 == 0xFF
+    }
+
+    /// Converts the scale into an [`f32`] value
+    ///
+    /// This conversion is lossless; 2<sup>-127</sup> is a subnormal [`f32`].
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        match self.exponent() {
+            Some(-127) => f32::from_bits(0x0040_0000),
+            Some(exp) => f32::from_bits(((exp + 127) as u32) << 23),
+            None => f32::NAN,
+        }
+    }
+
+    /// Converts the scale into an [`f64`] value
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        match self.exponent() {
+            Some(exp) => pow2(exp),
+            None => f64::NAN,
+        }
+    }
+}
+
+#[inline]
+fn clamp_exponent(exp: i32) -> i32 {
+    match exp {
+        i32::MIN..=-128 => -127,
+        128..=i32::MAX => 127,
+        _ => exp,
+    }
+}
+
+/// Returns 2<sup>`exp`</sup> for the exponents that can occur in a block
+#[inline]
+fn pow2(exp: i32) -> f64 {
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Splits the finite `value` into its sign and `m * 2^e`
+#[inline]
+fn decompose(value: f32) -> (bool, u64, i32) {
+    let bits = value.to_bits();
+    let sign = bits >> 31 != 0;
+    let exp = ((bits >> 23) & 0xFF) as i32;
+    let man = (bits & 0x007F_FFFF) as u64;
+    if exp == 0 {
+        (sign, man, -149)
+    } else {
+        (sign, man | 0x0080_0000, exp - 150)
+    }
+}
+
+/// Chooses the shared exponent of a block of finite values, as in section 6.3 of the
+/// specification: the exponent of the largest magnitude less that of the largest element
+fn shared_exponent(format: MxFormat, block: &[f32]) -> i32 {
+    let amax = block.iter().fold(0f32, |amax, x| {
+        amax.max(f32::from_bits(x.to_bits() & 0x7FFF_FFFF))
+    });
+    if amax == 0.0 {
+        return -127;
+    }
+    let (_, m, e) = decompose(amax);
+    let log2 = e + 63 - m.leading_zeros() as i32;
+    clamp_exponent(log2 - format.emax())
+}
+
+/// Rounds `m >> shift` to nearest, ties to even
+#[inline]
+fn round_shift(m: u64, shift: u32) -> u64 {
+    if shift == 0 {
+        return m;
+    }
+    if shift > 64 {
+        return 0;
+    }
+    let m = m as u128;
+    let r = m >> shift;
+    let rem = m & ((1u128 << shift) - 1);
+    let half = 1u128 << (shift - 1);
+    (r + (rem > half || (rem == half && r & 1 != 0)) as u128) as u64
+}
+
+/// Quantizes the finite `value` to an element of `format` under the scale 2<sup>`shared`</sup>
+fn quantize(format: MxFormat, value: f32, shared: i32) -> u8 {
+    let (sign, m, e) = decompose(value);
+    match format.element() {
+        Some(fmt) => {
+            let bits = if m == 0 {
+                fmt.zero(sign)
+            } else {
+                let rounding = RoundingMode::NearestTiesToEven;
+                softfloat::round_pack(fmt, rounding, sign, m, e - shared, &mut Status::default())
+            };
+            // Elements saturate instead of overflowing
+            Saturation::default().apply(fmt, value as f64, bits) as u8
+        }
+        None => {
+            // value * 2^(6 - shared), rounded and clamped to the symmetric range of int8
+            let e = e + 6 - shared;
+            let n = if m == 0 {
+                0
+            } else if e >= 0 {
+                (m << e.min(7)).min(127)
+            } else {
+                round_shift(m, (-e) as u32).min(127)
+            } as i8;
+            (if sign { -n } else { n }) as u8
+        }
+    }
+}
+
+/// Converts the element `bits` of `format` to its value under the scale 2<sup>`shared`</sup>
+fn dequantize(format: MxFormat, bits: u8, shared: i32) -> f64 {
+    match format.element() {
+        Some(fmt) => softfloat::to_f32(fmt, bits as u32) as f64 * pow2(shared),
+        None => bits as i8 as f64 * pow2(shared - 6),
+    }
+}
+
+#[inline]
+fn read_element(data: &[u8], index: usize, width: usize) -> u8 {
+    let bit = index * width;
+    let (byte, offset) = (bit / 8, bit % 8);
+    let mut window = data[byte] as u16;
+    if offset + width > 8 {
+        window |= (data[byte + 1] as u16) << 8;
+    }
+    ((window >> offset) & ((1 << width) - 1)) as u8
+}
+
+/// ORs the element `bits` into `data`, which must be zero there
+#[inline]
+fn write_element(data: &mut [u8], index: usize, width: usize, bits: u8) {
+    let bit = index * width;
+    let (byte, offset) = (bit / 8, bit % 8);
+    let window = (bits as u16) << offset;
+    data[byte] |= window as u8;
+    if offset + width > 8 {
+        data[byte + 1] |= (window >> 8) as u8;
+    }
+}
+
+fn check_lengths(format: MxFormat, len: usize, scales: usize, data: usize) {
+    assert_eq!(
+        scales,
+        format.scales_len(len),
+        "scale slice length does not match the number of blocks"
+    );
+    assert_eq!(
+        data,
+        format.data_len(len),
+        "element data length does not match the number of elements"
+    );
+}
+
+fn encode<T: Copy>(
+    format: MxFormat,
+    src: &[T],
+    to_f32: impl Fn(T) -> f32,
+    scales: &mut [E8M0],
+    data: &mut [u8],
+) {
+    check_lengths(format, src.len(), scales.len(), data.len());
+    let width = format.element_bits();
+    for byte in data.iter_mut() {
+        *byte = 0;
+    }
+
+    let mut block = [0f32; BLOCK_SIZE];
+    for (i, (chunk, scale)) in src.chunks(BLOCK_SIZE).zip(scales.iter_mut()).enumerate() {
+        let block = &mut block[..chunk.len()];
+        for (dst, x) in block.iter_mut().zip(chunk) {
+            *dst = to_f32(*x);
+        }
+        if block.iter().any(|x| !x.is_finite()) {
+            // The elements are left as zero
+            *scale = E8M0::NAN;
+            continue;
+        }
+
+        let shared = shared_exponent(format, block);
+        *scale = E8M0::from_exponent(shared);
+        for (j, x) in block.iter().enumerate() {
+            let bits = quantize(format, *x, shared);
+            write_element(data, i * BLOCK_SIZE + j, width, bits);
+        }
+    }
+}
+
+fn decode<T>(
+    format: MxFormat,
+    scales: &[E8M0],
+    data: &[u8],
+    dst: &mut [T],
+    from_f64: impl Fn(f64) -> T,
+) {
+    check_lengths(format, dst.len(), scales.len(), data.len());
+    let width = format.element_bits();
+    for (i, (chunk, scale)) in dst.chunks_mut(BLOCK_SIZE).zip(scales).enumerate() {
+        for (j, x) in chunk.iter_mut().enumerate() {
+            *x = from_f64(match scale.exponent() {
+                Some(shared) => dequantize(
+                    format,
+                    read_element(data, i * BLOCK_SIZE + j, width),
+                    shared,
+                ),
+                None => f64::NAN,
+            });
+        }
+    }
+}
+
+/// Encodes `src` into blocks of `format`, writing one scale per block to `scales` and the packed
+/// elements to `data`
+///
+/// The scale of each block is chosen so that its largest magnitude falls in the top binade of the
+/// element type, as described in section 6.3 of the specification. Each value is then divided by
+/// the scale and rounded to the nearest element, ties to even. Values that still exceed the
+/// largest element, which can happen after rounding, saturate to it, and MXINT8 elements are
+/// clamped to ±127. A block containing NaN or ±∞ gets a NaN scale.
+///
+/// # Panics
+///
+/// This function will panic if `scales` or `data` do not have the lengths given by
+/// [`MxFormat::scales_len`] and [`MxFormat::data_len`] for `src.len()` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::mx::{self, MxFormat, E8M0};
+///
+/// let values = [1000.0f32, 1.0, -250.0];
+/// let mut scales = [E8M0::ONE];
+/// let mut data = [0u8; 3];
+/// mx::encode_f32(MxFormat::Fp8E4M3, &values, &mut scales, &mut data);
+///
+/// // 1000 is in [2^9, 2^10) and the largest E4M3 binade is [2^8, 2^9)
+/// assert_eq!(scales[0], E8M0::from_exponent(1));
+/// ```
+pub fn encode_f32(format: MxFormat, src: &[f32], scales: &mut [E8M0], data: &mut [u8]) {
+    encode(format, src, |x| x, scales, data)
+}
+
+/// Encodes `src` into blocks of `format`, writing one scale per block to `scales` and the packed
+/// elements to `data`
+///
+/// See [`encode_f32`] for details.
+///
+/// # Panics
+///
+/// This function will panic if `scales` or `data` do not have the lengths given by
+/// [`MxFormat::scales_len`] and [`MxFormat::data_len`] for `src.len()` elements.
+pub fn encode_f16(format: MxFormat, src: &[f16], scales: &mut [E8M0], data: &mut [u8]) {
+    encode(format, src, f16::to_f32, scales, data)
+}
+
+/// Encodes `src` into blocks of `format`, writing one scale per block to `scales` and the packed
+/// elements to `data`
+///
+/// See [`encode_f32`] for details.
+///
+/// # Panics
+///
+/// This function will panic if `scales` or `data` do not have the lengths given by
+/// [`MxFormat::scales_len`] and [`MxFormat::data_len`] for `src.len()` elements.
+pub fn encode_bf16(format: MxFormat, src: &[bf16], scales: &mut [E8M0], data: &mut [u8]) {
+    encode(format, src, bf16::to_f32, scales, data)
+}
+
+/// Decodes blocks of `format` into `dst`
+///
+/// Each value is its element multiplied by the scale of its block, rounded to nearest once. Every
+/// value of a block with a NaN scale is NaN.
+///
+/// # Panics
+///
+/// This function will panic if `scales` or `data` do not have the lengths given by
+/// [`MxFormat::scales_len`] and [`MxFormat::data_len`] for `dst.len()` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::mx::{self, MxFormat, E8M0};
+///
+/// // Two MXFP4 elements per byte, low nibble first: 1.0 is 0b0010 and -0.5 is 0b1001
+/// let scales = [E8M0::from_exponent(2)];
+/// let data = [0x92];
+/// let mut values = [0f32; 2];
+/// mx::decode_f32(MxFormat::Fp4E2M1, &scales, &data, &mut values);
+///
+/// assert_eq!(values, [4.0, -2.0]);
+/// ```
+pub fn decode_f32(format: MxFormat, scales: &[E8M0], data: &[u8], dst: &mut [f32]) {
+    decode(format, scales, data, dst, |x| x as f32)
+}
+
+/// Decodes blocks of `format` into `dst`
+///
+/// See [`decode_f32`] for details. Values too large for `f16` become ±∞.
+///
+/// # Panics
+///
+/// This function will panic if `scales` or `data` do not have the lengths given by
+/// [`MxFormat::scales_len`] and [`MxFormat::data_len`] for `dst.len()` elements.
+pub fn decode_f16(format: MxFormat, scales: &[E8M0], data: &[u8], dst: &mut [f16]) {
+    decode(format, scales, data, dst, f16::from_f64)
+}
+
+/// Decodes blocks of `format` into `dst`
+///
+/// See [`decode_f32`] for details.
+///
+/// # Panics
+///
+/// This function will panic if `scales` or `data` do not have the lengths given by
+/// [`MxFormat::scales_len`] and [`MxFormat::data_len`] for `dst.len()` elements.
+pub fn decode_bf16(format: MxFormat, scales: &[E8M0], data: &[u8], dst: &mut [bf16]) {
+    decode(format, scales, data, dst, bf16::from_f64)
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FORMATS: [MxFormat; 6] = [
+        MxFormat::Fp8E4M3,
+        MxFormat::Fp8E5M2,
+        MxFormat::Fp6E2M3,
+        MxFormat::Fp6E3M2,
+        MxFormat::Fp4E2M1,
+        MxFormat::Int8,
+    ];
+
+    fn round_trip(format: MxFormat, src: &[f32], dst: &mut [f32]) -> ([E8M0; 4], [u8; 128]) {
+        let mut scales = [E8M0::NAN; 4];
+        let mut data = [0xFFu8; 128];
+        let scales = &mut scales[..format.scales_len(src.len())];
+        let data = &mut data[..format.data_len(src.len())];
+        encode_f32(format, src, scales, data);
+        decode_f32(format, scales, data, dst);
+
+        let mut s = [E8M0::NAN; 4];
+        let mut d = [0u8; 128];
+        s[..scales.len()].copy_from_slice(scales);
+        d[..data.len()].copy_from_slice(data);
+        (s, d)
+    }
+
+    /// Every element value of `format`, by brute force over the element codes
+    fn elements(format: MxFormat) -> impl Iterator<Item = f64> {
+        let codes = 1u16 << format.element_bits();
+        (0..codes).map(move |code| dequantize(format, code as u8, 0))
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!(MxFormat::Fp8E4M3.scales_len(0), 0);
+        assert_eq!(MxFormat::Fp8E4M3.scales_len(32), 1);
+        assert_eq!(MxFormat::Fp8E4M3.scales_len(33), 2);
+        assert_eq!(MxFormat::Int8.data_len(33), 33);
+        assert_eq!(MxFormat::Fp6E3M2.data_len(32), 24);
+        assert_eq!(MxFormat::Fp6E3M2.data_len(5), 4);
+        assert_eq!(MxFormat::Fp4E2M1.data_len(32), 16);
+        assert_eq!(MxFormat::Fp4E2M1.data_len(3), 2);
+    }
+
+    #[test]
+    fn e8m0() {
+        for bits in 0..0xFFu8 {
+            let scale = E8M0::from_bits(bits);
+            let exp = scale.exponent().unwrap();
+            assert_eq!(E8M0::from_exponent(exp), scale);
+            assert_eq!(scale.to_f64(), 2f64.powi(exp));
+            assert_eq!(scale.to_f32() as f64, scale.to_f64());
+        }
+        assert_eq!(E8M0::ONE.to_f32(), 1.0);
+        assert_eq!(E8M0::from_exponent(-1000), E8M0::MIN);
+        assert!(E8M0::NAN.is_nan());
+        assert!(E8M0::NAN.to_f32().is_nan());
+        assert!(E8M0::NAN.to_f64().is_nan());
+    }
+
+    #[test]
+    fn element_ranges() {
+        let max = |format| {
+            elements(format)
+                .filter(|x| x.is_finite())
+                .fold(0f64, f64::max)
+        };
+        assert_eq!(max(MxFormat::Fp8E4M3), 448.0);
+        assert_eq!(max(MxFormat::Fp8E5M2), 57344.0);
+        assert_eq!(max(MxFormat::Fp6E2M3), 7.5);
+        assert_eq!(max(MxFormat::Fp6E3M2), 28.0);
+        assert_eq!(max(MxFormat::Fp4E2M1), 6.0);
+        assert_eq!(max(MxFormat::Int8), 127.0 / 64.0);
+        assert_eq!(elements(MxFormat::Int8).fold(0f64, f64::min), -2.0);
+        for &format in &FORMATS[2..] {
+            assert!(elements(format).all(f64::is_finite), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn scale_selection() {
+        let mut dst = [0f32; 3];
+        for &format in &FORMATS {
+            let (scales, _) = round_trip(format, &[1.0, -0.25, 0.0], &mut dst);
+            assert_eq!(scales[0].exponent(), Some(-format.emax()), "{:?}", format);
+            assert_eq!(dst, [1.0, -0.25, 0.0], "{:?}", format);
+
+            let (scales, _) = round_trip(format, &[-1000.0, 0.5, 1e-30], &mut dst);
+            assert_eq!(
+                scales[0].exponent(),
+                Some(9 - format.emax()),
+                "{:?}",
+                format
+            );
+        }
+
+        // Shared exponents are clamped to the range of E8M0
+        let (scales, _) = round_trip(MxFormat::Int8, &[f32::MAX, 0.0, 0.0], &mut dst);
+        assert_eq!(scales[0], E8M0::MAX);
+        assert_eq!(dst, [127.0 * 2f32.powi(121), 0.0, 0.0]);
+        let (scales, _) = round_trip(MxFormat::Fp8E4M3, &[1e-45, 0.0, 0.0], &mut dst);
+        assert_eq!(scales[0], E8M0::MIN);
+        assert_eq!(dst, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn zero_and_non_finite_blocks() {
+        let mut dst = [1f32; 40];
+        let mut src = [0f32; 40];
+        src[35] = f32::NAN;
+        let (scales, data) = round_trip(MxFormat::Fp8E5M2, &src, &mut dst);
+        assert_eq!(scales[0], E8M0::MIN);
+        assert_eq!(scales[1], E8M0::NAN);
+        assert!(data[..40].iter().all(|&b| b == 0));
+        assert!(dst[..32].iter().all(|&x| x == 0.0));
+        assert!(dst[32..].iter().all(|x| x.is_nan()));
+
+        src[35] = f32::NEG_INFINITY;
+        let (scales, _) = round_trip(MxFormat::Int8, &src, &mut dst);
+        assert_eq!(scales[1], E8M0::NAN);
+
+        // Negative zero keeps its sign
+        let (_, data) = round_trip(MxFormat::Fp4E2M1, &[-0.0, 0.0], &mut dst[..2]);
+        assert_eq!(data[0], 0x08);
+        assert_eq!(dst[0].to_bits(), (-0f32).to_bits());
+    }
+
+    #[test]
+    fn rounding_and_saturation() {
+        let mut dst = [0f32; 4];
+        // 5 is halfway between 4 and 6, and 4 has the even significand; 4.5 rounds down
+        round_trip(MxFormat::Fp4E2M1, &[6.0, 5.0, -4.5, 0.25], &mut dst);
+        assert_eq!(dst, [6.0, 4.0, -4.0, 0.0]);
+
+        // 7.9 is in the binade of 4 but rounds past 6, the largest element
+        round_trip(MxFormat::Fp4E2M1, &[7.9, -7.9, 1.0, 0.75], &mut dst);
+        assert_eq!(dst, [6.0, -6.0, 1.0, 1.0]);
+        round_trip(MxFormat::Fp8E4M3, &[511.0, 1.0, -480.0, 464.0], &mut dst);
+        assert_eq!(dst, [448.0, 1.0, -448.0, 448.0]);
+        round_trip(MxFormat::Fp8E5M2, &[65535.0, 1.0, -2.0, 0.0], &mut dst);
+        assert_eq!(dst, [57344.0, 1.0, -2.0, 0.0]);
+        round_trip(MxFormat::Fp6E2M3, &[7.9, -3.0, 0.0625, 0.1875], &mut dst);
+        assert_eq!(dst, [7.5, -3.0, 0.0, 0.25]);
+
+        // MXINT8 clamps to ±127 units of 2^-6 under the scale
+        round_trip(
+            MxFormat::Int8,
+            &[1.999, -1.999, 0.0078125, 0.0234375],
+            &mut dst,
+        );
+        assert_eq!(dst, [127.0 / 64.0, -127.0 / 64.0, 0.0, 2.0 / 64.0]);
+    }
+
+    #[test]
+    fn packing() {
+        let mut dst = [0f32; 5];
+        let (_, data) = round_trip(MxFormat::Fp4E2M1, &[1.0, -0.5, 6.0, 0.0, -6.0], &mut dst);
+        assert_eq!(data[..3], [0x92, 0x07, 0x0F]);
+
+        // Four 6-bit elements in three bytes, least significant bits first
+        let (_, data) = round_trip(
+            MxFormat::Fp6E3M2,
+            &[1.0, -1.0, 28.0, -0.0625],
+            &mut dst[..4],
+        );
+        let codes = [0b001100u32, 0b101100, 0b011111, 0b100001];
+        let packed = codes[0] | codes[1] << 6 | codes[2] << 12 | codes[3] << 18;
+        assert_eq!(data[..3], packed.to_le_bytes()[..3]);
+        assert_eq!(dst[..4], [1.0, -1.0, 28.0, -0.0625]);
+
+        let (_, data) = round_trip(MxFormat::Int8, &[1.0, -1.0, -2.0, 0.5, 0.0], &mut dst);
+        assert_eq!(data[..5], [32, 0xE0, 0xC0, 16, 0]);
+    }
+
+    #[test]
+    fn matches_reference() {
+        // Pseudo-random values over several binades, in a full block and a partial one
+        let mut src = [0f32; 45];
+        let mut state = 0x2545_F491u32;
+        for x in src.iter_mut() {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let exp = (state % 24) as i32 - 12;
+            *x = f32::from_bits((state & 0x807F_FFFF) | ((exp + 127) as u32) << 23);
+        }
+
+        for &format in &FORMATS {
+            let mut dst = [0f32; 45];
+            let (scales, _) = round_trip(format, &src, &mut dst);
+            for (i, (&x, &y)) in src.iter().zip(&dst).enumerate() {
+                let scale = scales[i / BLOCK_SIZE].to_f64();
+                let x = x as f64 / scale;
+                // Nearest element, preferring an even code on ties and saturating at the largest
+                let mut best = f64::INFINITY;
+                for (code, e) in elements(format).enumerate() {
+                    if !e.is_finite() || (format == MxFormat::Int8 && e == -2.0) {
+                        continue;
+                    }
+                    let d = (e - x).abs();
+                    if d < (best - x).abs() || (d == (best - x).abs() && code & 1 == 0) {
+                        best = e;
+                    }
+                }
+                assert_eq!(y as f64, best * scale, "{:?} {}", format, src[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn half_types() {
+        let src = [f16::from_f32(3.0), f16::from_f32(-0.375), f16::MAX];
+        let mut scales = [E8M0::NAN];
+        let mut data = [0u8; 2];
+        encode_f16(MxFormat::Fp4E2M1, &src, &mut scales, &mut data);
+        assert_eq!(scales[0], E8M0::from_exponent(13));
+        let mut dst = [f16::ZERO; 3];
+        decode_f16(MxFormat::Fp4E2M1, &scales, &data, &mut dst);
+        // f16::MAX saturates to 6 * 2^13, and 3 is too small for the scale
+        assert_eq!(dst[0], f16::ZERO);
+        assert_eq!(dst[2], f16::from_f32(49152.0));
+
+        let big = bf16::from_f32(1.5 * 2f32.powi(100));
+        let src = [bf16::from_f32(3.0), bf16::from_f32(-0.375), big];
+        let mut data = [0u8; 3];
+        encode_bf16(MxFormat::Fp8E4M3, &src, &mut scales, &mut data);
+        let mut dst = [bf16::ZERO; 3];
+        decode_bf16(MxFormat::Fp8E4M3, &scales, &data, &mut dst);
+        assert_eq!(dst, [bf16::ZERO, bf16::NEG_ZERO, big]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_len_mismatch_panics() {
+        let mut scales = [E8M0::ONE; 1];
+        let mut data = [0u8; 16];
+        encode_f32(MxFormat::Fp6E2M3, &[0.0; 32], &mut scales, &mut data);
+    }
+}
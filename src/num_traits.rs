@@ -1,5 +1,7 @@
-use crate::{bf16, f16};
-use core::cmp::Ordering;
+use crate::{
+    bf16, dlfloat16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz,
+    posit::{Posit16, Posit32, Posit8},
+};
 use core::{num::FpCategory, ops::Div};
 use num_traits::{
     float::FloatCore, AsPrimitive, Bounded, Float, FloatConst, FromPrimitive, Num, NumCast, One,
@@ -195,37 +197,37 @@ impl FloatCore for f16 {
 
     #[inline]
     fn floor(self) -> Self {
-        Self::from_f32(self.to_f32().floor())
+        Self::floor(self)
     }
 
     #[inline]
     fn ceil(self) -> Self {
-        Self::from_f32(self.to_f32().ceil())
+        Self::ceil(self)
     }
 
     #[inline]
     fn round(self) -> Self {
-        Self::from_f32(self.to_f32().round())
+        Self::round(self)
     }
 
     #[inline]
     fn trunc(self) -> Self {
-        Self::from_f32(self.to_f32().trunc())
+        Self::trunc(self)
     }
 
     #[inline]
     fn fract(self) -> Self {
-        Self::from_f32(self.to_f32().fract())
+        Self::fract(self)
     }
 
     #[inline]
     fn abs(self) -> Self {
-        Self::from_bits(self.to_bits() & 0x7FFF)
+        Self::abs(self)
     }
 
     #[inline]
     fn signum(self) -> Self {
-        self.signum()
+        Self::signum(self)
     }
 
     #[inline]
@@ -238,37 +240,19 @@ impl FloatCore for f16 {
         self.is_sign_negative()
     }
 
+    #[inline]
     fn min(self, other: Self) -> Self {
-        match self.partial_cmp(&other) {
-            None => {
-                if self.is_nan() {
-                    other
-                } else {
-                    self
-                }
-            }
-            Some(Ordering::Greater) | Some(Ordering::Equal) => other,
-            Some(Ordering::Less) => self,
-        }
+        Self::min(self, other)
     }
 
+    #[inline]
     fn max(self, other: Self) -> Self {
-        match self.partial_cmp(&other) {
-            None => {
-                if self.is_nan() {
-                    other
-                } else {
-                    self
-                }
-            }
-            Some(Ordering::Greater) | Some(Ordering::Equal) => self,
-            Some(Ordering::Less) => other,
-        }
+        Self::max(self, other)
     }
 
     #[inline]
     fn recip(self) -> Self {
-        Self::from_f32(self.to_f32().recip())
+        Self::recip(self)
     }
 
     #[inline]
@@ -278,17 +262,17 @@ impl FloatCore for f16 {
 
     #[inline]
     fn to_degrees(self) -> Self {
-        Self::from_f32(self.to_f32().to_degrees())
+        Self::to_degrees(self)
     }
 
     #[inline]
     fn to_radians(self) -> Self {
-        Self::from_f32(self.to_f32().to_radians())
+        Self::to_radians(self)
     }
 
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) {
-        FloatCore::integer_decode(self.to_f32())
+        Self::integer_decode(self)
     }
 }
 
@@ -360,37 +344,37 @@ impl Float for f16 {
 
     #[inline]
     fn floor(self) -> Self {
-        Self::from_f32(self.to_f32().floor())
+        Self::floor(self)
     }
 
     #[inline]
     fn ceil(self) -> Self {
-        Self::from_f32(self.to_f32().ceil())
+        Self::ceil(self)
     }
 
     #[inline]
     fn round(self) -> Self {
-        Self::from_f32(self.to_f32().round())
+        Self::round(self)
     }
 
     #[inline]
     fn trunc(self) -> Self {
-        Self::from_f32(self.to_f32().trunc())
+        Self::trunc(self)
     }
 
     #[inline]
     fn fract(self) -> Self {
-        Self::from_f32(self.to_f32().fract())
+        Self::fract(self)
     }
 
     #[inline]
     fn abs(self) -> Self {
-        Self::from_f32(self.to_f32().abs())
+        Self::abs(self)
     }
 
     #[inline]
     fn signum(self) -> Self {
-        Self::from_f32(self.to_f32().signum())
+        Self::signum(self)
     }
 
     #[inline]
@@ -405,12 +389,12 @@ impl Float for f16 {
 
     #[inline]
     fn mul_add(self, a: Self, b: Self) -> Self {
-        Self::from_f32(self.to_f32().mul_add(a.to_f32(), b.to_f32()))
+        Self::mul_add(self, a, b)
     }
 
     #[inline]
     fn recip(self) -> Self {
-        Self::from_f32(self.to_f32().recip())
+        Self::recip(self)
     }
 
     #[inline]
@@ -420,27 +404,27 @@ impl Float for f16 {
 
     #[inline]
     fn powf(self, n: Self) -> Self {
-        Self::from_f32(self.to_f32().powf(n.to_f32()))
+        Self::powf(self, n)
     }
 
     #[inline]
     fn sqrt(self) -> Self {
-        Self::from_f32(self.to_f32().sqrt())
+        Self::sqrt(self)
     }
 
     #[inline]
     fn exp(self) -> Self {
-        Self::from_f32(self.to_f32().exp())
+        Self::exp(self)
     }
 
     #[inline]
     fn exp2(self) -> Self {
-        Self::from_f32(self.to_f32().exp2())
+        Self::exp2(self)
     }
 
     #[inline]
     fn ln(self) -> Self {
-        Self::from_f32(self.to_f32().ln())
+        Self::ln(self)
     }
 
     #[inline]
@@ -450,32 +434,32 @@ impl Float for f16 {
 
     #[inline]
     fn log2(self) -> Self {
-        Self::from_f32(self.to_f32().log2())
+        Self::log2(self)
     }
 
     #[inline]
     fn log10(self) -> Self {
-        Self::from_f32(self.to_f32().log10())
+        Self::log10(self)
     }
 
     #[inline]
     fn to_degrees(self) -> Self {
-        Self::from_f32(self.to_f32().to_degrees())
+        Self::to_degrees(self)
     }
 
     #[inline]
     fn to_radians(self) -> Self {
-        Self::from_f32(self.to_f32().to_radians())
+        Self::to_radians(self)
     }
 
     #[inline]
     fn max(self, other: Self) -> Self {
-        FloatCore::max(self, other)
+        Self::max(self, other)
     }
 
     #[inline]
     fn min(self, other: Self) -> Self {
-        FloatCore::min(self, other)
+        Self::min(self, other)
     }
 
     #[inline]
@@ -485,27 +469,27 @@ impl Float for f16 {
 
     #[inline]
     fn cbrt(self) -> Self {
-        Self::from_f32(self.to_f32().cbrt())
+        Self::cbrt(self)
     }
 
     #[inline]
     fn hypot(self, other: Self) -> Self {
-        Self::from_f32(self.to_f32().hypot(other.to_f32()))
+        Self::hypot(self, other)
     }
 
     #[inline]
     fn sin(self) -> Self {
-        Self::from_f32(self.to_f32().sin())
+        Self::sin(self)
     }
 
     #[inline]
     fn cos(self) -> Self {
-        Self::from_f32(self.to_f32().cos())
+        Self::cos(self)
     }
 
     #[inline]
     fn tan(self) -> Self {
-        Self::from_f32(self.to_f32().tan())
+        Self::tan(self)
     }
 
     #[inline]
@@ -530,8 +514,7 @@ impl Float for f16 {
 
     #[inline]
     fn sin_cos(self) -> (Self, Self) {
-        let (sin, cos) = self.to_f32().sin_cos();
-        (Self::from_f32(sin), Self::from_f32(cos))
+        (Self::sin(self), Self::cos(self))
     }
 
     #[inline]
@@ -556,7 +539,7 @@ impl Float for f16 {
 
     #[inline]
     fn tanh(self) -> Self {
-        Self::from_f32(self.to_f32().tanh())
+        Self::tanh(self)
     }
 
     #[inline]
@@ -576,7 +559,7 @@ impl Float for f16 {
 
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) {
-        Float::integer_decode(self.to_f32())
+        Self::integer_decode(self)
     }
 }
 
@@ -922,37 +905,37 @@ impl FloatCore for bf16 {
 
     #[inline]
     fn floor(self) -> Self {
-        Self::from_f32(self.to_f32().floor())
+        Self::floor(self)
     }
 
     #[inline]
     fn ceil(self) -> Self {
-        Self::from_f32(self.to_f32().ceil())
+        Self::ceil(self)
     }
 
     #[inline]
     fn round(self) -> Self {
-        Self::from_f32(self.to_f32().round())
+        Self::round(self)
     }
 
     #[inline]
     fn trunc(self) -> Self {
-        Self::from_f32(self.to_f32().trunc())
+        Self::trunc(self)
     }
 
     #[inline]
     fn fract(self) -> Self {
-        Self::from_f32(self.to_f32().fract())
+        Self::fract(self)
     }
 
     #[inline]
     fn abs(self) -> Self {
-        Self::from_bits(self.to_bits() & 0x7FFF)
+        Self::abs(self)
     }
 
     #[inline]
     fn signum(self) -> Self {
-        self.signum()
+        Self::signum(self)
     }
 
     #[inline]
@@ -965,37 +948,19 @@ impl FloatCore for bf16 {
         self.is_sign_negative()
     }
 
+    #[inline]
     fn min(self, other: Self) -> Self {
-        match self.partial_cmp(&other) {
-            None => {
-                if self.is_nan() {
-                    other
-                } else {
-                    self
-                }
-            }
-            Some(Ordering::Greater) | Some(Ordering::Equal) => other,
-            Some(Ordering::Less) => self,
-        }
+        Self::min(self, other)
     }
 
+    #[inline]
     fn max(self, other: Self) -> Self {
-        match self.partial_cmp(&other) {
-            None => {
-                if self.is_nan() {
-                    other
-                } else {
-                    self
-                }
-            }
-            Some(Ordering::Greater) | Some(Ordering::Equal) => self,
-            Some(Ordering::Less) => other,
-        }
+        Self::max(self, other)
     }
 
     #[inline]
     fn recip(self) -> Self {
-        Self::from_f32(self.to_f32().recip())
+        Self::recip(self)
     }
 
     #[inline]
@@ -1005,17 +970,17 @@ impl FloatCore for bf16 {
 
     #[inline]
     fn to_degrees(self) -> Self {
-        Self::from_f32(self.to_f32().to_degrees())
+        Self::to_degrees(self)
     }
 
     #[inline]
     fn to_radians(self) -> Self {
-        Self::from_f32(self.to_f32().to_radians())
+        Self::to_radians(self)
     }
 
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) {
-        FloatCore::integer_decode(self.to_f32())
+        Self::integer_decode(self)
     }
 }
 
@@ -1087,37 +1052,37 @@ impl Float for bf16 {
 
     #[inline]
     fn floor(self) -> Self {
-        Self::from_f32(self.to_f32().floor())
+        Self::floor(self)
     }
 
     #[inline]
     fn ceil(self) -> Self {
-        Self::from_f32(self.to_f32().ceil())
+        Self::ceil(self)
     }
 
     #[inline]
     fn round(self) -> Self {
-        Self::from_f32(self.to_f32().round())
+        Self::round(self)
     }
 
     #[inline]
     fn trunc(self) -> Self {
-        Self::from_f32(self.to_f32().trunc())
+        Self::trunc(self)
     }
 
     #[inline]
     fn fract(self) -> Self {
-        Self::from_f32(self.to_f32().fract())
+        Self::fract(self)
     }
 
     #[inline]
     fn abs(self) -> Self {
-        Self::from_f32(self.to_f32().abs())
+        Self::abs(self)
     }
 
     #[inline]
     fn signum(self) -> Self {
-        Self::from_f32(self.to_f32().signum())
+        Self::signum(self)
     }
 
     #[inline]
@@ -1132,12 +1097,12 @@ impl Float for bf16 {
 
     #[inline]
     fn mul_add(self, a: Self, b: Self) -> Self {
-        Self::from_f32(self.to_f32().mul_add(a.to_f32(), b.to_f32()))
+        Self::mul_add(self, a, b)
     }
 
     #[inline]
     fn recip(self) -> Self {
-        Self::from_f32(self.to_f32().recip())
+        Self::recip(self)
     }
 
     #[inline]
@@ -1147,27 +1112,27 @@ impl Float for bf16 {
 
     #[inline]
     fn powf(self, n: Self) -> Self {
-        Self::from_f32(self.to_f32().powf(n.to_f32()))
+        Self::powf(self, n)
     }
 
     #[inline]
     fn sqrt(self) -> Self {
-        Self::from_f32(self.to_f32().sqrt())
+        Self::sqrt(self)
     }
 
     #[inline]
     fn exp(self) -> Self {
-        Self::from_f32(self.to_f32().exp())
+        Self::exp(self)
     }
 
     #[inline]
     fn exp2(self) -> Self {
-        Self::from_f32(self.to_f32().exp2())
+        Self::exp2(self)
     }
 
     #[inline]
     fn ln(self) -> Self {
-        Self::from_f32(self.to_f32().ln())
+        Self::ln(self)
     }
 
     #[inline]
@@ -1177,32 +1142,32 @@ impl Float for bf16 {
 
     #[inline]
     fn log2(self) -> Self {
-        Self::from_f32(self.to_f32().log2())
+        Self::log2(self)
     }
 
     #[inline]
     fn log10(self) -> Self {
-        Self::from_f32(self.to_f32().log10())
+        Self::log10(self)
     }
 
     #[inline]
     fn to_degrees(self) -> Self {
-        Self::from_f32(self.to_f32().to_degrees())
+        Self::to_degrees(self)
     }
 
     #[inline]
     fn to_radians(self) -> Self {
-        Self::from_f32(self.to_f32().to_radians())
+        Self::to_radians(self)
     }
 
     #[inline]
     fn max(self, other: Self) -> Self {
-        FloatCore::max(self, other)
+        Self::max(self, other)
     }
 
     #[inline]
     fn min(self, other: Self) -> Self {
-        FloatCore::min(self, other)
+        Self::min(self, other)
     }
 
     #[inline]
@@ -1212,27 +1177,27 @@ impl Float for bf16 {
 
     #[inline]
     fn cbrt(self) -> Self {
-        Self::from_f32(self.to_f32().cbrt())
+        Self::cbrt(self)
     }
 
     #[inline]
     fn hypot(self, other: Self) -> Self {
-        Self::from_f32(self.to_f32().hypot(other.to_f32()))
+        Self::hypot(self, other)
     }
 
     #[inline]
     fn sin(self) -> Self {
-        Self::from_f32(self.to_f32().sin())
+        Self::sin(self)
     }
 
     #[inline]
     fn cos(self) -> Self {
-        Self::from_f32(self.to_f32().cos())
+        Self::cos(self)
     }
 
     #[inline]
     fn tan(self) -> Self {
-        Self::from_f32(self.to_f32().tan())
+        Self::tan(self)
     }
 
     #[inline]
@@ -1257,8 +1222,7 @@ impl Float for bf16 {
 
     #[inline]
     fn sin_cos(self) -> (Self, Self) {
-        let (sin, cos) = self.to_f32().sin_cos();
-        (Self::from_f32(sin), Self::from_f32(cos))
+        (Self::sin(self), Self::cos(self))
     }
 
     #[inline]
@@ -1283,7 +1247,7 @@ impl Float for bf16 {
 
     #[inline]
     fn tanh(self) -> Self {
-        Self::from_f32(self.to_f32().tanh())
+        Self::tanh(self)
     }
 
     #[inline]
@@ -1303,7 +1267,7 @@ impl Float for bf16 {
 
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) {
-        Float::integer_decode(self.to_f32())
+        Self::integer_decode(self)
     }
 }
 
@@ -1460,3 +1424,253 @@ impl_as_primitive_bf16_from!(i32, from_f32);
 impl_as_primitive_bf16_from!(u32, from_f32);
 impl_as_primitive_bf16_from!(f32, from_f32);
 impl_as_primitive_bf16_from!(f64, from_f64);
+
+macro_rules! impl_as_primitive_minor_float {
+    ($ty:ident, $prim:ty, $to:ident, $from:ident) => {
+        impl AsPrimitive<$prim> for $ty {
+            #[inline]
+            fn as_(self) -> $prim {
+                self.$to().as_()
+            }
+        }
+
+        impl AsPrimitive<$ty> for $prim {
+            #[inline]
+            fn as_(self) -> $ty {
+                $ty::$from(self.as_())
+            }
+        }
+    };
+}
+
+macro_rules! impl_minor_float_num_traits {
+    ($ty:ident) => {
+        impl ToPrimitive for $ty {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                Self::to_f32(*self).to_i64()
+            }
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                Self::to_f32(*self).to_u64()
+            }
+            #[inline]
+            fn to_i8(&self) -> Option<i8> {
+                Self::to_f32(*self).to_i8()
+            }
+            #[inline]
+            fn to_u8(&self) -> Option<u8> {
+                Self::to_f32(*self).to_u8()
+            }
+            #[inline]
+            fn to_i16(&self) -> Option<i16> {
+                Self::to_f32(*self).to_i16()
+            }
+            #[inline]
+            fn to_u16(&self) -> Option<u16> {
+                Self::to_f32(*self).to_u16()
+            }
+            #[inline]
+            fn to_i32(&self) -> Option<i32> {
+                Self::to_f32(*self).to_i32()
+            }
+            #[inline]
+            fn to_u32(&self) -> Option<u32> {
+                Self::to_f32(*self).to_u32()
+            }
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some(Self::to_f32(*self))
+            }
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(Self::to_f64(*self))
+            }
+        }
+
+        impl FromPrimitive for $ty {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_i8(n: i8) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_u8(n: u8) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_i16(n: i16) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_u16(n: u16) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_i32(n: i32) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_u32(n: u32) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                n.to_f32().map(Self::from_f32)
+            }
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                n.to_f64().map(Self::from_f64)
+            }
+        }
+
+        impl NumCast for $ty {
+            #[inline]
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                n.to_f64().map(Self::from_f64)
+            }
+        }
+
+        impl Bounded for $ty {
+            #[inline]
+            fn min_value() -> Self {
+                $ty::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                $ty::MAX
+            }
+        }
+
+        impl_as_primitive_minor_float!($ty, i64, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, u64, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, i8, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, u8, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, i16, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, u16, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, i32, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, u32, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, f32, to_f32, from_f32);
+        impl_as_primitive_minor_float!($ty, f64, to_f64, from_f64);
+    };
+}
+
+impl_minor_float_num_traits!(f8e4m3);
+impl_minor_float_num_traits!(f8e5m2);
+impl_minor_float_num_traits!(f8e4m3fnuz);
+impl_minor_float_num_traits!(f8e5m2fnuz);
+impl_minor_float_num_traits!(f16ahp);
+impl_minor_float_num_traits!(dlfloat16);
+
+macro_rules! impl_as_primitive_posit {
+    ($ty:ident, $prim:ty, $kind:ident) => {
+        impl<const ES: u32> AsPrimitive<$prim> for $ty<ES> {
+            #[inline]
+            fn as_(self) -> $prim {
+                self.to_f64().as_()
+            }
+        }
+
+        impl<const ES: u32> AsPrimitive<$ty<ES>> for $prim {
+            #[allow(trivial_numeric_casts)]
+            #[inline]
+            fn as_(self) -> $ty<ES> {
+                impl_as_primitive_posit!(@from $kind, $ty, self)
+            }
+        }
+    };
+    (@from signed, $ty:ident, $n:expr) => {
+        $ty::from_integer($n < 0, ($n as i64).unsigned_abs())
+    };
+    (@from unsigned, $ty:ident, $n:expr) => {
+        $ty::from_integer(false, $n as u64)
+    };
+    (@from float, $ty:ident, $n:expr) => {
+        $ty::from_f64($n as f64)
+    };
+}
+
+macro_rules! impl_posit_num_traits {
+    ($ty:ident) => {
+        impl<const ES: u32> ToPrimitive for $ty<ES> {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                Self::to_f64(*self).to_i64()
+            }
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                Self::to_f64(*self).to_u64()
+            }
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some(Self::to_f32(*self))
+            }
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(Self::to_f64(*self))
+            }
+        }
+
+        // Integers are rounded directly, since going through `f64` could round twice
+        impl<const ES: u32> FromPrimitive for $ty<ES> {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Some(Self::from_integer(n < 0, n.unsigned_abs()))
+            }
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Some(Self::from_integer(false, n))
+            }
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Some(Self::from_f32(n))
+            }
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                Some(Self::from_f64(n))
+            }
+        }
+
+        impl<const ES: u32> NumCast for $ty<ES> {
+            #[inline]
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                n.to_f64().map(Self::from_f64)
+            }
+        }
+
+        impl<const ES: u32> Bounded for $ty<ES> {
+            #[inline]
+            fn min_value() -> Self {
+                $ty::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                $ty::MAX
+            }
+        }
+
+        impl_as_primitive_posit!($ty, i64, signed);
+        impl_as_primitive_posit!($ty, u64, unsigned);
+        impl_as_primitive_posit!($ty, i8, signed);
+        impl_as_primitive_posit!($ty, u8, unsigned);
+        impl_as_primitive_posit!($ty, i16, signed);
+        impl_as_primitive_posit!($ty, u16, unsigned);
+        impl_as_primitive_posit!($ty, i32, signed);
+        impl_as_primitive_posit!($ty, u32, unsigned);
+        impl_as_primitive_posit!($ty, f32, float);
+        impl_as_primitive_posit!($ty, f64, float);
+    };
+}
+
+impl_posit_num_traits!(Posit8);
+impl_posit_num_traits!(Posit16);
+impl_posit_num_traits!(Posit32);
@@ -0,0 +1,280 @@
+//! Wrappers that give [`f16`] and [`bf16`] values the [`Eq`], [`Ord`] and [`Hash`] traits needed
+//! by sorting and by the keys of maps and sets
+
+use crate::{bf16, f16};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Wraps a [`f16`] or [`bf16`] value so that it implements [`Eq`], [`Ord`] and [`Hash`]
+///
+/// The [`PartialOrd`] implementations of the half types follow IEEE 754 comparison, where NaN is
+/// unordered, so they can't be sorted with `sort` or used as the keys of a
+/// `BTreeMap`. `TotalOrd` compares values with `total_cmp` instead, which orders negative NaNs,
+/// −∞, negative numbers, `-0.0`, `+0.0`, positive numbers, +∞ and positive NaNs. Two wrapped
+/// values are only equal if they have the same bits, and they are hashed by their bits. Use
+/// [`Canonical`] instead to treat `-0.0` and `+0.0` as the same key, and every NaN as one key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// let mut values = [
+///     TotalOrd(f16::NAN),
+///     TotalOrd(f16::ONE),
+///     TotalOrd(f16::NEG_ZERO),
+///     TotalOrd(f16::ZERO),
+/// ];
+/// values.sort();
+///
+/// assert_eq!(values[0], TotalOrd(f16::NEG_ZERO));
+/// assert_ne!(values[0], TotalOrd(f16::ZERO));
+/// assert!(values[3].0.is_nan());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct TotalOrd<T>(pub T);
+
+/// Wraps a [`f16`] or [`bf16`] value so that it can be used as the key of a `HashMap`, `HashSet`
+/// or `BTreeMap`
+///
+/// Keys are compared and hashed by their canonical value: `-0.0` and `+0.0` are the same key, and
+/// every NaN is the same key regardless of its sign and payload. This matches the [`PartialEq`]
+/// implementation of the half types for all values except NaN, which is equal to itself here so
+/// that [`Eq`] and [`Hash`] are consistent. Keys are ordered by value, with NaN greater than +∞.
+/// The wrapped value is kept as it was given, so the sign of a zero or the payload of a NaN can
+/// still be read from the first key inserted into a map.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use std::collections::HashSet;
+///
+/// let values = [f16::ZERO, f16::NEG_ZERO, f16::NAN, -f16::nan_with_payload(3), f16::ONE];
+/// let unique: HashSet<_> = values.iter().copied().map(Canonical).collect();
+///
+/// assert_eq!(unique.len(), 3);
+/// assert!(unique.contains(&Canonical(f16::NEG_ZERO)));
+/// assert!(Canonical(f16::ONE) < Canonical(f16::NAN));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Canonical<T>(pub T);
+
+macro_rules! impl_total_ord {
+    ($ty:ty) => {
+        impl PartialEq for TotalOrd<$ty> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for TotalOrd<$ty> {}
+
+        impl PartialOrd for TotalOrd<$ty> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for TotalOrd<$ty> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl From<$ty> for TotalOrd<$ty> {
+            #[inline]
+            fn from(x: $ty) -> Self {
+                TotalOrd(x)
+            }
+        }
+
+        impl From<TotalOrd<$ty>> for $ty {
+            #[inline]
+            fn from(x: TotalOrd<$ty>) -> Self {
+                x.0
+            }
+        }
+
+        impl Hash for TotalOrd<$ty> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl Canonical<$ty> {
+            /// Returns the value with `-0.0` replaced by `+0.0` and every NaN by the positive
+            /// quiet NaN without payload
+            #[inline]
+            fn canonical(&self) -> $ty {
+                if self.0.is_nan() {
+                    <$ty>::NAN
+                } else if self.0 == <$ty>::ZERO {
+                    <$ty>::ZERO
+                } else {
+                    self.0
+                }
+            }
+        }
+
+        impl PartialEq for Canonical<$ty> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.canonical().to_bits() == other.canonical().to_bits()
+            }
+        }
+
+        impl Eq for Canonical<$ty> {}
+
+        impl PartialOrd for Canonical<$ty> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Canonical<$ty> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.canonical().total_cmp(&other.canonical())
+            }
+        }
+
+        impl Hash for Canonical<$ty> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.canonical().to_bits().hash(state);
+            }
+        }
+
+        impl From<$ty> for Canonical<$ty> {
+            #[inline]
+            fn from(x: $ty) -> Self {
+                Canonical(x)
+            }
+        }
+
+        impl From<Canonical<$ty>> for $ty {
+            #[inline]
+            fn from(x: Canonical<$ty>) -> Self {
+                x.0
+            }
+        }
+    };
+}
+
+impl_total_ord!(f16);
+impl_total_ord!(bf16);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn qc_total_ord_matches_total_order(a: f16, b: bf16) -> bool {
+        let (x, y) = (TotalOrd(a), TotalOrd(f16::from_bits(b.to_bits())));
+        let (u, v) = (TotalOrd(b), TotalOrd(bf16::from_bits(a.to_bits())));
+        (x <= y) == a.total_order(y.0)
+            && (x == y) == (a.to_bits() == b.to_bits())
+            && (u <= v) == b.total_order(v.0)
+            && x.cmp(&y) == y.cmp(&x).reverse()
+    }
+
+    #[test]
+    fn test_total_ord() {
+        let mut values = [
+            TotalOrd(f16::NAN),
+            TotalOrd(f16::INFINITY),
+            TotalOrd(f16::ZERO),
+            TotalOrd(-f16::NAN),
+            TotalOrd(f16::NEG_ZERO),
+            TotalOrd(f16::MIN),
+            TotalOrd(f16::from_bits(0x7C01)),
+        ];
+        values.sort();
+        let bits = [0xFE00, 0xFBFF, 0x8000, 0x0000, 0x7C00, 0x7C01, 0x7E00];
+        for (x, &b) in values.iter().zip(bits.iter()) {
+            assert_eq!(x.0.to_bits(), b);
+        }
+
+        assert_eq!(TotalOrd(bf16::NAN), TotalOrd(bf16::NAN));
+        assert!(TotalOrd(bf16::NEG_ZERO) < TotalOrd(bf16::ZERO));
+        assert_eq!(bf16::from(TotalOrd::from(bf16::ONE)), bf16::ONE);
+        assert_eq!(TotalOrd::<f16>::default(), TotalOrd(f16::ZERO));
+    }
+
+    #[quickcheck]
+    fn qc_canonical_matches_partial_eq(a: f16, b: f16) -> bool {
+        let (x, y) = (Canonical(a), Canonical(b));
+        let (u, v) = (Canonical(a.to_bf16()), Canonical(b.to_bf16()));
+        let both_nan = a.is_nan() && b.is_nan();
+        (x == y) == (a == b || both_nan)
+            && (u == v) == (u.0 == v.0 || (u.0.is_nan() && v.0.is_nan()))
+            && (a.is_nan() || b.is_nan() || x.partial_cmp(&y) == a.partial_cmp(&b))
+            && x.cmp(&y) == y.cmp(&x).reverse()
+    }
+
+    #[test]
+    fn test_canonical() {
+        assert_eq!(Canonical(f16::ZERO), Canonical(f16::NEG_ZERO));
+        assert_eq!(Canonical(-bf16::NAN), Canonical(bf16::nan_with_payload(5)));
+        assert_ne!(Canonical(f16::NAN), Canonical(f16::INFINITY));
+        assert!(Canonical(f16::INFINITY) < Canonical(-f16::NAN));
+        assert!(Canonical(bf16::NEG_INFINITY) < Canonical(bf16::NEG_ZERO));
+        assert_eq!(
+            Canonical(bf16::NEG_ZERO).cmp(&Canonical(bf16::ZERO)),
+            Ordering::Equal
+        );
+        assert_eq!(f16::from(Canonical::from(f16::NEG_ZERO)).to_bits(), 0x8000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_keys() {
+        use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet};
+
+        fn hash<T: Hash>(x: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            x.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(Canonical(f16::ZERO)), hash(Canonical(f16::NEG_ZERO)));
+        assert_eq!(
+            hash(Canonical(f16::NAN)),
+            hash(Canonical(f16::from_bits(0xFC01)))
+        );
+        assert_eq!(hash(TotalOrd(bf16::ONE)), hash(TotalOrd(bf16::ONE)));
+
+        let values = [
+            f16::NEG_ZERO,
+            f16::ZERO,
+            f16::NAN,
+            -f16::NAN,
+            f16::ONE,
+            f16::ONE,
+        ];
+        let mut counts = HashMap::new();
+        let mut tree = BTreeMap::new();
+        for &x in values.iter() {
+            *counts.entry(Canonical(x)).or_insert(0) += 1;
+            *tree.entry(Canonical(x)).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&Canonical(f16::ZERO)], 2);
+        assert_eq!(counts[&Canonical(f16::from_bits(0x7C01))], 2);
+        let keys: Vec<_> = tree.keys().map(|k| k.0.to_bits()).collect();
+        assert_eq!(keys, [0x8000, 0x3C00, 0x7E00]);
+
+        let set: HashSet<_> = values.iter().copied().map(TotalOrd).collect();
+        assert_eq!(set.len(), 5);
+    }
+}
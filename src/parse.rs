@@ -0,0 +1,520 @@
+//! Correctly rounded decimal parsing for narrow binary formats.
+//!
+//! Decimal strings are converted straight to the target format with a single rounding, using a
+//! small fixed-size big integer so that no intermediate `f32` or `f64` can round first.
+
+use crate::softfloat::{self, Format, RoundingMode, Status};
+use core::{cmp::Ordering, fmt};
+
+/// An error which can be returned when parsing a [`f16`][crate::f16] or [`bf16`][crate::bf16]
+///
+/// This error is used as the error type for the [`FromStr`][core::str::FromStr] implementations
+/// and the `from_ascii` and `from_str_strict` constructors of both types. Use
+/// [`kind`][Self::kind] to find out why parsing failed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use half::ParseHalfErrorKind;
+///
+/// let err = f16::from_str_strict("70000").unwrap_err();
+/// assert_eq!(err.kind(), ParseHalfErrorKind::Overflow);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseHalfError {
+    kind: ParseHalfErrorKind,
+}
+
+/// The reason a [`ParseHalfError`] was returned
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseHalfErrorKind {
+    /// The input was empty
+    Empty,
+    /// The input was not a valid decimal floating point literal, `inf`, `infinity` or `nan`, or
+    /// in strict mode was `nan` for a format without NaN
+    Invalid,
+    /// In strict mode, a finite value was too large in magnitude and would round to ±∞, or the
+    /// input was ±∞ for a format without ±∞ or NaN
+    Overflow,
+    /// In strict mode, a nonzero value was too small in magnitude and would round to ±0
+    Underflow,
+}
+
+impl ParseHalfError {
+    #[inline]
+    pub(crate) const fn new(kind: ParseHalfErrorKind) -> Self {
+        ParseHalfError { kind }
+    }
+
+    /// Returns the reason parsing failed
+    #[inline]
+    pub const fn kind(&self) -> ParseHalfErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ParseHalfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseHalfErrorKind::Empty => "cannot parse float from empty string",
+            ParseHalfErrorKind::Invalid => "invalid float literal",
+            ParseHalfErrorKind::Overflow => "number too large to fit in target type",
+            ParseHalfErrorKind::Underflow => "number too small to fit in target type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseHalfError {}
+
+/// Number of significant decimal digits kept exactly
+///
+/// Every value halfway between two adjacent `bf16` or `f16` values has fewer than 100 significant
+/// digits, so truncating to this many digits and remembering whether anything nonzero was dropped
+/// never changes which side of a halfway point the input lies on.
+const MAX_DIGITS: usize = 128;
+
+/// Limbs of the big integer, enough for the largest intermediate of [`MAX_DIGITS`] digits scaled
+/// by the most extreme exponent that is not handled up front
+const LIMBS: usize = 24;
+
+/// A little-endian fixed-size unsigned big integer
+#[derive(Clone, Copy)]
+struct Big([u32; LIMBS]);
+
+impl Big {
+    const fn from_u32(n: u32) -> Big {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = n;
+        Big(limbs)
+    }
+
+    /// Computes `self * m + a`
+    fn mul_add_small(&mut self, m: u32, a: u32) {
+        let mut carry = a as u64;
+        for limb in self.0.iter_mut() {
+            let v = *limb as u64 * m as u64 + carry;
+            *limb = v as u32;
+            carry = v >> 32;
+        }
+        debug_assert!(carry == 0);
+    }
+
+    fn mul_pow10(&mut self, mut n: u32) {
+        while n >= 9 {
+            self.mul_add_small(1_000_000_000, 0);
+            n -= 9;
+        }
+        self.mul_add_small(10u32.pow(n), 0);
+    }
+
+    fn shl(&mut self, n: u32) {
+        let limbs = (n / 32) as usize;
+        let bits = n % 32;
+        for i in (0..LIMBS).rev() {
+            let hi = if i >= limbs { self.0[i - limbs] } else { 0 };
+            let lo = if i > limbs { self.0[i - limbs - 1] } else { 0 };
+            self.0[i] = if bits == 0 {
+                hi
+            } else {
+                (hi << bits) | (lo >> (32 - bits))
+            };
+        }
+    }
+
+    // The following operate on the low `n` limbs only, which must hold both values entirely
+
+    fn shr1(&mut self, n: usize) {
+        for i in 0..n {
+            let hi = if i + 1 < n { self.0[i + 1] } else { 0 };
+            self.0[i] = (self.0[i] >> 1) | (hi << 31);
+        }
+    }
+
+    fn cmp(&self, other: &Big, n: usize) -> Ordering {
+        self.0[..n].iter().rev().cmp(other.0[..n].iter().rev())
+    }
+
+    /// Computes `self - other`, which must not be negative
+    fn sub(&mut self, other: &Big, n: usize) {
+        let mut borrow = false;
+        for (a, &b) in self.0[..n].iter_mut().zip(other.0[..n].iter()) {
+            let (v, b1) = a.overflowing_sub(b);
+            let (v, b2) = v.overflowing_sub(borrow as u32);
+            *a = v;
+            borrow = b1 || b2;
+        }
+        debug_assert!(!borrow);
+    }
+
+    fn bit_len(&self) -> u32 {
+        for i in (0..LIMBS).rev() {
+            if self.0[i] != 0 {
+                return i as u32 * 32 + 32 - self.0[i].leading_zeros();
+            }
+        }
+        0
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&limb| limb == 0)
+    }
+}
+
+/// A decimal literal split into its parts
+enum Literal {
+    Nan,
+    Inf,
+    /// `digits * 10^exp`, where `digits` holds at most [`MAX_DIGITS`] significant digits and
+    /// `sticky` records whether any nonzero digit was dropped after them
+    Finite {
+        digits: Big,
+        count: usize,
+        exp: i32,
+        sticky: bool,
+    },
+}
+
+/// Splits `src` into its sign and value, using the same syntax as `f32::from_str`
+fn scan(src: &[u8]) -> Result<(bool, Literal), ParseHalfError> {
+    let invalid = ParseHalfError::new(ParseHalfErrorKind::Invalid);
+    if src.is_empty() {
+        return Err(ParseHalfError::new(ParseHalfErrorKind::Empty));
+    }
+    let (sign, src) = match src[0] {
+        b'-' => (true, &src[1..]),
+        b'+' => (false, &src[1..]),
+        _ => (false, src),
+    };
+    if src.eq_ignore_ascii_case(b"inf") || src.eq_ignore_ascii_case(b"infinity") {
+        return Ok((sign, Literal::Inf));
+    }
+    if src.eq_ignore_ascii_case(b"nan") {
+        return Ok((sign, Literal::Nan));
+    }
+
+    let mut digits = Big::from_u32(0);
+    let mut count = 0;
+    let mut sticky = false;
+    // Decimal exponent adjustment from dropped integer digits and kept fraction digits
+    let mut exp = 0i32;
+    let mut seen_digit = false;
+    let mut seen_point = false;
+    let mut i = 0;
+    while i < src.len() {
+        match src[i] {
+            c @ b'0'..=b'9' => {
+                seen_digit = true;
+                let d = (c - b'0') as u32;
+                if count == 0 && d == 0 {
+                    // Leading zeros are not significant
+                    if seen_point {
+                        exp = exp.saturating_sub(1);
+                    }
+                } else if count < MAX_DIGITS {
+                    digits.mul_add_small(10, d);
+                    count += 1;
+                    if seen_point {
+                        exp = exp.saturating_sub(1);
+                    }
+                } else {
+                    sticky |= d != 0;
+                    if !seen_point {
+                        exp = exp.saturating_add(1);
+                    }
+                }
+            }
+            b'.' if !seen_point => seen_point = true,
+            _ => break,
+        }
+        i += 1;
+    }
+    if !seen_digit {
+        return Err(invalid);
+    }
+
+    if i < src.len() {
+        if src[i] != b'e' && src[i] != b'E' {
+            return Err(invalid);
+        }
+        i += 1;
+        let (exp_sign, rest) = match src.get(i) {
+            Some(b'-') => (true, &src[i + 1..]),
+            Some(b'+') => (false, &src[i + 1..]),
+            _ => (false, &src[i..]),
+        };
+        if rest.is_empty() {
+            return Err(invalid);
+        }
+        let mut e = 0i32;
+        for &c in rest {
+            if !c.is_ascii_digit() {
+                return Err(invalid);
+            }
+            e = e.saturating_mul(10).saturating_add((c - b'0') as i32);
+        }
+        exp = if exp_sign {
+            exp.saturating_sub(e)
+        } else {
+            exp.saturating_add(e)
+        };
+    }
+
+    Ok((
+        sign,
+        Literal::Finite {
+            digits,
+            count,
+            exp,
+            sticky,
+        },
+    ))
+}
+
+/// The exact value of a decimal literal, ready to be rounded into a binary format
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Value {
+    Nan,
+    Inf,
+    Zero,
+    /// `m * 2^e`, with any inexact low-order bits OR-ed into the least significant bit of `m`.
+    /// Values too large or too small for any supported format are replaced with ones that still
+    /// overflow or underflow.
+    Finite(u64, i32),
+}
+
+/// Parses `src` as a decimal number, returning its sign and a value suitable for rounding
+pub(crate) fn parse_value(src: &[u8]) -> Result<(bool, Value), ParseHalfError> {
+    let (sign, literal) = scan(src)?;
+    let (digits, count, exp, sticky) = match literal {
+        Literal::Nan => return Ok((sign, Value::Nan)),
+        Literal::Inf => return Ok((sign, Value::Inf)),
+        Literal::Finite {
+            digits,
+            count,
+            exp,
+            sticky,
+        } => (digits, count, exp, sticky),
+    };
+    if count == 0 {
+        return Ok((sign, Value::Zero));
+    }
+
+    // The value lies in [10^(magnitude - 1), 10^magnitude). Anything at least 10^40 overflows and
+    // anything below 10^-46 underflows every format, which also bounds the big integers below.
+    let magnitude = exp.saturating_add(count as i32);
+    if magnitude > 40 {
+        return Ok((sign, Value::Finite(1 << 63, 70)));
+    }
+    if magnitude < -46 {
+        return Ok((sign, Value::Finite(1, -200)));
+    }
+
+    // value = a / b, scaled by 2^-shift so that the integer quotient has 63 or 64 bits
+    let (mut a, mut b) = (digits, Big::from_u32(1));
+    if exp >= 0 {
+        a.mul_pow10(exp as u32);
+    } else {
+        b.mul_pow10(exp.unsigned_abs());
+    }
+    let shift = 63 - (a.bit_len() as i32 - b.bit_len() as i32);
+    if shift >= 0 {
+        a.shl(shift as u32);
+    } else {
+        b.shl(shift.unsigned_abs());
+    }
+
+    // Restoring division, one quotient bit at a time
+    let mut q = 0u64;
+    b.shl(63);
+    let n = (b.bit_len() as usize / 32 + 2).min(LIMBS);
+    for i in (0..64).rev() {
+        if a.cmp(&b, n) != Ordering::Less {
+            a.sub(&b, n);
+            q |= 1 << i;
+        }
+        b.shr1(n);
+    }
+    let q = q | (sticky || !a.is_zero()) as u64;
+    Ok((sign, Value::Finite(q, -shift)))
+}
+
+/// Parses `src` as a decimal number and rounds it to the nearest value of `fmt`, ties to even
+///
+/// In `strict` mode, finite inputs that round to ±∞ and nonzero inputs that round to ±0 are
+/// reported as errors instead, as are `nan` and `inf` in formats that cannot represent them.
+pub(crate) fn parse(fmt: Format, src: &[u8], strict: bool) -> Result<u32, ParseHalfError> {
+    let (sign, value) = parse_value(src)?;
+    let mut status = Status::default();
+    let bits = match value {
+        Value::Nan => softfloat::special(fmt, sign, true, &mut status),
+        Value::Inf => softfloat::special(fmt, sign, false, &mut status),
+        Value::Zero => return Ok(fmt.zero(sign)),
+        Value::Finite(m, e) => {
+            let rounding = RoundingMode::NearestTiesToEven;
+            softfloat::round_pack(fmt, rounding, sign, m, e, &mut status)
+        }
+    };
+
+    if strict {
+        // Formats without NaN convert it to zero
+        if value == Value::Nan && status.invalid {
+            return Err(ParseHalfError::new(ParseHalfErrorKind::Invalid));
+        }
+        // Formats without ±∞ or NaN report overflow, and ±∞ itself, as an invalid operation
+        if status.overflow || status.invalid {
+            return Err(ParseHalfError::new(ParseHalfErrorKind::Overflow));
+        }
+        if bits & !fmt.sign_mask() == 0 {
+            return Err(ParseHalfError::new(ParseHalfErrorKind::Underflow));
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::softfloat::{BF16, F16};
+    use quickcheck_macros::quickcheck;
+    use std::format;
+
+    fn parse_ok(fmt: Format, src: &str) -> u32 {
+        parse(fmt, src.as_bytes(), false).unwrap()
+    }
+
+    fn kind(fmt: Format, src: &str, strict: bool) -> ParseHalfErrorKind {
+        parse(fmt, src.as_bytes(), strict).unwrap_err().kind()
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!(parse_ok(F16, "1"), 0x3C00);
+        assert_eq!(parse_ok(F16, "+1."), 0x3C00);
+        assert_eq!(parse_ok(F16, "-.5"), 0xB800);
+        assert_eq!(parse_ok(F16, "0.0001e4"), 0x3C00);
+        assert_eq!(parse_ok(F16, "100E-2"), 0x3C00);
+        assert_eq!(parse_ok(F16, "1e+0"), 0x3C00);
+        assert_eq!(parse_ok(F16, "-0"), 0x8000);
+        assert_eq!(parse_ok(F16, "0e999999999999"), 0x0000);
+        assert_eq!(parse_ok(F16, "inf"), 0x7C00);
+        assert_eq!(parse_ok(F16, "-Infinity"), 0xFC00);
+        assert!(F16.is_nan(parse_ok(F16, "NaN")));
+        assert_eq!(parse_ok(BF16, "-inf"), 0xFF80);
+
+        assert_eq!(kind(F16, "", false), ParseHalfErrorKind::Empty);
+        for src in &[
+            "-", ".", "e5", "1e", "1e+", "1.2.3", "1x", " 1", "1 ", "infinit", "++1", "0x10",
+        ] {
+            assert_eq!(
+                kind(F16, src, false),
+                ParseHalfErrorKind::Invalid,
+                "{}",
+                src
+            );
+        }
+    }
+
+    #[test]
+    fn rounding_near_halfway() {
+        // 1 + 2^-11 is halfway between 1 and the next f16, and rounds to even
+        assert_eq!(parse_ok(F16, "1.00048828125"), 0x3C00);
+        assert_eq!(
+            parse_ok(F16, "1.00048828125000000000000000000000000000001"),
+            0x3C01
+        );
+        assert_eq!(
+            parse_ok(F16, "1.00048828124999999999999999999999999999999"),
+            0x3C00
+        );
+        // 65520 is halfway between f16::MAX and the next power of two
+        assert_eq!(parse_ok(F16, "65519.99999999999999"), 0x7BFF);
+        assert_eq!(parse_ok(F16, "65520"), 0x7C00);
+        assert_eq!(parse_ok(F16, "70000"), 0x7C00);
+        // Half of the smallest subnormal rounds to even, i.e. zero
+        assert_eq!(parse_ok(F16, "2.98023223876953125e-8"), 0x0000);
+        assert_eq!(parse_ok(F16, "2.98023223876953125000000000001e-8"), 0x0001);
+        // A nonzero digit beyond the kept digits still breaks a tie
+        let long = format!("1.00048828125{}1", "0".repeat(300));
+        assert_eq!(parse_ok(F16, &long), 0x3C01);
+        let long = format!("{}1e-300", "0".repeat(300));
+        assert_eq!(parse_ok(F16, &long), 0x0000);
+        // bf16 halfway between 1 and 1 + 2^-7
+        assert_eq!(parse_ok(BF16, "1.00390625"), 0x3F80);
+        assert_eq!(parse_ok(BF16, "1.0039062500001"), 0x3F81);
+        assert_eq!(parse_ok(BF16, "1e-50"), 0x0000);
+        assert_eq!(parse_ok(BF16, "1e40"), 0x7F80);
+    }
+
+    #[test]
+    fn strict() {
+        assert_eq!(kind(F16, "70000", true), ParseHalfErrorKind::Overflow);
+        assert_eq!(kind(F16, "-1e10", true), ParseHalfErrorKind::Overflow);
+        assert_eq!(kind(F16, "1e-10", true), ParseHalfErrorKind::Underflow);
+        assert_eq!(kind(BF16, "-1e-50", true), ParseHalfErrorKind::Underflow);
+        assert_eq!(parse(F16, b"65519", true), Ok(0x7BFF));
+        assert_eq!(parse(F16, b"6e-8", true), Ok(0x0001));
+        assert_eq!(parse(F16, b"-0.0", true), Ok(0x8000));
+        assert_eq!(parse(F16, b"inf", true), Ok(0x7C00));
+    }
+
+    /// Decodes bits exactly into an `f64`, which prints as the shortest decimal that round trips
+    fn to_f64(fmt: Format, bits: u32) -> f64 {
+        let sign = if bits & fmt.sign_mask() != 0 {
+            -1.0
+        } else {
+            1.0
+        };
+        let exp = ((bits & fmt.exp_mask()) >> fmt.man_bits) as i32;
+        let man = (bits & fmt.man_mask()) as f64;
+        let scale = 2f64.powi(fmt.emin() - fmt.man_bits as i32);
+        let man = if exp == 0 {
+            man
+        } else {
+            man + (1u32 << fmt.man_bits) as f64
+        };
+        sign * man * scale * 2f64.powi((exp - 1).max(0))
+    }
+
+    fn check_round_trip_and_midpoints(fmt: Format, bits: u16) -> bool {
+        let bits = bits as u32;
+        let abs = bits & !fmt.sign_mask();
+        if abs >= fmt.exp_mask() - 1 {
+            return true;
+        }
+        let x = to_f64(fmt, bits);
+        let next = to_f64(fmt, bits + 1);
+        // Both the value itself and the exact midpoint to the next value are exactly
+        // representable in f64, and are printed exactly with enough digits
+        // Enough digits to print every value and midpoint of the format exactly
+        let digits = if fmt == F16 { 40 } else { 110 };
+        let exact = format!("{:.*e}", digits, x);
+        let mid = format!("{:.*e}", digits, (x + next) / 2.0);
+        let even = if bits & 1 == 0 { bits } else { bits + 1 };
+        // Append a nonzero digit to the mantissa of the midpoint to move just above it
+        let e = mid.find('e').unwrap();
+        let above = format!("{}1{}", &mid[..e], &mid[e..]);
+        parse(fmt, exact.as_bytes(), false) == Ok(bits)
+            && parse(fmt, format!("{:e}", x).as_bytes(), false) == Ok(bits)
+            && parse(fmt, mid.as_bytes(), false) == Ok(even)
+            && parse(fmt, above.as_bytes(), false) == Ok(bits + 1)
+    }
+
+    #[test]
+    fn exhaustive_round_trip_and_midpoints() {
+        // The sign is handled separately from the magnitude, so positive values suffice
+        for bits in 0..0x8000u16 {
+            assert!(check_round_trip_and_midpoints(F16, bits), "{:04X}", bits);
+        }
+    }
+
+    #[quickcheck]
+    fn qc_bf16_round_trip_and_midpoints(bits: u16) -> bool {
+        check_round_trip_and_midpoints(BF16, bits)
+    }
+}
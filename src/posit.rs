@@ -0,0 +1,1125 @@
+//! Posit types and quire accumulators
+//!
+//! Posits are an alternative to IEEE 754 floating point with tapered precision: values near one
+//! have more fraction bits than [`f16`][crate::f16] or [`bf16`][crate::bf16] of the same width,
+//! and the precision falls off gradually towards the largest and smallest magnitudes. There is a
+//! single zero, no infinities, and a single exception value, NaR ("not a real"), that takes the
+//! place of NaN. Finite results never overflow or underflow: they saturate at
+//! [`MAX`][Posit16::MAX] and [`MIN_POSITIVE`][Posit16::MIN_POSITIVE] instead.
+//!
+//! A posit of `N` bits has a sign bit, a variable-length regime, up to `ES` exponent bits and the
+//! remaining fraction bits, and is ordered like a two's complement `N`-bit integer. The types
+//! [`Posit8`], [`Posit16`] and [`Posit32`] take `ES` as a const generic parameter. The aliases
+//! [`p8`], [`p16`] and [`p32`] use 0, 1 and 2 exponent bits, the common choices for each width.
+//! The 2022 posit standard uses 2 exponent bits for every width, which is `Posit8<2>`,
+//! `Posit16<2>` and [`p32`].
+//!
+//! Every finite posit must lie within 2<sup>±120</sup>, which allows `ES` up to 4 for
+//! [`Posit8`], 3 for [`Posit16`] and 2 for [`Posit32`]. Using a type with a larger `ES` fails to
+//! compile.
+//!
+//! Conversions and parsing are correctly rounded, to nearest with ties to even as the posit
+//! standard defines it on the encoding. Like the other types of this crate, posits are intended
+//! for storage and provide no arithmetic. For exact dot products, the quire types [`Quire8`],
+//! [`Quire16`] and [`Quire32`] accumulate sums of products without any rounding and round only
+//! once when the result is read.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use half::posit::{p16, Quire16};
+//!
+//! let x = p16::from_f32(0.1);
+//! assert_eq!(x.to_string(), "0.1");
+//! assert_eq!(x.to_f64(), 0.100006103515625);
+//! assert_eq!(p16::from_f32(1e10), p16::MAX);
+//! assert!(p16::from_f32(f32::NAN).is_nar());
+//!
+//! // The quire keeps the small product that rounding after every step would lose
+//! let a = [p16::MAX, p16::ONE, p16::MAX];
+//! let b = [p16::MAX, p16::EPSILON, -p16::MAX];
+//! let mut quire = Quire16::new();
+//! quire.add_dot(&a, &b);
+//! assert_eq!(quire.to_posit(), p16::EPSILON);
+//! ```
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+        Write,
+    },
+    ops::Neg,
+    str::FromStr,
+};
+
+use crate::{
+    parse::{self, Value},
+    shortest::{self, Buffer, Style},
+    ParseHalfError,
+};
+
+/// Largest power of two exponent of any supported posit
+const MAX_SCALE: u32 = 120;
+
+/// Number of 64-bit limbs of a quire
+const QUIRE_LIMBS: usize = 8;
+
+/// Number of fraction bits of a quire, enough for the product of two of the smallest posits
+const QUIRE_FRAC: i32 = 2 * MAX_SCALE as i32;
+
+/// A quire: a two's complement fixed point number with [`QUIRE_FRAC`] fraction bits
+type Limbs = [u64; QUIRE_LIMBS];
+
+/// The quire NaR, which like the posit NaR is the most negative two's complement pattern
+const QUIRE_NAR: Limbs = [0, 0, 0, 0, 0, 0, 0, 1 << 63];
+
+/// A runtime description of a posit layout
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Layout {
+    bits: u32,
+    es: u32,
+}
+
+impl Layout {
+    /// Returns the layout, failing const evaluation if it is not supported
+    const fn checked(bits: u32, es: u32) -> Layout {
+        let layout = Layout { bits, es };
+        crate::const_assert(layout.is_supported());
+        layout
+    }
+
+    const fn is_supported(self) -> bool {
+        self.es <= 4 && (self.bits - 2) << self.es <= MAX_SCALE
+    }
+
+    /// Power of two exponent of the largest finite value
+    #[inline]
+    const fn max_scale(self) -> i32 {
+        ((self.bits - 2) << self.es) as i32
+    }
+
+    #[inline]
+    const fn nar(self) -> u32 {
+        1 << (self.bits - 1)
+    }
+
+    #[inline]
+    const fn mask(self) -> u32 {
+        u32::MAX >> (32 - self.bits)
+    }
+
+    /// Magnitude of the largest finite value
+    #[inline]
+    const fn max(self) -> u32 {
+        self.nar() - 1
+    }
+
+    /// Negates `bits`, which leaves zero and NaR alone
+    #[inline]
+    const fn neg(self, bits: u32) -> u32 {
+        bits.wrapping_neg() & self.mask()
+    }
+
+    /// The encoding of 2<sup>`scale`</sup>, which must be representable
+    const fn pow2(self, scale: i32) -> u32 {
+        let k = scale >> self.es;
+        let exp = (scale - (k << self.es)) as u64;
+        // The regime, exponent and fraction bits after the sign, left-aligned
+        let (regime, len) = if k >= 0 {
+            (((1u64 << (k + 1)) - 1) << 1, k as u32 + 2)
+        } else {
+            (1u64, (-k) as u32 + 1)
+        };
+        let body = (regime << (64 - len)) | (exp << (64 - len - self.es));
+        (body >> (65 - self.bits)) as u32
+    }
+
+    /// Compares two encodings, which are ordered like two's complement integers
+    #[inline]
+    fn cmp(self, a: u32, b: u32) -> Ordering {
+        let shift = 32 - self.bits;
+        ((a << shift) as i32).cmp(&((b << shift) as i32))
+    }
+
+    /// Splits an encoding that is neither zero nor NaR into its sign, its power of two exponent and
+    /// its fraction bits, left-aligned in a `u64`
+    fn decode(self, bits: u32) -> (bool, i32, u64) {
+        let sign = bits & self.nar() != 0;
+        let abs = if sign { self.neg(bits) } else { bits };
+        // The bits after the sign, left-aligned
+        let body = (abs as u64) << (65 - self.bits);
+        let (k, len) = if body >> 63 != 0 {
+            let ones = (!body).leading_zeros();
+            (ones as i32 - 1, ones + 1)
+        } else {
+            let zeros = body.leading_zeros();
+            (-(zeros as i32), zeros + 1)
+        };
+        // Exponent bits cut off by a long regime are zero
+        let rest = body << len;
+        let exp = if self.es == 0 {
+            0
+        } else {
+            (rest >> (64 - self.es)) as i32
+        };
+        (sign, (k << self.es) + exp, rest << self.es)
+    }
+
+    /// Rounds the value `(-1)^sign * m * 2^e` to the nearest posit, ties to even
+    ///
+    /// `m` must be nonzero, with any inexact low-order bits OR-ed into its least significant bit.
+    /// Magnitudes beyond the largest or below the smallest posit saturate.
+    fn round_pack(self, sign: bool, m: u64, e: i32) -> u32 {
+        debug_assert!(m != 0);
+        let lz = m.leading_zeros();
+        let scale = e.saturating_add(63 - lz as i32);
+        let max = self.max_scale();
+        let abs = if scale >= max {
+            self.max()
+        } else if scale < -max {
+            1
+        } else {
+            let frac = (m << lz) << 1;
+            let k = scale >> self.es;
+            let exp = (scale - (k << self.es)) as u128;
+            let (regime, len) = if k >= 0 {
+                (((1u128 << (k + 1)) - 1) << 1, k as u32 + 2)
+            } else {
+                (1u128, (-k) as u32 + 1)
+            };
+            // Every bit of the exact value after the sign, left-aligned
+            let body = (regime << (128 - len))
+                | (exp << (128 - len - self.es))
+                | ((frac as u128) << (64 - len - self.es));
+            let keep = self.bits - 1;
+            let kept = (body >> (128 - keep)) as u32;
+            let rest = body << keep;
+            let half = 1u128 << 127;
+            // The largest regime is excluded above, so rounding up never reaches NaR
+            kept + (rest > half || (rest == half && kept & 1 != 0)) as u32
+        };
+        if sign {
+            self.neg(abs)
+        } else {
+            abs
+        }
+    }
+
+    fn round_f64(self, value: f64) -> u32 {
+        let x = value.to_bits();
+        let sign = x >> 63 != 0;
+        let exp = ((x >> 52) & 0x7FF) as i32;
+        let man = x & 0x000F_FFFF_FFFF_FFFF;
+        if exp == 0x7FF {
+            self.nar()
+        } else if exp == 0 && man == 0 {
+            0
+        } else if exp == 0 {
+            self.round_pack(sign, man, -1074)
+        } else {
+            self.round_pack(sign, man | (1 << 52), exp - 1075)
+        }
+    }
+
+    /// Converts an encoding to `f64`, which is exact for every supported layout
+    fn to_f64(self, bits: u32) -> f64 {
+        if bits == 0 {
+            return 0.0;
+        }
+        if bits == self.nar() {
+            return f64::NAN;
+        }
+        let (sign, scale, frac) = self.decode(bits);
+        f64::from_bits(((sign as u64) << 63) | (((scale + 1023) as u64) << 52) | (frac >> 12))
+    }
+
+    /// Parses `src` as a decimal number and rounds it to the nearest posit
+    ///
+    /// NaN, infinities and the literal `NaR` all parse as NaR.
+    fn parse(self, src: &[u8]) -> Result<u32, ParseHalfError> {
+        if src.eq_ignore_ascii_case(b"nar") {
+            return Ok(self.nar());
+        }
+        let (sign, value) = parse::parse_value(src)?;
+        Ok(match value {
+            Value::Nan | Value::Inf => self.nar(),
+            Value::Zero => 0,
+            Value::Finite(m, e) => self.round_pack(sign, m, e),
+        })
+    }
+
+    /// Writes `bits` using the shortest decimal digits that round trip
+    fn write_shortest(self, bits: u32, style: Style, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if bits == self.nar() {
+            return f.pad("NaR");
+        }
+        let nonnegative = bits & self.nar() == 0;
+        let abs = if nonnegative { bits } else { self.neg(bits) };
+        if abs == 0 {
+            return shortest::write_decimal(true, 0, 0, style, f);
+        }
+        // Posits are exact in `f64`, whose formatting rounds correctly to any number of digits.
+        // Seventeen digits identify every `f64`, so the loop always finishes.
+        let value = self.to_f64(abs);
+        for precision in 0..17 {
+            let mut buf = Buffer::new();
+            write!(buf, "{:.*e}", precision, value)?;
+            if precision < 16 && self.parse(buf.as_str().as_bytes()) != Ok(abs) {
+                continue;
+            }
+            let (mut digits, mut exp) = split_scientific(buf.as_str());
+            while digits % 10 == 0 {
+                digits /= 10;
+                exp += 1;
+            }
+            return shortest::write_decimal(nonnegative, digits, exp, style, f);
+        }
+        unreachable!()
+    }
+
+    /// Adds the exact product of `a` and `b` to the quire `q`, or subtracts it if `subtract` is
+    /// set
+    fn accumulate(self, q: &mut Limbs, a: u32, b: u32, subtract: bool) {
+        if *q == QUIRE_NAR {
+            return;
+        }
+        if a == self.nar() || b == self.nar() {
+            *q = QUIRE_NAR;
+            return;
+        }
+        if a == 0 || b == 0 {
+            return;
+        }
+        let (a_sign, a_scale, a_frac) = self.decode(a);
+        let (b_sign, b_scale, b_frac) = self.decode(b);
+        // Posits have at most 29 fraction bits, so the product of the significands is exact
+        let product = ((1 << 31) | (a_frac >> 33)) * ((1 << 31) | (b_frac >> 33));
+        // The product is product * 2^(a_scale + b_scale - 62), and no posit is finer than the
+        // smallest one, so shifting right only discards zeros
+        let shift = a_scale + b_scale - 62 + QUIRE_FRAC;
+        let mut addend = [0u64; QUIRE_LIMBS];
+        if shift < 0 {
+            addend[0] = product >> -shift;
+        } else {
+            let (word, bit) = ((shift / 64) as usize, shift % 64);
+            addend[word] = product << bit;
+            if bit != 0 {
+                addend[word + 1] = product >> (64 - bit);
+            }
+        }
+
+        if (a_sign != b_sign) != subtract {
+            let mut borrow = false;
+            for (x, y) in q.iter_mut().zip(&addend) {
+                let (d, b1) = x.overflowing_sub(*y);
+                let (d, b2) = d.overflowing_sub(borrow as u64);
+                *x = d;
+                borrow = b1 || b2;
+            }
+        } else {
+            let mut carry = false;
+            for (x, y) in q.iter_mut().zip(&addend) {
+                let (s, c1) = x.overflowing_add(*y);
+                let (s, c2) = s.overflowing_add(carry as u64);
+                *x = s;
+                carry = c1 || c2;
+            }
+        }
+    }
+
+    /// Rounds the value of the quire `q` to the nearest posit
+    fn quire_to_posit(self, q: &Limbs) -> u32 {
+        if *q == QUIRE_NAR {
+            return self.nar();
+        }
+        let sign = q[QUIRE_LIMBS - 1] >> 63 != 0;
+        let mut mag = *q;
+        if sign {
+            let mut carry = true;
+            for x in mag.iter_mut() {
+                let (s, c) = (!*x).overflowing_add(carry as u64);
+                *x = s;
+                carry = c;
+            }
+        }
+        let top = match mag.iter().rposition(|&x| x != 0) {
+            Some(top) => top,
+            None => return 0,
+        };
+        // The 64 bits starting at the most significant one, with anything below OR-ed into the
+        // last bit
+        let lz = mag[top].leading_zeros();
+        let mut m = mag[top] << lz;
+        if top > 0 {
+            let next = mag[top - 1];
+            if lz != 0 {
+                m |= next >> (64 - lz);
+            }
+            let sticky = next << lz != 0 || mag[..top - 1].iter().any(|&x| x != 0);
+            m |= sticky as u64;
+        }
+        self.round_pack(sign, m, top as i32 * 64 - lz as i32 - QUIRE_FRAC)
+    }
+}
+
+/// Splits the output of `{:e}` for a positive number into its digits and power of ten
+fn split_scientific(s: &str) -> (u64, i32) {
+    let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+    let mut digits = 0u64;
+    let mut fraction_digits = 0;
+    let mut seen_point = false;
+    for b in mantissa.bytes() {
+        if b == b'.' {
+            seen_point = true;
+        } else {
+            digits = digits * 10 + (b - b'0') as u64;
+            fraction_digits += seen_point as i32;
+        }
+    }
+    (digits, exp[1..].parse::<i32>().unwrap() - fraction_digits)
+}
+
+/// An 8-bit posit with `ES` exponent bits
+///
+/// See the [module documentation][self] for an overview of posits. [`p8`] is the common choice of
+/// `Posit8<0>`, which covers 1/64 to 64 with up to 5 fraction bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::posit::p8;
+///
+/// assert_eq!(p8::from_f32(1.1).to_f32(), 1.09375);
+/// assert_eq!(p8::MAX.to_f32(), 64.0);
+/// assert_eq!(p8::from_f32(1000.0), p8::MAX);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Posit8<const ES: u32>(u8);
+
+/// A 16-bit posit with `ES` exponent bits
+///
+/// See the [module documentation][self] for an overview of posits. [`p16`] is the common choice of
+/// `Posit16<1>`, which covers 2<sup>-28</sup> to 2<sup>28</sup> with up to 12 fraction bits, two
+/// more than [`f16`][crate::f16].
+///
+/// # Examples
+///
+/// ```rust
+/// use half::posit::p16;
+///
+/// let x = p16::from_f64(3.14159);
+/// assert_eq!(x.to_string(), "3.1416");
+/// assert_eq!(p16::EPSILON.to_f32(), 2f32.powi(-12));
+/// assert!(p16::NAR < p16::MIN);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Posit16<const ES: u32>(u16);
+
+/// A 32-bit posit with `ES` exponent bits
+///
+/// See the [module documentation][self] for an overview of posits. [`p32`] is the common and
+/// standard choice of `Posit32<2>`, which covers 2<sup>-120</sup> to 2<sup>120</sup> with up to
+/// 27 fraction bits. Values near one are more precise than [`f32`], so
+/// [`to_f32`][Posit32::to_f32] rounds while [`to_f64`][Posit32::to_f64] is exact.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::posit::p32;
+///
+/// let x = p32::from_f64(1.0 + 2f64.powi(-27));
+/// assert_eq!(x.to_f64(), 1.0 + 2f64.powi(-27));
+/// assert_eq!(x.to_f32(), 1.0);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Posit32<const ES: u32>(u32);
+
+/// An 8-bit posit with no exponent bits
+#[allow(non_camel_case_types)]
+pub type p8 = Posit8<0>;
+/// A 16-bit posit with one exponent bit
+#[allow(non_camel_case_types)]
+pub type p16 = Posit16<1>;
+/// A 32-bit posit with two exponent bits
+#[allow(non_camel_case_types)]
+pub type p32 = Posit32<2>;
+
+/// An exact accumulator for sums of products of [`Posit8`] values
+///
+/// See [`Quire16`] for details.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Quire8<const ES: u32> {
+    limbs: Limbs,
+}
+
+/// An exact accumulator for sums of products of [`Posit16`] values
+///
+/// A quire is a wide fixed point register that holds any sum of products of posits without
+/// rounding, so a dot product computed with [`add_dot`][Self::add_dot] is rounded only once, by
+/// [`to_posit`][Self::to_posit]. Its 512 bits have room for 2<sup>29</sup> products of the largest
+/// posits before they overflow. Accumulating NaR makes the quire NaR until it is
+/// [cleared][Self::clear].
+///
+/// # Examples
+///
+/// ```rust
+/// use half::posit::{p16, Quire16};
+///
+/// let a = [p16::from_f32(1e5), p16::from_f32(1.5), p16::from_f32(-1e5)];
+/// let b = [p16::from_f32(1e3), p16::from_f32(0.25), p16::from_f32(1e3)];
+/// let mut quire = Quire16::new();
+/// quire.add_dot(&a, &b);
+/// assert_eq!(quire.to_posit().to_f32(), 0.375);
+///
+/// quire.add_product(p16::NAR, p16::ONE);
+/// assert!(quire.is_nar());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Quire16<const ES: u32> {
+    limbs: Limbs,
+}
+
+/// An exact accumulator for sums of products of [`Posit32`] values
+///
+/// See [`Quire16`] for details.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Quire32<const ES: u32> {
+    limbs: Limbs,
+}
+
+macro_rules! impl_posit {
+    ($ty:ident, $quire:ident, $bits:ty, $n:expr, $bytes:expr) => {
+        #[allow(trivial_numeric_casts)]
+        impl<const ES: u32> $ty<ES> {
+            const LAYOUT: Layout = Layout::checked($n, ES);
+
+            /// Zero, the only zero
+            pub const ZERO: Self = Self(0);
+            /// One
+            pub const ONE: Self = Self(1 << ($n - 2));
+            /// Negative one
+            pub const NEG_ONE: Self = Self(Self::ONE.0.wrapping_neg());
+            /// Not a real (NaR), the only exception value, which is also the smallest in the
+            /// order of posits
+            pub const NAR: Self = Self(1 << ($n - 1));
+            /// Largest finite value
+            pub const MAX: Self = Self(Self::LAYOUT.max() as $bits);
+            /// Smallest finite value
+            pub const MIN: Self = Self(Self::LAYOUT.neg(Self::LAYOUT.max()) as $bits);
+            /// Smallest positive value
+            pub const MIN_POSITIVE: Self = Self(1);
+            /// The difference between 1.0 and the next largest representable number
+            pub const EPSILON: Self = Self(Self::LAYOUT.pow2(ES as i32 + 3 - $n) as $bits);
+
+            /// The bits widened to `u32`, as the layout functions take them
+            #[inline]
+            const fn raw(self) -> u32 {
+                self.0 as u32
+            }
+
+            /// Constructs a posit from its raw bits
+            #[inline]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Converts a posit into its raw bits
+            #[inline]
+            pub const fn to_bits(self) -> $bits {
+                self.0
+            }
+
+            /// Returns the memory representation of the underlying bit representation as a byte
+            /// array in little-endian byte order
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; $bytes] {
+                self.0.to_le_bytes()
+            }
+
+            /// Returns the memory representation of the underlying bit representation as a byte
+            /// array in big-endian (network) byte order
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; $bytes] {
+                self.0.to_be_bytes()
+            }
+
+            /// Returns the memory representation of the underlying bit representation as a byte
+            /// array in native byte order
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; $bytes] {
+                self.0.to_ne_bytes()
+            }
+
+            /// Creates a posit from its representation as a byte array in little endian
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                Self(<$bits>::from_le_bytes(bytes))
+            }
+
+            /// Creates a posit from its representation as a byte array in big endian
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> Self {
+                Self(<$bits>::from_be_bytes(bytes))
+            }
+
+            /// Creates a posit from its representation as a byte array in native endianness
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [u8; $bytes]) -> Self {
+                Self(<$bits>::from_ne_bytes(bytes))
+            }
+
+            /// Constructs a posit from a 32-bit floating point value
+            ///
+            /// The value is rounded to nearest, ties to even. Magnitudes beyond
+            /// [`MAX`][Self::MAX] or below [`MIN_POSITIVE`][Self::MIN_POSITIVE] saturate, so only
+            /// zero converts to zero. NaN and ±∞ become NaR.
+            #[inline]
+            pub fn from_f32(value: f32) -> Self {
+                // Widening is exact, so this rounds only once
+                Self::from_f64(value as f64)
+            }
+
+            /// Constructs a posit from a 64-bit floating point value
+            ///
+            /// The value is rounded to nearest, ties to even, exactly once. See
+            /// [`from_f32`][Self::from_f32] for details.
+            #[inline]
+            pub fn from_f64(value: f64) -> Self {
+                Self(Self::LAYOUT.round_f64(value) as $bits)
+            }
+
+            /// Rounds the integer `(-1)^sign * magnitude` to the nearest posit
+            #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+            #[inline]
+            pub(crate) fn from_integer(sign: bool, magnitude: u64) -> Self {
+                if magnitude == 0 {
+                    Self::ZERO
+                } else {
+                    Self(Self::LAYOUT.round_pack(sign, magnitude, 0) as $bits)
+                }
+            }
+
+            /// Parses a decimal number from ASCII bytes, rounding to nearest, ties to even
+            ///
+            /// The syntax is the same as for [`f16::from_ascii`][crate::f16::from_ascii]. NaN,
+            /// infinities and `NaR` all parse as NaR.
+            #[inline]
+            pub fn from_ascii(src: &[u8]) -> Result<Self, ParseHalfError> {
+                Self::LAYOUT.parse(src).map(|bits| Self(bits as $bits))
+            }
+
+            /// Converts a posit into an [`f32`] value, rounding to nearest, ties to even, if the
+            /// posit has more fraction bits than [`f32`]
+            ///
+            /// NaR becomes NaN.
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                // The conversion to f64 is exact, so this rounds only once
+                self.to_f64() as f32
+            }
+
+            /// Converts a posit into an [`f64`] value
+            ///
+            /// This conversion is lossless as all posits can be represented exactly in [`f64`].
+            /// NaR becomes NaN.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                Self::LAYOUT.to_f64(self.raw())
+            }
+
+            /// Returns `true` if this value is NaR and `false` otherwise
+            #[inline]
+            pub const fn is_nar(self) -> bool {
+                self.0 == 1 << ($n - 1)
+            }
+
+            /// Returns `true` if this value is greater than or equal to zero
+            #[inline]
+            pub const fn is_sign_positive(self) -> bool {
+                self.0 >> ($n - 1) == 0
+            }
+
+            /// Returns `true` if this value is less than zero
+            ///
+            /// This is `false` for NaR.
+            #[inline]
+            pub const fn is_sign_negative(self) -> bool {
+                self.0 >> ($n - 1) != 0 && !self.is_nar()
+            }
+
+            /// Returns the absolute value of `self`
+            ///
+            /// The absolute value of NaR is NaR.
+            #[inline]
+            pub const fn abs(self) -> Self {
+                if self.is_sign_negative() {
+                    Self(Self::LAYOUT.neg(self.raw()) as $bits)
+                } else {
+                    self
+                }
+            }
+
+            /// Returns a number that represents the sign of `self`
+            ///
+            /// * 1.0 if the number is positive
+            /// * 0.0 if the number is zero
+            /// * −1.0 if the number is negative
+            /// * NaR if the number is NaR
+            #[inline]
+            pub const fn signum(self) -> Self {
+                if self.is_nar() || self.0 == 0 {
+                    self
+                } else if self.is_sign_negative() {
+                    Self::NEG_ONE
+                } else {
+                    Self::ONE
+                }
+            }
+        }
+
+        impl<const ES: u32> From<$ty<ES>> for f64 {
+            #[inline]
+            fn from(x: $ty<ES>) -> f64 {
+                x.to_f64()
+            }
+        }
+
+        impl<const ES: u32> PartialOrd for $ty<ES> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Posits are totally ordered like two's complement integers, with NaR equal to itself
+        /// and less than every other value, as the posit standard specifies
+        impl<const ES: u32> Ord for $ty<ES> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                Self::LAYOUT.cmp(self.raw(), other.raw())
+            }
+        }
+
+        impl<const ES: u32> FromStr for $ty<ES> {
+            type Err = ParseHalfError;
+            fn from_str(src: &str) -> Result<Self, ParseHalfError> {
+                Self::from_ascii(src.as_bytes())
+            }
+        }
+
+        impl<const ES: u32> Debug for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if (f.alternate() || f.precision().is_some()) && !self.is_nar() {
+                    Debug::fmt(&self.to_f64(), f)
+                } else {
+                    Self::LAYOUT.write_shortest(self.raw(), Style::Debug, f)
+                }
+            }
+        }
+
+        impl<const ES: u32> Display for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if (f.alternate() || f.precision().is_some()) && !self.is_nar() {
+                    Display::fmt(&self.to_f64(), f)
+                } else {
+                    Self::LAYOUT.write_shortest(self.raw(), Style::Plain, f)
+                }
+            }
+        }
+
+        impl<const ES: u32> LowerExp for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if (f.alternate() || f.precision().is_some()) && !self.is_nar() {
+                    LowerExp::fmt(&self.to_f64(), f)
+                } else {
+                    Self::LAYOUT.write_shortest(self.raw(), Style::Exp(b'e'), f)
+                }
+            }
+        }
+
+        impl<const ES: u32> UpperExp for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                if (f.alternate() || f.precision().is_some()) && !self.is_nar() {
+                    UpperExp::fmt(&self.to_f64(), f)
+                } else {
+                    Self::LAYOUT.write_shortest(self.raw(), Style::Exp(b'E'), f)
+                }
+            }
+        }
+
+        impl<const ES: u32> Binary for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:b}", self.0)
+            }
+        }
+
+        impl<const ES: u32> Octal for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:o}", self.0)
+            }
+        }
+
+        impl<const ES: u32> LowerHex for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:x}", self.0)
+            }
+        }
+
+        impl<const ES: u32> UpperHex for $ty<ES> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(f, "{:X}", self.0)
+            }
+        }
+
+        impl<const ES: u32> Neg for $ty<ES> {
+            type Output = Self;
+
+            #[allow(trivial_numeric_casts)]
+            fn neg(self) -> Self::Output {
+                Self(Self::LAYOUT.neg(self.raw()) as $bits)
+            }
+        }
+
+        #[allow(trivial_numeric_casts)]
+        impl<const ES: u32> $quire<ES> {
+            /// Constructs a quire holding zero
+            #[inline]
+            pub const fn new() -> Self {
+                Self {
+                    limbs: [0; QUIRE_LIMBS],
+                }
+            }
+
+            /// Constructs a quire holding the value of `x`
+            #[inline]
+            pub fn from_posit(x: $ty<ES>) -> Self {
+                let mut quire = Self::new();
+                quire.add_posit(x);
+                quire
+            }
+
+            /// Resets the quire to zero
+            #[inline]
+            pub fn clear(&mut self) {
+                self.limbs = [0; QUIRE_LIMBS];
+            }
+
+            /// Returns `true` if NaR has been accumulated and `false` otherwise
+            #[inline]
+            pub fn is_nar(&self) -> bool {
+                self.limbs == QUIRE_NAR
+            }
+
+            /// Adds `x` to the quire exactly
+            #[inline]
+            pub fn add_posit(&mut self, x: $ty<ES>) {
+                self.add_product(x, $ty::ONE);
+            }
+
+            /// Subtracts `x` from the quire exactly
+            #[inline]
+            pub fn sub_posit(&mut self, x: $ty<ES>) {
+                self.sub_product(x, $ty::ONE);
+            }
+
+            /// Adds the product of `a` and `b` to the quire exactly
+            #[inline]
+            pub fn add_product(&mut self, a: $ty<ES>, b: $ty<ES>) {
+                $ty::<ES>::LAYOUT.accumulate(&mut self.limbs, a.raw(), b.raw(), false);
+            }
+
+            /// Subtracts the product of `a` and `b` from the quire exactly
+            #[inline]
+            pub fn sub_product(&mut self, a: $ty<ES>, b: $ty<ES>) {
+                $ty::<ES>::LAYOUT.accumulate(&mut self.limbs, a.raw(), b.raw(), true);
+            }
+
+            /// Adds the dot product of `a` and `b` to the quire exactly
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if the two slices have different lengths.
+            pub fn add_dot(&mut self, a: &[$ty<ES>], b: &[$ty<ES>]) {
+                assert_eq!(a.len(), b.len(), "slices have different lengths");
+                for (&x, &y) in a.iter().zip(b) {
+                    self.add_product(x, y);
+                }
+            }
+
+            /// Rounds the value of the quire to the nearest posit, ties to even
+            ///
+            /// Like conversions, values out of range saturate, and only an exact zero becomes
+            /// zero.
+            #[inline]
+            pub fn to_posit(&self) -> $ty<ES> {
+                $ty(<$ty<ES>>::LAYOUT.quire_to_posit(&self.limbs) as $bits)
+            }
+        }
+    };
+}
+
+impl_posit!(Posit8, Quire8, u8, 8, 1);
+impl_posit!(Posit16, Quire16, u16, 16, 2);
+impl_posit!(Posit32, Quire32, u32, 32, 4);
+
+impl<const ES: u32> From<Posit8<ES>> for f32 {
+    #[inline]
+    fn from(x: Posit8<ES>) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl<const ES: u32> From<Posit16<ES>> for f32 {
+    #[inline]
+    fn from(x: Posit16<ES>) -> f32 {
+        x.to_f32()
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use quickcheck_macros::quickcheck;
+    use std::{format, string::ToString, vec::Vec};
+
+    /// Checks every encoding of `layout` for exact round trips, ordering and rounding of the
+    /// halfway points, which are the encodings of one more bit ending in one
+    fn check_exhaustive(layout: Layout) {
+        let wider = Layout {
+            bits: layout.bits + 1,
+            es: layout.es,
+        };
+        let mut previous = f64::NEG_INFINITY;
+        for i in 1..=layout.mask() {
+            // Every encoding except NaR, in increasing order
+            let bits = (layout.nar() + i) & layout.mask();
+            let value = layout.to_f64(bits);
+            assert!(value > previous, "{:X}", bits);
+            previous = value;
+            assert_eq!(layout.round_f64(value), bits, "{:X}", bits);
+            assert_eq!(layout.parse(value.to_string().as_bytes()), Ok(bits));
+            // Nothing rounds to zero, and nothing rounds past the largest posit
+            let next = (bits + 1) & layout.mask();
+            if bits == layout.max() || bits == 0 || next == 0 {
+                continue;
+            }
+            let half = wider.to_f64(((bits << 1) | 1) & wider.mask());
+            // The neighbouring doubles above and below the halfway point
+            let (up, down) = if half > 0.0 {
+                (half.to_bits() + 1, half.to_bits() - 1)
+            } else {
+                (half.to_bits() - 1, half.to_bits() + 1)
+            };
+            let (up, down) = (f64::from_bits(up), f64::from_bits(down));
+            let even = if bits & 1 == 0 { bits } else { next };
+            assert_eq!(layout.round_f64(half), even, "{:X}", bits);
+            assert_eq!(layout.round_f64(up), next, "{:X}", bits);
+            assert_eq!(layout.round_f64(down), bits, "{:X}", bits);
+        }
+    }
+
+    #[test]
+    fn exhaustive_posit8() {
+        for es in 0..=4 {
+            check_exhaustive(Layout::checked(8, es));
+        }
+    }
+
+    #[test]
+    fn exhaustive_posit16() {
+        for es in 0..=3 {
+            check_exhaustive(Layout::checked(16, es));
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(p8::ONE.to_f32(), 1.0);
+        assert_eq!(p8::NEG_ONE.to_f32(), -1.0);
+        assert_eq!(p8::MAX.to_f32(), 64.0);
+        assert_eq!(p8::MIN.to_f32(), -64.0);
+        assert_eq!(p8::MIN_POSITIVE.to_f32(), 1.0 / 64.0);
+        assert_eq!(p8::EPSILON.to_f32(), 1.0 / 32.0);
+
+        assert_eq!(p16::ONE.to_bits(), 0x4000);
+        assert_eq!(p16::NEG_ONE.to_bits(), 0xC000);
+        assert_eq!(p16::MAX.to_f64(), 2f64.powi(28));
+        assert_eq!(p16::MIN_POSITIVE.to_f64(), 2f64.powi(-28));
+        assert_eq!(p16::EPSILON.to_f64(), 2f64.powi(-12));
+        assert_eq!(Posit16::<2>::EPSILON.to_f64(), 2f64.powi(-11));
+        assert_eq!(Posit16::<2>::MAX.to_f64(), 2f64.powi(56));
+
+        assert_eq!(p32::ONE.to_bits(), 0x4000_0000);
+        assert_eq!(p32::MAX.to_f64(), 2f64.powi(120));
+        assert_eq!(p32::MIN_POSITIVE.to_f64(), 2f64.powi(-120));
+        assert_eq!(p32::EPSILON.to_f64(), 2f64.powi(-27));
+        assert_eq!(Posit32::<0>::EPSILON.to_f64(), 2f64.powi(-29));
+        assert_eq!(Posit8::<4>::EPSILON.to_f64(), 0.5);
+
+        for &es_one in &[Posit8::<3>::ONE.to_f64(), Posit32::<1>::ONE.to_f64()] {
+            assert_eq!(es_one, 1.0);
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(p16::from_f32(0.0), p16::ZERO);
+        assert_eq!(p16::from_f32(-0.0), p16::ZERO);
+        assert!(p16::from_f32(f32::NAN).is_nar());
+        assert!(p16::from_f64(f64::NEG_INFINITY).is_nar());
+        assert!(p16::NAR.to_f32().is_nan());
+        assert_eq!(p16::from_f64(1e300), p16::MAX);
+        assert_eq!(p16::from_f64(-1e300), p16::MIN);
+        assert_eq!(p16::from_f64(1e-300), p16::MIN_POSITIVE);
+        assert_eq!(p16::from_f64(-5e-324), -p16::MIN_POSITIVE);
+        assert_eq!(p32::from_f32(f32::MAX), p32::MAX);
+        assert_eq!(p32::from_f32(1.5).to_f32(), 1.5);
+        assert_eq!(p8::from_f32(-3.3).to_f32(), -3.25);
+
+        assert_eq!(p16::from_integer(false, 1000), p16::from_f32(1000.0));
+        assert_eq!(
+            p32::from_integer(true, u64::MAX),
+            p32::from_f64(-(u64::MAX as f64))
+        );
+        assert_eq!(p32::from_bits(0x4000_0001).to_f64(), 1.0 + 2f64.powi(-27));
+        assert_eq!(p32::from_le_bytes(p32::ONE.to_le_bytes()), p32::ONE);
+    }
+
+    #[quickcheck]
+    fn qc_posit32_round_trip(bits: u32) -> bool {
+        let x = p32::from_bits(bits);
+        x.is_nar() || (p32::from_f64(x.to_f64()) == x && x.to_string().parse::<p32>() == Ok(x))
+    }
+
+    #[quickcheck]
+    fn qc_posit32_monotonic(a: f64, b: f64) -> bool {
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        !a.is_finite() || !b.is_finite() || p32::from_f64(a) <= p32::from_f64(b)
+    }
+
+    #[quickcheck]
+    fn qc_posit32_nearest(x: f64) -> bool {
+        // Away from the extremes, where long regimes cut off exponent bits, rounding to the
+        // nearest encoding is rounding to the nearest value
+        if !(2f64.powi(-100)..2f64.powi(100)).contains(&x.abs()) {
+            return true;
+        }
+        let p = p32::from_f64(x);
+        let err = (p.to_f64() - x).abs();
+        let up = p32::from_bits(p.to_bits().wrapping_add(1)).to_f64();
+        let down = p32::from_bits(p.to_bits().wrapping_sub(1)).to_f64();
+        err <= (up - x).abs() && err <= (down - x).abs()
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(p16::NAR < p16::MIN);
+        assert_eq!(p16::NAR, p16::NAR);
+        assert!(p16::MIN < p16::NEG_ONE);
+        assert!(-p16::MIN_POSITIVE < p16::ZERO);
+        assert!(p16::ZERO < p16::MIN_POSITIVE);
+        assert!(p16::ONE < p16::MAX);
+        assert_eq!(-p16::NAR, p16::NAR);
+        assert_eq!(-p16::ZERO, p16::ZERO);
+        assert_eq!(p16::MIN.abs(), p16::MAX);
+        assert!(p16::NAR.abs().is_nar());
+        assert_eq!(p16::from_f32(-7.0).signum(), p16::NEG_ONE);
+        assert_eq!(p16::ZERO.signum(), p16::ZERO);
+        assert!(!p16::NAR.is_sign_negative() && !p16::NAR.is_sign_positive());
+        assert!(p16::ZERO.is_sign_positive());
+
+        let mut values: Vec<p8> = (0..=255u8).map(p8::from_bits).collect();
+        values.sort();
+        assert!(values[0].is_nar());
+        assert!(values[1..]
+            .windows(2)
+            .all(|w| w[0].to_f64() < w[1].to_f64()));
+    }
+
+    #[test]
+    fn format_and_parse() {
+        assert_eq!(p16::from_f32(0.1).to_string(), "0.1");
+        // Everything above the largest posit rounds to it, so one digit is enough
+        assert_eq!(p16::MAX.to_string(), "300000000");
+        assert_eq!(format!("{:?}", p16::MAX), "300000000.0");
+        assert_eq!(format!("{:?}", p16::ONE), "1.0");
+        assert_eq!(format!("{:e}", p32::MIN_POSITIVE), "8e-37");
+        assert_eq!(format!("{:E}", -p8::from_f32(1.5)), "-1.5E0");
+        assert_eq!(format!("{}", p16::NAR), "NaR");
+        assert_eq!(format!("{:>5}", p16::ZERO), "    0");
+        assert_eq!(format!("{:.2}", p16::from_f32(1.0 / 3.0)), "0.33");
+        assert_eq!(format!("{:04X}", p16::ONE), "4000");
+
+        assert_eq!("0.1".parse::<p16>(), Ok(p16::from_f32(0.1)));
+        assert_eq!("-1e99".parse::<p16>(), Ok(p16::MIN));
+        assert!("NaR".parse::<p16>().unwrap().is_nar());
+        assert!("nan".parse::<p16>().unwrap().is_nar());
+        assert!("inf".parse::<p32>().unwrap().is_nar());
+        assert!("1.0x".parse::<p8>().is_err());
+    }
+
+    #[test]
+    fn quire() {
+        let mut q = Quire16::new();
+        assert_eq!(q.to_posit(), p16::ZERO);
+        q.add_product(p16::MAX, p16::MAX);
+        q.add_posit(p16::EPSILON);
+        q.sub_product(p16::MAX, p16::MAX);
+        assert_eq!(q.to_posit(), p16::EPSILON);
+        q.sub_posit(p16::EPSILON);
+        q.sub_posit(p16::ONE);
+        assert_eq!(q.to_posit(), p16::NEG_ONE);
+
+        q.clear();
+        q.add_product(p16::MIN_POSITIVE, p16::MIN_POSITIVE);
+        assert_eq!(q.to_posit(), p16::MIN_POSITIVE);
+        q.add_product(p16::MAX, p16::MAX);
+        assert_eq!(q.to_posit(), p16::MAX);
+        q.add_product(p16::NAR, p16::ZERO);
+        assert!(q.is_nar());
+        q.add_product(p16::ONE, p16::ONE);
+        assert!(q.to_posit().is_nar());
+
+        let mut q = Quire32::from_posit(p32::MAX);
+        for _ in 0..4 {
+            q.add_product(p32::MAX, p32::MAX);
+            q.sub_product(p32::MIN, p32::MAX);
+        }
+        q.sub_product(p32::MAX, p32::MAX.abs());
+        assert_eq!(q.to_posit(), p32::MAX);
+        let mut q = Quire32::new();
+        q.add_product(p32::MIN_POSITIVE, p32::MIN_POSITIVE);
+        q.sub_product(p32::MIN_POSITIVE, p32::MIN_POSITIVE);
+        assert_eq!(q, Quire32::new());
+    }
+
+    #[quickcheck]
+    fn qc_quire8_dot(pairs: Vec<(u8, u8)>) -> bool {
+        // Products of p8 values have at most 12 significant bits between 2^-12 and 2^12, so a
+        // short sum of them is exact in f64
+        let pairs = &pairs[..pairs.len().min(16)];
+        let a: Vec<p8> = pairs.iter().map(|&(x, _)| p8::from_bits(x)).collect();
+        let b: Vec<p8> = pairs.iter().map(|&(_, y)| p8::from_bits(y)).collect();
+        let mut q = Quire8::new();
+        q.add_dot(&a, &b);
+        let exact: f64 = a.iter().zip(&b).map(|(x, y)| x.to_f64() * y.to_f64()).sum();
+        if exact.is_nan() {
+            q.is_nar()
+        } else {
+            q.to_posit() == p8::from_f64(exact)
+        }
+    }
+}
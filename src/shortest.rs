@@ -0,0 +1,446 @@
+//! Shortest round-trip decimal formatting for narrow binary formats.
+//!
+//! This follows the digit generation of Ryū, but because the significands of these formats are
+//! at most 11 bits wide, every intermediate fits exactly in a `u128` and no precomputed tables of
+//! powers are needed. The result is the shortest decimal that parses back to the same value,
+//! choosing the closest one if there are several.
+
+use crate::softfloat::{Encoding, Format};
+use core::fmt::{self, Formatter};
+
+/// A finite decimal `digits * 10^exp`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Decimal {
+    digits: u32,
+    exp: i32,
+}
+
+/// The largest power of five that can multiply a scaled significand without overflowing a `u128`
+const MAX_POW5: u32 = 49;
+
+/// The layout of a finite value: the number of fraction bits, the exponent bias, and the biased
+/// exponent and fraction fields
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Finite {
+    man_bits: u32,
+    bias: i32,
+    exp: i32,
+    man: u32,
+}
+
+impl Finite {
+    /// Splits the finite magnitude `bits` of `fmt` into its fields
+    fn new(fmt: Format, bits: u32) -> Finite {
+        let exp = ((bits & fmt.exp_mask()) >> fmt.man_bits) as i32;
+        let man = bits & fmt.man_mask();
+        if fmt.encoding == Encoding::DlFloat && bits != 0 {
+            // The zero exponent holds normal values, so shift every exponent up by one
+            return Finite {
+                man_bits: fmt.man_bits,
+                bias: fmt.bias + 1,
+                exp: exp + 1,
+                man,
+            };
+        }
+        Finite {
+            man_bits: fmt.man_bits,
+            bias: fmt.bias,
+            exp,
+            man,
+        }
+    }
+}
+
+/// Finds the shortest decimal that rounds to the nonzero value `x`
+fn shortest(x: Finite) -> Decimal {
+    let Finite {
+        man_bits,
+        bias,
+        exp,
+        man,
+    } = x;
+    // Work with four times the significand so the halfway points to both neighbours are integers
+    let (m2, e2) = if exp == 0 {
+        (man, 1 - bias - man_bits as i32 - 2)
+    } else {
+        (man | (1 << man_bits), exp - bias - man_bits as i32 - 2)
+    };
+    // Ties round to even, so the halfway points belong to an even significand, or to an even
+    // exponent field in formats without fraction bits
+    let accept_bounds = if man_bits == 0 {
+        exp & 1 == 0
+    } else {
+        m2 & 1 == 0
+    };
+    let mv = 4 * m2 as u128;
+    let mp = mv + 2;
+    // The gap below a power of two is half as wide, except at the smallest normal exponent
+    let mm = mv - 1 - (man != 0 || exp <= 1) as u128;
+
+    // Scale the value and both halfway points by 10^-e10 so the value has an integer part, and
+    // record whether anything was discarded below it. The integer parts are exact floors.
+    let (mut vr, mut vp, mut vm, mut e10);
+    let (mut last_digit, mut vr_zeros, vp_exact, mut vm_zeros);
+    if e2 >= 0 {
+        // Integers already, and small enough to fit even for the largest bf16
+        vr = mv << e2;
+        vp = mp << e2;
+        vm = mm << e2;
+        e10 = 0;
+        last_digit = 0;
+        vr_zeros = true;
+        vp_exact = true;
+        vm_zeros = true;
+    } else {
+        // x * 10^q = x * 5^q / 2^(shift), with the remainder of the shift tracked exactly
+        let q = (-e2) as u32;
+        let q = q.min(MAX_POW5);
+        let shift = (-e2) as u32 - q;
+        let pow5 = 5u128.pow(q);
+        let mask = (1u128 << shift) - 1;
+        let (r, p, m) = (mv * pow5, mp * pow5, mm * pow5);
+        vr = r >> shift;
+        vp = p >> shift;
+        vm = m >> shift;
+        e10 = -(q as i32);
+        // The first discarded digit of the value, and whether all later ones are zero
+        let frac = (r & mask) * 10;
+        last_digit = (frac >> shift) as u32;
+        vr_zeros = frac & mask == 0;
+        vp_exact = p & mask == 0;
+        vm_zeros = m & mask == 0;
+    }
+    if !accept_bounds {
+        // An exact upper halfway point rounds away from this value, so it is not a candidate
+        vp -= vp_exact as u128;
+    }
+    vm_zeros &= accept_bounds;
+
+    // Remove digits while the interval still contains a shorter number
+    while vp / 10 > vm / 10 {
+        vm_zeros &= vm % 10 == 0;
+        vr_zeros &= last_digit == 0;
+        last_digit = (vr % 10) as u32;
+        vr /= 10;
+        vp /= 10;
+        vm /= 10;
+        e10 += 1;
+    }
+    if vm_zeros {
+        // The lower bound itself is a candidate, and may be shortened further
+        while vm % 10 == 0 {
+            vr_zeros &= last_digit == 0;
+            last_digit = (vr % 10) as u32;
+            vr /= 10;
+            vm /= 10;
+            e10 += 1;
+        }
+    }
+    // Round the remaining digits of the value to nearest, ties to even
+    if vr_zeros && last_digit == 5 && vr % 2 == 0 {
+        last_digit = 4;
+    }
+    let round_up = (vr == vm && !vm_zeros) || last_digit >= 5;
+    let digits = vr + round_up as u128;
+    Decimal {
+        digits: digits as u32,
+        exp: e10,
+    }
+}
+
+/// A small stack buffer for assembling formatted output
+pub(crate) struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    pub(crate) fn new() -> Buffer {
+        Buffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    fn push_zeros(&mut self, count: i32) {
+        for _ in 0..count {
+            self.push(b'0');
+        }
+    }
+
+    fn push_digits(&mut self, digits: &[u8]) {
+        for &d in digits {
+            self.push(d);
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only ASCII is ever pushed
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.len + s.len() > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.push_digits(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// The decimal digits of `n`, most significant first
+fn to_digits(n: u64, out: &mut [u8; 20]) -> &[u8] {
+    let mut i = out.len();
+    let mut n = n;
+    loop {
+        i -= 1;
+        out[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return &out[i..];
+        }
+    }
+}
+
+/// How a finite value should be written
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    /// Positional notation, like `Display` for primitive floats
+    Plain,
+    /// Positional notation with at least one fractional digit, switching to scientific notation
+    /// for very large or small magnitudes, like `Debug` for primitive floats
+    Debug,
+    /// Scientific notation with the given exponent marker
+    Exp(u8),
+}
+
+/// Writes the value `bits` of `fmt` using the shortest decimal digits that round trip
+fn write(fmt: Format, bits: u32, style: Style, f: &mut Formatter<'_>) -> fmt::Result {
+    let abs = bits & !fmt.sign_mask();
+    let nonnegative = bits & fmt.sign_mask() == 0;
+    if fmt.is_nan(bits) {
+        return f.pad("NaN");
+    }
+    if fmt.is_infinite(bits) {
+        return f.pad_integral(nonnegative, "", "inf");
+    }
+
+    write_finite(nonnegative, Finite::new(fmt, abs), style, f)
+}
+
+/// Writes the finite value `x` using the shortest decimal digits that round trip
+fn write_finite(nonnegative: bool, x: Finite, style: Style, f: &mut Formatter<'_>) -> fmt::Result {
+    let decimal = if x.exp == 0 && x.man == 0 {
+        Decimal { digits: 0, exp: 0 }
+    } else {
+        shortest(x)
+    };
+    write_decimal(nonnegative, decimal.digits as u64, decimal.exp, style, f)
+}
+
+/// Writes the decimal `digits * 10^exp`, whose digits must not be padded with trailing zeros
+///
+/// Zero digits write zero.
+pub(crate) fn write_decimal(
+    nonnegative: bool,
+    digits: u64,
+    exp: i32,
+    style: Style,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    let zero = digits == 0;
+    let mut storage = [0u8; 20];
+    let digits = to_digits(digits, &mut storage);
+    let len = digits.len() as i32;
+    // Number of digits before the decimal point
+    let point = len + exp;
+
+    let scientific = match style {
+        Style::Plain => false,
+        Style::Debug => !zero && !(-3..=16).contains(&point),
+        Style::Exp(_) => true,
+    };
+    let mut buf = Buffer::new();
+    if scientific {
+        buf.push(digits[0]);
+        if digits.len() > 1 {
+            buf.push(b'.');
+            buf.push_digits(&digits[1..]);
+        }
+        buf.push(if let Style::Exp(marker) = style {
+            marker
+        } else {
+            b'e'
+        });
+        let exp = point - 1;
+        if exp < 0 {
+            buf.push(b'-');
+        }
+        let mut storage = [0u8; 20];
+        buf.push_digits(to_digits(exp.unsigned_abs() as u64, &mut storage));
+    } else if point <= 0 {
+        buf.push_digits(b"0.");
+        buf.push_zeros(-point);
+        buf.push_digits(digits);
+    } else if point >= len {
+        buf.push_digits(digits);
+        buf.push_zeros(point - len);
+        if style == Style::Debug {
+            buf.push_digits(b".0");
+        }
+    } else {
+        let (int, frac) = digits.split_at(point as usize);
+        buf.push_digits(int);
+        buf.push(b'.');
+        buf.push_digits(frac);
+    }
+    f.pad_integral(nonnegative, "", buf.as_str())
+}
+
+/// Formats like `Display` for primitive floats, with the shortest digits that round trip
+#[inline]
+pub(crate) fn display(fmt: Format, bits: u32, f: &mut Formatter<'_>) -> fmt::Result {
+    write(fmt, bits, Style::Plain, f)
+}
+
+/// Formats like `Debug` for primitive floats, with the shortest digits that round trip
+#[inline]
+pub(crate) fn debug(fmt: Format, bits: u32, f: &mut Formatter<'_>) -> fmt::Result {
+    write(fmt, bits, Style::Debug, f)
+}
+
+/// Formats like `LowerExp` or `UpperExp` for primitive floats, with the shortest digits that round
+/// trip
+#[inline]
+pub(crate) fn exp(fmt: Format, bits: u32, upper: bool, f: &mut Formatter<'_>) -> fmt::Result {
+    let marker = if upper { b'E' } else { b'e' };
+    write(fmt, bits, Style::Exp(marker), f)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::{
+        bf16, f16,
+        parse::parse,
+        softfloat::{AHP, BF16, DLF16, F16},
+    };
+    use quickcheck_macros::quickcheck;
+    use std::format;
+
+    /// Parses `digits * 10^exp` into `fmt`
+    fn parse_decimal(fmt: Format, digits: u32, exp: i32) -> u32 {
+        parse(fmt, format!("{}e{}", digits, exp).as_bytes(), false).unwrap()
+    }
+
+    fn check_shortest(fmt: Format, bits: u32) {
+        let Decimal { digits, exp } = shortest(Finite::new(fmt, bits));
+        assert_eq!(
+            parse_decimal(fmt, digits, exp),
+            bits,
+            "{:04X} {}e{}",
+            bits,
+            digits,
+            exp
+        );
+        assert!(digits % 10 != 0, "{:04X} {}e{}", bits, digits, exp);
+        // No number with one digit fewer rounds to the same value
+        if digits >= 10 {
+            let shorter = digits / 10;
+            for candidate in shorter.saturating_sub(1)..=shorter + 2 {
+                assert_ne!(
+                    parse_decimal(fmt, candidate, exp + 1),
+                    bits,
+                    "{:04X} {}e{} has a shorter form {}e{}",
+                    bits,
+                    digits,
+                    exp,
+                    candidate,
+                    exp + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exhaustive_f16_shortest() {
+        for bits in 1..0x7C00 {
+            check_shortest(F16, bits);
+        }
+    }
+
+    #[test]
+    fn exhaustive_variant_shortest() {
+        // Every larger decimal saturates to the largest AHP value, so it is left out
+        for bits in 1..AHP.max() {
+            check_shortest(AHP, bits);
+        }
+        for bits in 1..=DLF16.max() {
+            check_shortest(DLF16, bits);
+        }
+    }
+
+    #[quickcheck]
+    fn qc_bf16_shortest(bits: u16) -> bool {
+        let bits = bits as u32 % 0x7F7F + 1;
+        check_shortest(BF16, bits);
+        true
+    }
+
+    #[test]
+    fn display() {
+        let cases: &[(f16, &str, &str)] = &[
+            (f16::from_f32(0.1), "0.1", "0.1"),
+            (f16::from_f32(-1.5), "-1.5", "-1.5"),
+            (f16::ONE, "1", "1.0"),
+            (f16::ZERO, "0", "0.0"),
+            (f16::NEG_ZERO, "-0", "-0.0"),
+            (f16::MAX, "65500", "65500.0"),
+            (f16::MIN_POSITIVE, "0.00006104", "6.104e-5"),
+            (f16::MIN_POSITIVE_SUBNORMAL, "0.00000006", "6e-8"),
+            (f16::from_f32(0.0001), "0.0001", "0.0001"),
+            (f16::INFINITY, "inf", "inf"),
+            (f16::NEG_INFINITY, "-inf", "-inf"),
+            (f16::NAN, "NaN", "NaN"),
+        ];
+        for &(x, display, debug) in cases {
+            assert_eq!(format!("{}", x), display);
+            assert_eq!(format!("{:?}", x), debug);
+        }
+        assert_eq!(format!("{}", bf16::from_f32(0.1)), "0.1");
+        assert_eq!(format!("{:?}", bf16::MAX), "3.39e38");
+        assert_eq!(format!("{:?}", bf16::from_f32(-3.0)), "-3.0");
+
+        assert_eq!(format!("{:e}", f16::from_f32(1234.0)), "1.234e3");
+        assert_eq!(format!("{:E}", f16::from_f32(0.1)), "1E-1");
+        assert_eq!(format!("{:e}", f16::ZERO), "0e0");
+
+        // Flags
+        assert_eq!(format!("{:+}", f16::ONE), "+1");
+        assert_eq!(format!("{:>6}", f16::from_f32(0.5)), "   0.5");
+        assert_eq!(format!("{:06}", f16::from_f32(-0.5)), "-000.5");
+        assert_eq!(format!("{:.3}", f16::from_f32(0.1)), "0.100");
+        assert_eq!(format!("{:#}", f16::from_f32(0.1)), "0.099975586");
+        assert_eq!(format!("{:#?}", f16::from_f32(0.1)), "0.099975586");
+    }
+
+    #[test]
+    fn display_round_trips() {
+        // Signs are written separately from the magnitude, so positive values suffice
+        for bits in 0..0x7C00u16 {
+            let x = f16::from_bits(bits);
+            for s in &[format!("{}", x), format!("{:?}", x), format!("{:e}", x)] {
+                assert_eq!(s.parse::<f16>().unwrap().to_bits(), bits, "{}", s);
+            }
+        }
+    }
+}
@@ -1,22 +1,36 @@
 //! Contains utility functions and traits to convert between slices of [`u16`] bits and [`f16`] or
-//! [`bf16`] numbers
+//! [`bf16`] numbers, and between slices of [`u8`] bits and the 8-bit floating point types
 //!
 //! The utility [`HalfBitsSliceExt`] sealed extension trait is implemented for `[u16]` slices,
 //! while the utility [`HalfFloatSliceExt`] sealed extension trait is implemented for both `[f16]`
-//! and `[bf16]` slices. These traits provide efficient conversions and reinterpret casting of
-//! larger buffers of floating point values, and are automatically included in the
-//! [`prelude`][crate::prelude] module.
-
-use crate::{bf16, binary16::convert, f16};
-use core::slice;
+//! and `[bf16]` slices, as well as slices of the other 16-bit formats [`f16ahp`] and
+//! [`dlfloat16`]. [`Fp8BitsSliceExt`] and [`Fp8FloatSliceExt`] do the same for `[u8]` and
+//! the 8-bit types, and [`Tf32SliceExt`] and [`Tf32FloatSliceExt`] round `[f32]` slices to
+//! TensorFloat-32 and convert `[tf32]` slices. [`PositSliceExt`] converts slices of the posit
+//! types. These traits provide efficient conversions and
+//! reinterpret casting of larger buffers of floating point values, and are automatically included
+//! in the [`prelude`][crate::prelude] module.
+
+use crate::{
+    bf16,
+    binary16::convert,
+    dlfloat16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz,
+    posit::{Posit16, Posit32, Posit8},
+    softfloat, tf32, tfloat32, RoundingMode, Saturation, Status,
+};
+use core::{num::FpCategory, slice};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
-/// Extensions to `[f16]` and `[bf16]` slices to support conversion and reinterpret operations
+/// Extensions to `[f16]`, `[bf16]` and slices of the other 16-bit formats to support conversion,
+/// reinterpret and reduction operations
 ///
 /// This trait is sealed and cannot be implemented outside of this crate.
 pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
+    /// The element type of the slice
+    type Element;
+
     /// Reinterprets a slice of [`f16`] or [`bf16`] numbers as a slice of [`u16`] bits
     ///
     /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
@@ -117,6 +131,291 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     /// ```
     fn convert_from_f64_slice(&mut self, src: &[f64]);
 
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`
+    /// using the given rounding mode
+    ///
+    /// The length of `src` must be the same as `self`. Each element is rounded exactly as
+    /// [`f16::from_f32_round`] or [`bf16::from_f32_round`] would.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut lower = [f16::ZERO; 2];
+    /// let mut upper = [f16::ZERO; 2];
+    /// let float_values = [0.1, -0.1];
+    ///
+    /// lower.convert_from_f32_slice_round(&float_values, RoundingMode::TowardNegative);
+    /// upper.convert_from_f32_slice_round(&float_values, RoundingMode::TowardPositive);
+    ///
+    /// for i in 0..2 {
+    ///     assert!(lower[i].to_f32() <= float_values[i] && float_values[i] <= upper[i].to_f32());
+    /// }
+    /// ```
+    fn convert_from_f32_slice_round(&mut self, src: &[f32], mode: RoundingMode);
+
+    /// Converts all of the elements of a `[f64]` slice into [`f16`] or [`bf16`] values in `self`
+    /// using the given rounding mode
+    ///
+    /// The length of `src` must be the same as `self`. Each element is rounded exactly as
+    /// [`f16::from_f64_round`] or [`bf16::from_f64_round`] would.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ZERO; 2];
+    /// let float_values = [1.0 + f64::EPSILON, -1.0 - f64::EPSILON];
+    ///
+    /// buffer.convert_from_f64_slice_round(&float_values, RoundingMode::TowardZero);
+    ///
+    /// assert_eq!(buffer, [bf16::ONE, -bf16::ONE]);
+    /// ```
+    fn convert_from_f64_slice_round(&mut self, src: &[f64], mode: RoundingMode);
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`
+    /// using stochastic rounding
+    ///
+    /// The length of `src` must be the same as `self`. `random` is called once for every element,
+    /// in order, and each element is rounded exactly as [`f16::from_f32_stochastic`] or
+    /// [`bf16::from_f32_stochastic`] would with the returned value. Seeding the random source
+    /// identically therefore reproduces the same results.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // A small xorshift generator; any source of uniform random u32 values works
+    /// fn xorshift(seed: u32) -> impl FnMut() -> u32 {
+    ///     let mut state = seed;
+    ///     move || {
+    ///         state ^= state << 13;
+    ///         state ^= state >> 17;
+    ///         state ^= state << 5;
+    ///         state
+    ///     }
+    /// }
+    ///
+    /// let float_values = [1.001f32; 64];
+    /// let mut a = [bf16::ZERO; 64];
+    /// let mut b = [bf16::ZERO; 64];
+    ///
+    /// a.convert_from_f32_slice_stochastic(&float_values, xorshift(42));
+    /// b.convert_from_f32_slice_stochastic(&float_values, xorshift(42));
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    fn convert_from_f32_slice_stochastic<R>(&mut self, src: &[f32], random: R)
+    where
+        R: FnMut() -> u32;
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// clamping values that are out of range instead of overflowing to ±∞
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::from_f32_saturating`] or [`bf16::from_f32_saturating`] would. The conversion is
+    /// vectorized like [`convert_from_f32_slice`][Self::convert_from_f32_slice].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ZERO; 4];
+    /// let float_values = [1e6, -1e6, f32::INFINITY, f32::NAN];
+    /// let saturation = Saturation { clamp_infinity: true, nan_to_zero: true };
+    ///
+    /// buffer.convert_from_f32_slice_saturating(&float_values, saturation);
+    ///
+    /// assert_eq!(buffer, [f16::MAX, f16::MIN, f16::MAX, f16::ZERO]);
+    /// ```
+    fn convert_from_f32_slice_saturating(&mut self, src: &[f32], saturation: Saturation);
+
+    /// Converts all of the elements of a `[f64]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// clamping values that are out of range instead of overflowing to ±∞
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::from_f64_saturating`] or [`bf16::from_f64_saturating`] would. The conversion is
+    /// vectorized like [`convert_from_f64_slice`][Self::convert_from_f64_slice].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ZERO; 3];
+    /// let float_values = [1e300, -1e300, f64::NEG_INFINITY];
+    ///
+    /// buffer.convert_from_f64_slice_saturating(&float_values, Saturation::default());
+    ///
+    /// assert_eq!(buffer, [bf16::MAX, bf16::MIN, bf16::NEG_INFINITY]);
+    /// ```
+    fn convert_from_f64_slice_saturating(&mut self, src: &[f64], saturation: Saturation);
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// flushing subnormal inputs and results to zero
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::from_f32_ftz`] or [`bf16::from_f32_ftz`] would. The conversion is vectorized like
+    /// [`convert_from_f32_slice`][Self::convert_from_f32_slice].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ONE; 3];
+    /// let float_values = [1e-7, -1e-7, 2.0];
+    ///
+    /// buffer.convert_from_f32_slice_ftz(&float_values);
+    ///
+    /// assert_eq!(buffer[1].to_bits(), f16::NEG_ZERO.to_bits());
+    /// assert_eq!(buffer, [f16::ZERO, f16::ZERO, f16::from_f32(2.0)]);
+    /// ```
+    fn convert_from_f32_slice_ftz(&mut self, src: &[f32]);
+
+    /// Converts all of the elements of a `[f64]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// flushing subnormal inputs and results to zero
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::from_f64_ftz`] or [`bf16::from_f64_ftz`] would. The conversion is vectorized like
+    /// [`convert_from_f64_slice`][Self::convert_from_f64_slice].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ONE; 2];
+    /// let float_values = [1e-40, 0.5];
+    ///
+    /// buffer.convert_from_f64_slice_ftz(&float_values);
+    ///
+    /// assert_eq!(buffer, [bf16::ZERO, bf16::from_f64(0.5)]);
+    /// ```
+    fn convert_from_f64_slice_ftz(&mut self, src: &[f64]);
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// returning the IEEE exceptions raised by any element
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::from_f32_with_status`] or [`bf16::from_f32_with_status`] would, and the returned
+    /// [`Status`] is the union of the flags of every element.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ZERO; 3];
+    /// let float_values = [1.0, 0.1, 1e6];
+    ///
+    /// let status = buffer.convert_from_f32_slice_with_status(&float_values);
+    ///
+    /// assert_eq!(buffer, [f16::ONE, f16::from_f32(0.1), f16::INFINITY]);
+    /// assert!(status.inexact && status.overflow && !status.invalid);
+    /// ```
+    fn convert_from_f32_slice_with_status(&mut self, src: &[f32]) -> Status;
+
+    /// Converts all of the elements of a `[f64]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// returning the IEEE exceptions raised by any element
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::from_f64_with_status`] or [`bf16::from_f64_with_status`] would, and the returned
+    /// [`Status`] is the union of the flags of every element.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ZERO; 2];
+    /// let float_values = [0.5, -2.0];
+    ///
+    /// let status = buffer.convert_from_f64_slice_with_status(&float_values);
+    ///
+    /// assert_eq!(buffer, [bf16::from_f64(0.5), bf16::from_f64(-2.0)]);
+    /// assert!(status.is_empty());
+    /// ```
+    fn convert_from_f64_slice_with_status(&mut self, src: &[f64]) -> Status;
+
+    /// Converts all of the elements of a `[f16]` slice into [`f16`] or [`bf16`] values in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Each element is rounded exactly as
+    /// [`f16::to_bf16`] would when converting to [`bf16`], and is copied unchanged when `self` is
+    /// also `[f16]`. Every [`f16`] value is within the range of [`bf16`], so nothing overflows,
+    /// but the fraction is rounded from 10 bits to 7.
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
+    /// efficient than converting individual elements on some hardware that supports SIMD
+    /// conversions. See [crate documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ZERO; 3];
+    /// let half_values = [f16::from_f32(1.5), f16::from_f32(0.1), f16::MAX];
+    ///
+    /// buffer.convert_from_f16_slice(&half_values);
+    ///
+    /// assert_eq!(buffer, [bf16::from_f32(1.5), bf16::from_f32(0.1), bf16::from_f32(65536.)]);
+    /// ```
+    fn convert_from_f16_slice(&mut self, src: &[f16]);
+
+    /// Converts all of the elements of a `[bf16]` slice into [`f16`] or [`bf16`] values in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Each element is rounded exactly as
+    /// [`bf16::to_f16`] would when converting to [`f16`], and is copied unchanged when `self` is
+    /// also `[bf16]`. Values too large for [`f16`] round to ±∞, and values too small become
+    /// [`f16`] subnormals or zero.
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
+    /// efficient than converting individual elements on some hardware that supports SIMD
+    /// conversions. See [crate documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ZERO; 3];
+    /// let bfloat_values = [bf16::from_f32(1.5), bf16::from_f32(-3.0), bf16::from_f32(1e5)];
+    ///
+    /// buffer.convert_from_bf16_slice(&bfloat_values);
+    ///
+    /// assert_eq!(buffer, [f16::from_f32(1.5), f16::from_f32(-3.0), f16::INFINITY]);
+    /// ```
+    fn convert_from_bf16_slice(&mut self, src: &[bf16]);
+
     /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in `dst`
     ///
     /// The length of `src` must be the same as `self`.
@@ -173,128 +472,840 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     /// ```
     fn convert_to_f64_slice(&self, dst: &mut [f64]);
 
-    // Because trait is sealed, we can get away with different interfaces between features
-
-    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in a new
-    /// vector
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in `dst`,
+    /// treating subnormal values as zero
     ///
-    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
-    /// efficient than converting individual elements on some hardware that supports SIMD
-    /// conversions. See [crate documentation](crate) for more information on hardware conversion
-    /// support.
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::to_f32_daz`] or [`bf16::to_f32_daz`] would. The conversion is vectorized like
+    /// [`convert_to_f32_slice`][Self::convert_to_f32_slice].
     ///
-    /// This method is only available with the `std` or `alloc` feature.
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
     /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let half_values = [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.), f16::from_f32(4.)];
-    /// let vec = half_values.to_f32_vec();
+    /// let mut buffer = [1f32; 3];
+    /// let half_values = [f16::MIN_POSITIVE_SUBNORMAL, -f16::MAX_SUBNORMAL, f16::ONE];
     ///
-    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// half_values.convert_to_f32_slice_daz(&mut buffer);
+    ///
+    /// assert_eq!(buffer[1].to_bits(), (-0f32).to_bits());
+    /// assert_eq!(buffer, [0., 0., 1.]);
     /// ```
-    #[cfg(any(feature = "alloc", feature = "std"))]
-    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    fn to_f32_vec(&self) -> Vec<f32>;
+    fn convert_to_f32_slice_daz(&self, dst: &mut [f32]);
 
-    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f64`] values in a new
-    /// vector
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f64`] values in `dst`,
+    /// treating subnormal values as zero
     ///
-    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
-    /// efficient than converting individual elements on some hardware that supports SIMD
-    /// conversions. See [crate documentation](crate) for more information on hardware conversion
-    /// support.
+    /// The length of `src` must be the same as `self`. Each element is converted exactly as
+    /// [`f16::to_f64_daz`] or [`bf16::to_f64_daz`] would. The conversion is vectorized like
+    /// [`convert_to_f64_slice`][Self::convert_to_f64_slice].
     ///
-    /// This method is only available with the `std` or `alloc` feature.
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
     /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let half_values = [f16::from_f64(1.), f16::from_f64(2.), f16::from_f64(3.), f16::from_f64(4.)];
-    /// let vec = half_values.to_f64_vec();
+    /// let mut buffer = [1f64; 2];
+    /// let half_values = [bf16::MAX_SUBNORMAL, bf16::MIN_POSITIVE];
     ///
-    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// half_values.convert_to_f64_slice_daz(&mut buffer);
+    ///
+    /// assert_eq!(buffer, [0., bf16::MIN_POSITIVE.to_f64()]);
     /// ```
-    #[cfg(any(feature = "alloc", feature = "std"))]
-    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    fn to_f64_vec(&self) -> Vec<f64>;
-}
+    fn convert_to_f64_slice_daz(&self, dst: &mut [f64]);
 
-/// Extensions to `[u16]` slices to support reinterpret operations
-///
-/// This trait is sealed and cannot be implemented outside of this crate.
-pub trait HalfBitsSliceExt: private::SealedHalfBitsSlice {
-    /// Reinterprets a slice of [`u16`] bits as a slice of [`f16`] or [`bf16`] numbers
+    /// Returns the least element of the slice, propagating NaN, or `None` if the slice is empty
     ///
-    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
-    ///
-    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
-    /// location as `self`.
+    /// This reduces the slice with the IEEE 754-2019 `minimum` operation, as
+    /// [`f16::minimum`] does: the result is a quiet NaN if any element is NaN, and `-0.0` is
+    /// considered less than `+0.0`.
     ///
     /// # Examples
-    ///
     /// ```rust
     /// # use half::prelude::*;
-    /// let int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
-    /// let float_buffer: &[f16] = int_buffer.reinterpret_cast();
-    ///
-    /// assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    /// let values = [f16::from_f32(2.), f16::ZERO, f16::NEG_ZERO, f16::from_f32(3.)];
     ///
-    /// // You may have to specify the cast type directly if the compiler can't infer the type.
-    /// // The following is also valid in Rust.
-    /// let typed_buffer = int_buffer.reinterpret_cast::<f16>();
+    /// assert_eq!(values.minimum().unwrap().to_bits(), f16::NEG_ZERO.to_bits());
+    /// assert!([f16::ONE, f16::NAN].minimum().unwrap().is_nan());
+    /// assert_eq!(<[f16]>::minimum(&[]), None);
     /// ```
-    fn reinterpret_cast<H>(&self) -> &[H]
-    where
-        H: crate::private::SealedHalf;
+    fn minimum(&self) -> Option<Self::Element>;
 
-    /// Reinterprets a mutable slice of [`u16`] bits as a mutable slice of [`f16`] or [`bf16`]
-    /// numbers
+    /// Returns the greatest element of the slice, propagating NaN, or `None` if the slice is empty
     ///
-    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    /// This reduces the slice with the IEEE 754-2019 `maximum` operation, as
+    /// [`f16::maximum`] does: the result is a quiet NaN if any element is NaN, and `+0.0` is
+    /// considered greater than `-0.0`.
     ///
-    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
-    /// which prevents mutating `self` as long as the returned `&mut [f16]` is borrowed.
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [bf16::from_f32(2.), bf16::NEG_ZERO, bf16::from_f32(3.)];
+    ///
+    /// assert_eq!(values.maximum(), Some(bf16::from_f32(3.)));
+    /// assert!([bf16::NAN, bf16::ONE].maximum().unwrap().is_nan());
+    /// ```
+    fn maximum(&self) -> Option<Self::Element>;
+
+    /// Returns the least element of the slice ignoring NaN, or `None` if the slice is empty
+    ///
+    /// This reduces the slice with the IEEE 754-2019 `minimumNumber` operation, as
+    /// [`f16::minimum_number`] does: NaN elements are skipped, and the result is only NaN if every
+    /// element is NaN. `-0.0` is considered less than `+0.0`.
     ///
     /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [f16::NAN, f16::from_f32(2.), f16::from_f32(-1.)];
+    ///
+    /// assert_eq!(values.minimum_number(), Some(f16::from_f32(-1.)));
+    /// assert!([f16::NAN, f16::NAN].minimum_number().unwrap().is_nan());
+    /// ```
+    fn minimum_number(&self) -> Option<Self::Element>;
+
+    /// Returns the greatest element of the slice ignoring NaN, or `None` if the slice is empty
     ///
+    /// This reduces the slice with the IEEE 754-2019 `maximumNumber` operation, as
+    /// [`f16::maximum_number`] does: NaN elements are skipped, and the result is only NaN if every
+    /// element is NaN. `+0.0` is considered greater than `-0.0`.
+    ///
+    /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let mut int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    /// let values = [bf16::from_f32(2.), bf16::NAN, bf16::from_f32(-1.)];
     ///
-    /// {
-    ///     let float_buffer: &mut [f16] = int_buffer.reinterpret_cast_mut();
+    /// assert_eq!(values.maximum_number(), Some(bf16::from_f32(2.)));
+    /// ```
+    fn maximum_number(&self) -> Option<Self::Element>;
+
+    /// Replaces every NaN in the slice with the positive quiet NaN without payload
     ///
-    ///     assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    /// After this pass every NaN has the same bit pattern, as [`f16::NAN`] and [`bf16::NAN`] do,
+    /// so the slice can be hashed, compared or deduplicated bitwise. All other values are left
+    /// unchanged.
     ///
-    ///     // Mutating the f16 slice will mutating the original
-    ///     float_buffer[0] = f16::from_f32(0.);
-    /// }
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ONE, -f16::nan_with_payload(7), f16::from_bits(0x7C01)];
     ///
-    /// // Note that we need to drop float_buffer before using int_buffer again or we will get a borrow error.
-    /// assert_eq!(int_buffer, [f16::from_f32(0.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()]);
+    /// buffer.canonicalize_nans();
     ///
-    /// // You may have to specify the cast type directly if the compiler can't infer the type.
-    /// // The following is also valid in Rust.
-    /// let typed_buffer = int_buffer.reinterpret_cast_mut::<f16>();
+    /// assert_eq!(buffer.reinterpret_cast(), [0x3C00, 0x7E00, 0x7E00]);
     /// ```
-    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
+    fn canonicalize_nans(&mut self);
+
+    /// Returns the largest ULP distance between the elements of `self` and the corresponding
+    /// `expected` values rounded to the element type
+    ///
+    /// Each distance is computed as by [`f16::ulp_distance`], so a result of 0 means every element
+    /// is the correctly rounded expected value, and [`u32::MAX`] means an element is NaN where the
+    /// expected value is not, or the reverse. An empty slice gives 0.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let expected = [1.0f32, 0.1, -3.0];
+    /// let mut actual = [f16::ZERO; 3];
+    /// actual.convert_from_f32_slice(&expected);
+    ///
+    /// assert_eq!(actual.max_ulp_error(&expected), 0);
+    ///
+    /// actual[1] = actual[1].next_up().next_up();
+    /// assert_eq!(actual.max_ulp_error(&expected), 2);
+    /// ```
+    fn max_ulp_error(&self, expected: &[f32]) -> u32;
+
+    /// Sorts the slice in the IEEE 754 total order
+    ///
+    /// The order is the one of [`f16::total_cmp`]: negative NaNs, −∞, negative numbers, `-0.0`,
+    /// `+0.0`, positive numbers, +∞ and positive NaNs. The sort is done in place with a radix sort
+    /// over the 16-bit representation, which takes linear time and does not allocate.
+    ///
+    /// Elements are only equal in the total order if they have the same bits, so this sort is
+    /// stable and gives the same result as [`sort_unstable_total`][Self::sort_unstable_total].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut values = [f16::from_f32(2.), f16::NAN, f16::ZERO, f16::NEG_ZERO, -f16::ONE];
+    ///
+    /// values.sort_total();
+    ///
+    /// assert_eq!(values.reinterpret_cast(), [0xBC00, 0x8000, 0x0000, 0x4000, 0x7E00]);
+    /// ```
+    fn sort_total(&mut self);
+
+    /// Sorts the slice in the IEEE 754 total order, without preserving the order of equal elements
+    ///
+    /// Equal elements in the total order are indistinguishable, so this is the same as
+    /// [`sort_total`][Self::sort_total], and is provided to mirror the standard library slice
+    /// methods.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut values = [bf16::INFINITY, bf16::from_f32(-3.), bf16::ONE];
+    ///
+    /// values.sort_unstable_total();
+    ///
+    /// assert_eq!(values, [bf16::from_f32(-3.), bf16::ONE, bf16::INFINITY]);
+    /// ```
+    fn sort_unstable_total(&mut self);
+
+    /// Binary searches a slice sorted in the IEEE 754 total order for `x`
+    ///
+    /// The slice should be sorted as by [`sort_total`][Self::sort_total]. If `x` is found then
+    /// [`Result::Ok`] is returned with the index of the matching element, otherwise
+    /// [`Result::Err`] is returned with the index where `x` could be inserted to keep the slice
+    /// sorted. Because the search uses the total order, NaN can be searched for, and `-0.0` and
+    /// `+0.0` are different values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [f16::NEG_ZERO, f16::ONE, f16::from_f32(4.), f16::NAN];
+    ///
+    /// assert_eq!(values.binary_search_total(&f16::ONE), Ok(1));
+    /// assert_eq!(values.binary_search_total(&f16::NAN), Ok(3));
+    /// assert_eq!(values.binary_search_total(&f16::ZERO), Err(1));
+    /// ```
+    fn binary_search_total(&self, x: &Self::Element) -> Result<usize, usize>;
+
+    // Because trait is sealed, we can get away with different interfaces between features
+
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in a new
+    /// vector
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
+    /// efficient than converting individual elements on some hardware that supports SIMD
+    /// conversions. See [crate documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let half_values = [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.), f16::from_f32(4.)];
+    /// let vec = half_values.to_f32_vec();
+    ///
+    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f32_vec(&self) -> Vec<f32>;
+
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f64`] values in a new
+    /// vector
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
+    /// efficient than converting individual elements on some hardware that supports SIMD
+    /// conversions. See [crate documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let half_values = [f16::from_f64(1.), f16::from_f64(2.), f16::from_f64(3.), f16::from_f64(4.)];
+    /// let vec = half_values.to_f64_vec();
+    ///
+    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f64_vec(&self) -> Vec<f64>;
+}
+
+/// Extensions to `[u16]` slices to support reinterpret operations
+///
+/// Besides [`f16`] and [`bf16`], the bits may be reinterpreted as any of the other 16-bit types
+/// of this crate, including [`Posit16`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfBitsSliceExt: private::SealedHalfBitsSlice {
+    /// Reinterprets a slice of [`u16`] bits as a slice of [`f16`] or [`bf16`] numbers
+    ///
+    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    /// let float_buffer: &[f16] = int_buffer.reinterpret_cast();
+    ///
+    /// assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    ///
+    /// // You may have to specify the cast type directly if the compiler can't infer the type.
+    /// // The following is also valid in Rust.
+    /// let typed_buffer = int_buffer.reinterpret_cast::<f16>();
+    /// ```
+    fn reinterpret_cast<H>(&self) -> &[H]
     where
         H: crate::private::SealedHalf;
+
+    /// Reinterprets a mutable slice of [`u16`] bits as a mutable slice of [`f16`] or [`bf16`]
+    /// numbers
+    ///
+    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    ///
+    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
+    /// which prevents mutating `self` as long as the returned `&mut [f16]` is borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    ///
+    /// {
+    ///     let float_buffer: &mut [f16] = int_buffer.reinterpret_cast_mut();
+    ///
+    ///     assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    ///
+    ///     // Mutating the f16 slice will mutating the original
+    ///     float_buffer[0] = f16::from_f32(0.);
+    /// }
+    ///
+    /// // Note that we need to drop float_buffer before using int_buffer again or we will get a borrow error.
+    /// assert_eq!(int_buffer, [f16::from_f32(0.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()]);
+    ///
+    /// // You may have to specify the cast type directly if the compiler can't infer the type.
+    /// // The following is also valid in Rust.
+    /// let typed_buffer = int_buffer.reinterpret_cast_mut::<f16>();
+    /// ```
+    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
+    where
+        H: crate::private::SealedHalf;
+}
+
+/// Extensions to slices of the 8-bit floating point types to support conversion and reinterpret
+/// operations
+///
+/// This is the counterpart of [`HalfFloatSliceExt`] for [`f8e4m3`], [`f8e5m2`], [`f8e4m3fnuz`]
+/// and [`f8e5m2fnuz`] slices.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Fp8FloatSliceExt: private::SealedFp8FloatSlice {
+    /// Reinterprets a slice of 8-bit floating point numbers as a slice of [`u8`] bits
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let float_buffer = [f8e4m3::from_f32(1.), f8e4m3::from_f32(2.)];
+    ///
+    /// assert_eq!(float_buffer.reinterpret_cast(), [0x38, 0x40]);
+    /// ```
+    fn reinterpret_cast(&self) -> &[u8];
+
+    /// Reinterprets a mutable slice of 8-bit floating point numbers as a mutable slice of [`u8`]
+    /// bits
+    ///
+    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
+    /// which prevents mutating `self` as long as the returned `&mut [u8]` is borrowed.
+    fn reinterpret_cast_mut(&mut self) -> &mut [u8];
+
+    /// Converts all of the elements of a `[f32]` slice into 8-bit floating point values in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted like
+    /// `from_f32`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f8e5m2::ZERO; 3];
+    /// buffer.convert_from_f32_slice(&[1., 0.3, 1e6]);
+    ///
+    /// assert_eq!(buffer, [f8e5m2::ONE, f8e5m2::from_f32(0.3), f8e5m2::INFINITY]);
+    /// ```
+    fn convert_from_f32_slice(&mut self, src: &[f32]);
+
+    /// Converts all of the elements of a `[f64]` slice into 8-bit floating point values in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted like
+    /// `from_f64`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_f64_slice(&mut self, src: &[f64]);
+
+    /// Converts all of the elements of a `[f32]` slice into 8-bit floating point values in
+    /// `self`, clamping values that are out of range
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted like
+    /// `from_f32_saturating`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let clamp = Saturation { clamp_infinity: true, nan_to_zero: false };
+    /// let mut buffer = [f8e4m3::ZERO; 3];
+    /// buffer.convert_from_f32_slice_saturating(&[1000., -f32::INFINITY, 1.], clamp);
+    ///
+    /// assert_eq!(buffer, [f8e4m3::MAX, f8e4m3::MIN, f8e4m3::ONE]);
+    /// ```
+    fn convert_from_f32_slice_saturating(&mut self, src: &[f32], saturation: Saturation);
+
+    /// Converts all of the elements of a `[f64]` slice into 8-bit floating point values in
+    /// `self`, clamping values that are out of range
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted like
+    /// `from_f64_saturating`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_f64_slice_saturating(&mut self, src: &[f64], saturation: Saturation);
+
+    /// Converts all of the 8-bit floating point elements of `self` into [`f32`] values in `dst`
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let src = [f8e4m3fnuz::from_f32(0.5), f8e4m3fnuz::MAX];
+    /// let mut buffer = [0f32; 2];
+    /// src.convert_to_f32_slice(&mut buffer);
+    ///
+    /// assert_eq!(buffer, [0.5, 240.]);
+    /// ```
+    fn convert_to_f32_slice(&self, dst: &mut [f32]);
+
+    /// Converts all of the 8-bit floating point elements of `self` into [`f64`] values in `dst`
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_to_f64_slice(&self, dst: &mut [f64]);
+
+    /// Converts all of the 8-bit floating point elements of `self` into [`f32`] values in a new
+    /// vector
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f32_vec(&self) -> Vec<f32>;
+
+    /// Converts all of the 8-bit floating point elements of `self` into [`f64`] values in a new
+    /// vector
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f64_vec(&self) -> Vec<f64>;
+}
+
+/// Extensions to `[u8]` slices to support reinterpret operations
+///
+/// The bits may also be reinterpreted as [`Posit8`] values.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Fp8BitsSliceExt: private::SealedFp8BitsSlice {
+    /// Reinterprets a slice of [`u8`] bits as a slice of 8-bit floating point numbers
+    ///
+    /// `F` is the type to cast to, and must be one of [`f8e4m3`], [`f8e5m2`], [`f8e4m3fnuz`] or
+    /// [`f8e5m2fnuz`].
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let int_buffer = [0x38u8, 0x40];
+    /// let float_buffer = int_buffer.reinterpret_cast::<f8e4m3>();
+    ///
+    /// assert_eq!(float_buffer, [f8e4m3::from_f32(1.), f8e4m3::from_f32(2.)]);
+    /// ```
+    fn reinterpret_cast<F>(&self) -> &[F]
+    where
+        F: crate::private::SealedFp8;
+
+    /// Reinterprets a mutable slice of [`u8`] bits as a mutable slice of 8-bit floating point
+    /// numbers
+    ///
+    /// `F` is the type to cast to, and must be one of [`f8e4m3`], [`f8e5m2`], [`f8e4m3fnuz`] or
+    /// [`f8e5m2fnuz`].
+    ///
+    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
+    /// which prevents mutating `self` as long as the returned slice is borrowed.
+    fn reinterpret_cast_mut<F>(&mut self) -> &mut [F]
+    where
+        F: crate::private::SealedFp8;
+}
+
+/// Extensions to `[f32]` to round the values to TensorFloat-32 in place
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Tf32SliceExt: private::SealedTf32Slice {
+    /// Rounds every element of `self` to the nearest [`tf32`] value using the given rounding mode
+    ///
+    /// The values stay [`f32`] values but have at most 11 significant bits afterwards, exactly as
+    /// tensor cores see their [`f32`] operands. This is the same as converting each element with
+    /// [`tf32::from_f32_round`] and back. The
+    /// [`NearestTiesToEven`][RoundingMode::NearestTiesToEven] and
+    /// [`TowardZero`][RoundingMode::TowardZero] modes are branch-free integer operations that the
+    /// compiler vectorizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [1.0f32 + f32::EPSILON, 0.1, f32::MAX];
+    ///
+    /// buffer.round_to_tf32(RoundingMode::NearestTiesToEven);
+    /// assert_eq!(buffer, [1.0, 0.099975586, f32::INFINITY]);
+    /// ```
+    fn round_to_tf32(&mut self, mode: RoundingMode);
+}
+
+/// Extensions to `[tf32]` to support conversion and reinterpret operations
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Tf32FloatSliceExt: private::SealedTf32FloatSlice {
+    /// Reinterprets a slice of [`tf32`] numbers as a slice of the equal [`f32`] numbers
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let buffer = [tf32::from_f32(1.), tf32::from_f32(0.1)];
+    ///
+    /// assert_eq!(buffer.reinterpret_cast(), [1.0, 0.099975586]);
+    /// ```
+    fn reinterpret_cast(&self) -> &[f32];
+
+    /// Converts all of the elements of a `[f32]` slice into [`tf32`] values in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Values are rounded to nearest, ties to
+    /// even, like [`tf32::from_f32`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_f32_slice(&mut self, src: &[f32]);
+
+    /// Converts all of the elements of a `[f32]` slice into [`tf32`] values in `self` using the
+    /// given rounding mode
+    ///
+    /// The length of `src` must be the same as `self`. Each element is converted like
+    /// [`tf32::from_f32_round`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_f32_slice_round(&mut self, src: &[f32], mode: RoundingMode);
+
+    /// Converts all of the [`tf32`] elements of `self` into [`f32`] values in `dst`
+    ///
+    /// The length of `src` must be the same as `self`. This conversion is lossless.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_to_f32_slice(&self, dst: &mut [f32]);
+
+    /// Converts all of the [`tf32`] elements of `self` into [`f32`] values in a new vector
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f32_vec(&self) -> Vec<f32>;
+}
+
+/// Extensions to slices of posits to support conversion and reinterpret operations
+///
+/// This is implemented for slices of [`Posit8`], [`Posit16`] and [`Posit32`] with any number of
+/// exponent bits, including the aliases [`p8`][crate::p8], [`p16`][crate::p16] and
+/// [`p32`][crate::p32].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait PositSliceExt: private::SealedPositSlice {
+    /// The unsigned integer type holding the bits of each posit
+    type Bits;
+
+    /// Reinterprets a slice of posits as a slice of their bits
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let buffer = [p16::ONE, p16::NAR];
+    ///
+    /// assert_eq!(buffer.reinterpret_cast(), [0x4000, 0x8000]);
+    /// ```
+    fn reinterpret_cast(&self) -> &[Self::Bits];
+
+    /// Reinterprets a mutable slice of posits as a mutable slice of their bits
+    ///
+    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
+    /// which prevents mutating `self` as long as the returned slice is borrowed.
+    fn reinterpret_cast_mut(&mut self) -> &mut [Self::Bits];
+
+    /// Converts all of the elements of a `[f32]` slice into posits in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Each element is rounded like
+    /// [`p16::from_f32`][crate::posit::Posit16::from_f32].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [p16::ZERO; 3];
+    /// buffer.convert_from_f32_slice(&[1.0, 1e20, f32::NAN]);
+    ///
+    /// assert_eq!(buffer[..2], [p16::ONE, p16::MAX]);
+    /// assert!(buffer[2].is_nar());
+    /// ```
+    fn convert_from_f32_slice(&mut self, src: &[f32]);
+
+    /// Converts all of the elements of a `[f64]` slice into posits in `self`
+    ///
+    /// The length of `src` must be the same as `self`. Each element is rounded like
+    /// [`p16::from_f64`][crate::posit::Posit16::from_f64].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_f64_slice(&mut self, src: &[f64]);
+
+    /// Converts all of the posits in `self` into [`f32`] values in `dst`
+    ///
+    /// The length of `src` must be the same as `self`. NaR becomes NaN, and [`Posit32`] values
+    /// with more fraction bits than [`f32`] are rounded to nearest.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_to_f32_slice(&self, dst: &mut [f32]);
+
+    /// Converts all of the posits in `self` into [`f64`] values in `dst`
+    ///
+    /// The length of `src` must be the same as `self`. This conversion is lossless, except that
+    /// NaR becomes NaN.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_to_f64_slice(&self, dst: &mut [f64]);
+
+    /// Converts all of the posits in `self` into [`f32`] values in a new vector
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f32_vec(&self) -> Vec<f32>;
+
+    /// Converts all of the posits in `self` into [`f64`] values in a new vector
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_f64_vec(&self) -> Vec<f64>;
 }
 
 mod private {
-    use crate::{bf16, f16};
+    use crate::{
+        bf16, dlfloat16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz,
+        posit::{Posit16, Posit32, Posit8},
+        tf32,
+    };
 
     pub trait SealedHalfFloatSlice {}
     impl SealedHalfFloatSlice for [f16] {}
     impl SealedHalfFloatSlice for [bf16] {}
+    impl SealedHalfFloatSlice for [f16ahp] {}
+    impl SealedHalfFloatSlice for [dlfloat16] {}
 
     pub trait SealedHalfBitsSlice {}
     impl SealedHalfBitsSlice for [u16] {}
+
+    pub trait SealedFp8FloatSlice {}
+    impl SealedFp8FloatSlice for [f8e4m3] {}
+    impl SealedFp8FloatSlice for [f8e5m2] {}
+    impl SealedFp8FloatSlice for [f8e4m3fnuz] {}
+    impl SealedFp8FloatSlice for [f8e5m2fnuz] {}
+
+    pub trait SealedFp8BitsSlice {}
+    impl SealedFp8BitsSlice for [u8] {}
+
+    pub trait SealedTf32Slice {}
+    impl SealedTf32Slice for [f32] {}
+
+    pub trait SealedTf32FloatSlice {}
+    impl SealedTf32FloatSlice for [tf32] {}
+
+    pub trait SealedPositSlice {}
+    impl<const ES: u32> SealedPositSlice for [Posit8<ES>] {}
+    impl<const ES: u32> SealedPositSlice for [Posit16<ES>] {}
+    impl<const ES: u32> SealedPositSlice for [Posit32<ES>] {}
+}
+
+/// Reduces a slice of 16-bit values with one of the binary operations of [`softfloat`]
+#[inline]
+fn reduce(
+    fmt: softfloat::Format,
+    bits: &[u16],
+    op: fn(softfloat::Format, u32, u32) -> u32,
+) -> Option<u16> {
+    bits.iter()
+        .map(|&x| x as u32)
+        .reduce(|a, b| op(fmt, a, b))
+        .map(|x| x as u16)
+}
+
+/// Replaces every NaN in a slice of 16-bit values with the canonical NaN of `fmt`
+fn canonicalize_nans(fmt: softfloat::Format, bits: &mut [u16]) {
+    let nan = softfloat::special(fmt, false, true, &mut Status::default()) as u16;
+    for x in bits.iter_mut() {
+        if fmt.is_nan(*x as u32) {
+            *x = nan;
+        }
+    }
+}
+
+/// Returns the largest ULP distance between the 16-bit values of `fmt` in `bits` and `expected`
+fn max_ulp_error(fmt: softfloat::Format, bits: &[u16], expected: impl Iterator<Item = u16>) -> u32 {
+    bits.iter()
+        .zip(expected)
+        .map(|(&a, b)| softfloat::ulp_distance(fmt, a as u32, b as u32))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Sorts a slice of 16-bit sign-magnitude values in the IEEE 754 total order
+fn sort_total(bits: &mut [u16]) {
+    // Map each value to an unsigned key that sorts in the total order, which is its own inverse
+    // after flipping the sign bit back
+    #[inline]
+    fn key(x: u16) -> u16 {
+        if x & 0x8000 != 0 {
+            !x
+        } else {
+            x | 0x8000
+        }
+    }
+    #[inline]
+    fn unkey(k: u16) -> u16 {
+        if k & 0x8000 != 0 {
+            k & 0x7FFF
+        } else {
+            !k
+        }
+    }
+
+    if bits.len() < 64 {
+        bits.sort_unstable_by_key(|&x| key(x));
+        return;
+    }
+    for x in bits.iter_mut() {
+        *x = key(*x);
+    }
+
+    // Partition in place by the high byte of the key, as in an American flag sort
+    let mut counts = [0usize; 256];
+    for &k in bits.iter() {
+        counts[(k >> 8) as usize] += 1;
+    }
+    let mut next = [0usize; 256];
+    let mut end = [0usize; 256];
+    let mut start = 0;
+    for (digit, &count) in counts.iter().enumerate() {
+        next[digit] = start;
+        start += count;
+        end[digit] = start;
+    }
+    for digit in 0..256 {
+        while next[digit] < end[digit] {
+            let other = (bits[next[digit]] >> 8) as usize;
+            if other == digit {
+                next[digit] += 1;
+            } else {
+                bits.swap(next[digit], next[other]);
+                next[other] += 1;
+            }
+        }
+    }
+
+    // Equal keys are identical values, so each bucket is sorted by counting its low bytes and
+    // writing them back out in order
+    let mut start = 0;
+    for (high, &count) in counts.iter().enumerate() {
+        let bucket = &mut bits[start..start + count];
+        start += count;
+        let mut low_counts = [0usize; 256];
+        for &k in bucket.iter() {
+            low_counts[(k & 0xFF) as usize] += 1;
+        }
+        let mut i = 0;
+        for (low, &n) in low_counts.iter().enumerate() {
+            let value = unkey(((high as u16) << 8) | low as u16);
+            for x in bucket[i..i + n].iter_mut() {
+                *x = value;
+            }
+            i += n;
+        }
+    }
+}
+
+/// Binary searches a slice of 16-bit values of `fmt` sorted in the IEEE 754 total order
+fn binary_search_total(fmt: softfloat::Format, bits: &[u16], x: u16) -> Result<usize, usize> {
+    let key = softfloat::total_order_key(fmt, x as u32);
+    bits.binary_search_by_key(&key, |&b| softfloat::total_order_key(fmt, b as u32))
 }
 
 impl HalfFloatSliceExt for [f16] {
+    type Element = f16;
+
     #[inline]
     fn reinterpret_cast(&self) -> &[u16] {
         let pointer = self.as_ptr() as *const u16;
@@ -367,6 +1378,151 @@ impl HalfFloatSliceExt for [f16] {
         }
     }
 
+    fn convert_from_f32_slice_round(&mut self, src: &[f32], mode: RoundingMode) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = f16::from_f32_round(*f, mode);
+        }
+    }
+
+    fn convert_from_f64_slice_round(&mut self, src: &[f64], mode: RoundingMode) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = f16::from_f64_round(*f, mode);
+        }
+    }
+
+    fn convert_from_f32_slice_stochastic<R>(&mut self, src: &[f32], mut random: R)
+    where
+        R: FnMut() -> u32,
+    {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = f16::from_f32_stochastic(*f, random());
+        }
+    }
+
+    fn convert_from_f32_slice_saturating(&mut self, src: &[f32], saturation: Saturation) {
+        self.convert_from_f32_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            let bits = saturation.apply(softfloat::F16, *f as f64, dst.to_bits() as u32);
+            *dst = f16::from_bits(bits as u16);
+        }
+    }
+
+    fn convert_from_f64_slice_saturating(&mut self, src: &[f64], saturation: Saturation) {
+        self.convert_from_f64_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            let bits = saturation.apply(softfloat::F16, *f, dst.to_bits() as u32);
+            *dst = f16::from_bits(bits as u16);
+        }
+    }
+
+    fn convert_from_f32_slice_ftz(&mut self, src: &[f32]) {
+        self.convert_from_f32_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = if f.classify() == FpCategory::Subnormal {
+                f16::from_bits((f.to_bits() >> 16) as u16 & 0x8000)
+            } else {
+                dst.flush_subnormal()
+            };
+        }
+    }
+
+    fn convert_from_f64_slice_ftz(&mut self, src: &[f64]) {
+        self.convert_from_f64_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = if f.classify() == FpCategory::Subnormal {
+                f16::from_bits((f.to_bits() >> 48) as u16 & 0x8000)
+            } else {
+                dst.flush_subnormal()
+            };
+        }
+    }
+
+    fn convert_from_f32_slice_with_status(&mut self, src: &[f32]) -> Status {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut status = Status::default();
+        for (dst, f) in self.iter_mut().zip(src) {
+            let (x, s) = f16::from_f32_with_status(*f);
+            *dst = x;
+            status |= s;
+        }
+        status
+    }
+
+    fn convert_from_f64_slice_with_status(&mut self, src: &[f64]) -> Status {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut status = Status::default();
+        for (dst, f) in self.iter_mut().zip(src) {
+            let (x, s) = f16::from_f64_with_status(*f);
+            *dst = x;
+            status |= s;
+        }
+        status
+    }
+
+    fn convert_from_f16_slice(&mut self, src: &[f16]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+        self.copy_from_slice(src);
+    }
+
+    fn convert_from_bf16_slice(&mut self, src: &[bf16]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut chunks = src.reinterpret_cast().chunks_exact(4);
+        let mut chunk_count = 0usize; // Not using .enumerate() because we need this value for remainder
+        for chunk in &mut chunks {
+            let vec = crate::bfloat::convert::bf16x4_to_f16x4(chunk);
+            let dst_idx = chunk_count * 4;
+            self[dst_idx..dst_idx + 4].copy_from_slice(vec.reinterpret_cast());
+            chunk_count += 1;
+        }
+
+        // Process remainder
+        if !chunks.remainder().is_empty() {
+            let mut buf = [0u16; 4];
+            buf[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+            let vec = crate::bfloat::convert::bf16x4_to_f16x4(&buf);
+            let dst_idx = chunk_count * 4;
+            self[dst_idx..dst_idx + chunks.remainder().len()]
+                .copy_from_slice(vec[..chunks.remainder().len()].reinterpret_cast());
+        }
+    }
+
     fn convert_to_f32_slice(&self, dst: &mut [f32]) {
         assert_eq!(
             self.len(),
@@ -421,6 +1577,85 @@ impl HalfFloatSliceExt for [f16] {
         }
     }
 
+    fn convert_to_f32_slice_daz(&self, dst: &mut [f32]) {
+        self.convert_to_f32_slice(dst);
+        for (dst, h) in dst.iter_mut().zip(self.iter()) {
+            if h.classify() == FpCategory::Subnormal {
+                *dst = h.to_f32_daz();
+            }
+        }
+    }
+
+    fn convert_to_f64_slice_daz(&self, dst: &mut [f64]) {
+        self.convert_to_f64_slice(dst);
+        for (dst, h) in dst.iter_mut().zip(self.iter()) {
+            if h.classify() == FpCategory::Subnormal {
+                *dst = h.to_f64_daz();
+            }
+        }
+    }
+
+    #[inline]
+    fn minimum(&self) -> Option<f16> {
+        reduce(softfloat::F16, self.reinterpret_cast(), softfloat::minimum).map(f16::from_bits)
+    }
+
+    #[inline]
+    fn maximum(&self) -> Option<f16> {
+        reduce(softfloat::F16, self.reinterpret_cast(), softfloat::maximum).map(f16::from_bits)
+    }
+
+    #[inline]
+    fn minimum_number(&self) -> Option<f16> {
+        reduce(
+            softfloat::F16,
+            self.reinterpret_cast(),
+            softfloat::minimum_number,
+        )
+        .map(f16::from_bits)
+    }
+
+    #[inline]
+    fn maximum_number(&self) -> Option<f16> {
+        reduce(
+            softfloat::F16,
+            self.reinterpret_cast(),
+            softfloat::maximum_number,
+        )
+        .map(f16::from_bits)
+    }
+
+    #[inline]
+    fn canonicalize_nans(&mut self) {
+        canonicalize_nans(softfloat::F16, self.reinterpret_cast_mut());
+    }
+
+    #[inline]
+    fn max_ulp_error(&self, expected: &[f32]) -> u32 {
+        assert_eq!(
+            self.len(),
+            expected.len(),
+            "actual and expected slices have different lengths"
+        );
+        let expected = expected.iter().map(|&x| f16::from_f32(x).to_bits());
+        max_ulp_error(softfloat::F16, self.reinterpret_cast(), expected)
+    }
+
+    #[inline]
+    fn sort_total(&mut self) {
+        sort_total(self.reinterpret_cast_mut());
+    }
+
+    #[inline]
+    fn sort_unstable_total(&mut self) {
+        sort_total(self.reinterpret_cast_mut());
+    }
+
+    #[inline]
+    fn binary_search_total(&self, x: &f16) -> Result<usize, usize> {
+        binary_search_total(softfloat::F16, self.reinterpret_cast(), x.to_bits())
+    }
+
     #[cfg(any(feature = "alloc", feature = "std"))]
     #[inline]
     fn to_f32_vec(&self) -> Vec<f32> {
@@ -447,6 +1682,8 @@ impl HalfFloatSliceExt for [f16] {
 }
 
 impl HalfFloatSliceExt for [bf16] {
+    type Element = bf16;
+
     #[inline]
     fn reinterpret_cast(&self) -> &[u16] {
         let pointer = self.as_ptr() as *const u16;
@@ -472,23 +1709,168 @@ impl HalfFloatSliceExt for [bf16] {
             "destination and source slices have different lengths"
         );
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in src.iter().enumerate() {
-            self[i] = bf16::from_f32(*f);
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in src.iter().enumerate() {
+            self[i] = bf16::from_f32(*f);
+        }
+    }
+
+    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in src.iter().enumerate() {
+            self[i] = bf16::from_f64(*f);
+        }
+    }
+
+    fn convert_from_f32_slice_round(&mut self, src: &[f32], mode: RoundingMode) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = bf16::from_f32_round(*f, mode);
+        }
+    }
+
+    fn convert_from_f64_slice_round(&mut self, src: &[f64], mode: RoundingMode) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = bf16::from_f64_round(*f, mode);
+        }
+    }
+
+    fn convert_from_f32_slice_stochastic<R>(&mut self, src: &[f32], mut random: R)
+    where
+        R: FnMut() -> u32,
+    {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = bf16::from_f32_stochastic(*f, random());
+        }
+    }
+
+    fn convert_from_f32_slice_saturating(&mut self, src: &[f32], saturation: Saturation) {
+        self.convert_from_f32_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            let bits = saturation.apply(softfloat::BF16, *f as f64, dst.to_bits() as u32);
+            *dst = bf16::from_bits(bits as u16);
+        }
+    }
+
+    fn convert_from_f64_slice_saturating(&mut self, src: &[f64], saturation: Saturation) {
+        self.convert_from_f64_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            let bits = saturation.apply(softfloat::BF16, *f, dst.to_bits() as u32);
+            *dst = bf16::from_bits(bits as u16);
+        }
+    }
+
+    fn convert_from_f32_slice_ftz(&mut self, src: &[f32]) {
+        self.convert_from_f32_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = if f.classify() == FpCategory::Subnormal {
+                bf16::from_bits((f.to_bits() >> 16) as u16 & 0x8000)
+            } else {
+                dst.flush_subnormal()
+            };
+        }
+    }
+
+    fn convert_from_f64_slice_ftz(&mut self, src: &[f64]) {
+        self.convert_from_f64_slice(src);
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = if f.classify() == FpCategory::Subnormal {
+                bf16::from_bits((f.to_bits() >> 48) as u16 & 0x8000)
+            } else {
+                dst.flush_subnormal()
+            };
+        }
+    }
+
+    fn convert_from_f32_slice_with_status(&mut self, src: &[f32]) -> Status {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut status = Status::default();
+        for (dst, f) in self.iter_mut().zip(src) {
+            let (x, s) = bf16::from_f32_with_status(*f);
+            *dst = x;
+            status |= s;
+        }
+        status
+    }
+
+    fn convert_from_f64_slice_with_status(&mut self, src: &[f64]) -> Status {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut status = Status::default();
+        for (dst, f) in self.iter_mut().zip(src) {
+            let (x, s) = bf16::from_f64_with_status(*f);
+            *dst = x;
+            status |= s;
+        }
+        status
+    }
+
+    fn convert_from_f16_slice(&mut self, src: &[f16]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut chunks = src.reinterpret_cast().chunks_exact(4);
+        let mut chunk_count = 0usize; // Not using .enumerate() because we need this value for remainder
+        for chunk in &mut chunks {
+            let vec = crate::bfloat::convert::f16x4_to_bf16x4(chunk);
+            let dst_idx = chunk_count * 4;
+            self[dst_idx..dst_idx + 4].copy_from_slice(vec.reinterpret_cast());
+            chunk_count += 1;
+        }
+
+        // Process remainder
+        if !chunks.remainder().is_empty() {
+            let mut buf = [0u16; 4];
+            buf[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+            let vec = crate::bfloat::convert::f16x4_to_bf16x4(&buf);
+            let dst_idx = chunk_count * 4;
+            self[dst_idx..dst_idx + chunks.remainder().len()]
+                .copy_from_slice(vec[..chunks.remainder().len()].reinterpret_cast());
         }
     }
 
-    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+    fn convert_from_bf16_slice(&mut self, src: &[bf16]) {
         assert_eq!(
             self.len(),
             src.len(),
             "destination and source slices have different lengths"
         );
-
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in src.iter().enumerate() {
-            self[i] = bf16::from_f64(*f);
-        }
+        self.copy_from_slice(src);
     }
 
     fn convert_to_f32_slice(&self, dst: &mut [f32]) {
@@ -517,6 +1899,85 @@ impl HalfFloatSliceExt for [bf16] {
         }
     }
 
+    fn convert_to_f32_slice_daz(&self, dst: &mut [f32]) {
+        self.convert_to_f32_slice(dst);
+        for (dst, h) in dst.iter_mut().zip(self.iter()) {
+            if h.classify() == FpCategory::Subnormal {
+                *dst = h.to_f32_daz();
+            }
+        }
+    }
+
+    fn convert_to_f64_slice_daz(&self, dst: &mut [f64]) {
+        self.convert_to_f64_slice(dst);
+        for (dst, h) in dst.iter_mut().zip(self.iter()) {
+            if h.classify() == FpCategory::Subnormal {
+                *dst = h.to_f64_daz();
+            }
+        }
+    }
+
+    #[inline]
+    fn minimum(&self) -> Option<bf16> {
+        reduce(softfloat::BF16, self.reinterpret_cast(), softfloat::minimum).map(bf16::from_bits)
+    }
+
+    #[inline]
+    fn maximum(&self) -> Option<bf16> {
+        reduce(softfloat::BF16, self.reinterpret_cast(), softfloat::maximum).map(bf16::from_bits)
+    }
+
+    #[inline]
+    fn minimum_number(&self) -> Option<bf16> {
+        reduce(
+            softfloat::BF16,
+            self.reinterpret_cast(),
+            softfloat::minimum_number,
+        )
+        .map(bf16::from_bits)
+    }
+
+    #[inline]
+    fn maximum_number(&self) -> Option<bf16> {
+        reduce(
+            softfloat::BF16,
+            self.reinterpret_cast(),
+            softfloat::maximum_number,
+        )
+        .map(bf16::from_bits)
+    }
+
+    #[inline]
+    fn canonicalize_nans(&mut self) {
+        canonicalize_nans(softfloat::BF16, self.reinterpret_cast_mut());
+    }
+
+    #[inline]
+    fn max_ulp_error(&self, expected: &[f32]) -> u32 {
+        assert_eq!(
+            self.len(),
+            expected.len(),
+            "actual and expected slices have different lengths"
+        );
+        let expected = expected.iter().map(|&x| bf16::from_f32(x).to_bits());
+        max_ulp_error(softfloat::BF16, self.reinterpret_cast(), expected)
+    }
+
+    #[inline]
+    fn sort_total(&mut self) {
+        sort_total(self.reinterpret_cast_mut());
+    }
+
+    #[inline]
+    fn sort_unstable_total(&mut self) {
+        sort_total(self.reinterpret_cast_mut());
+    }
+
+    #[inline]
+    fn binary_search_total(&self, x: &bf16) -> Result<usize, usize> {
+        binary_search_total(softfloat::BF16, self.reinterpret_cast(), x.to_bits())
+    }
+
     #[cfg(any(feature = "alloc", feature = "std"))]
     #[inline]
     fn to_f32_vec(&self) -> Vec<f32> {
@@ -569,6 +2030,566 @@ impl HalfBitsSliceExt for [u16] {
     }
 }
 
+macro_rules! impl_half_float_slice {
+    ($ty:ident, $fmt:expr) => {
+        impl HalfFloatSliceExt for [$ty] {
+            type Element = $ty;
+
+            #[inline]
+            fn reinterpret_cast(&self) -> &[u16] {
+                let pointer = self.as_ptr() as *const u16;
+                let length = self.len();
+                // SAFETY: We are reconstructing full length of original slice, using its same
+                // lifetime, and the size of elements are identical
+                unsafe { slice::from_raw_parts(pointer, length) }
+            }
+
+            #[inline]
+            fn reinterpret_cast_mut(&mut self) -> &mut [u16] {
+                let pointer = self.as_mut_ptr() as *mut u16;
+                let length = self.len();
+                // SAFETY: We are reconstructing full length of original slice, using its same
+                // lifetime, and the size of elements are identical
+                unsafe { slice::from_raw_parts_mut(pointer, length) }
+            }
+
+            fn convert_from_f32_slice(&mut self, src: &[f32]) {
+                self.convert_from_f32_slice_round(src, RoundingMode::default());
+            }
+
+            fn convert_from_f64_slice(&mut self, src: &[f64]) {
+                self.convert_from_f64_slice_round(src, RoundingMode::default());
+            }
+
+            fn convert_from_f32_slice_round(&mut self, src: &[f32], mode: RoundingMode) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32_round(*f, mode);
+                }
+            }
+
+            fn convert_from_f64_slice_round(&mut self, src: &[f64], mode: RoundingMode) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f64_round(*f, mode);
+                }
+            }
+
+            fn convert_from_f32_slice_stochastic<R>(&mut self, src: &[f32], mut random: R)
+            where
+                R: FnMut() -> u32,
+            {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32_stochastic(*f, random());
+                }
+            }
+
+            fn convert_from_f32_slice_saturating(&mut self, src: &[f32], saturation: Saturation) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32_saturating(*f, saturation);
+                }
+            }
+
+            fn convert_from_f64_slice_saturating(&mut self, src: &[f64], saturation: Saturation) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f64_saturating(*f, saturation);
+                }
+            }
+
+            fn convert_from_f32_slice_ftz(&mut self, src: &[f32]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32_ftz(*f);
+                }
+            }
+
+            fn convert_from_f64_slice_ftz(&mut self, src: &[f64]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f64_ftz(*f);
+                }
+            }
+
+            fn convert_from_f32_slice_with_status(&mut self, src: &[f32]) -> Status {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                let mut status = Status::default();
+                for (dst, f) in self.iter_mut().zip(src) {
+                    let (x, s) = $ty::from_f32_with_status(*f);
+                    *dst = x;
+                    status |= s;
+                }
+                status
+            }
+
+            fn convert_from_f64_slice_with_status(&mut self, src: &[f64]) -> Status {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                let mut status = Status::default();
+                for (dst, f) in self.iter_mut().zip(src) {
+                    let (x, s) = $ty::from_f64_with_status(*f);
+                    *dst = x;
+                    status |= s;
+                }
+                status
+            }
+
+            fn convert_from_f16_slice(&mut self, src: &[f16]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, h) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32(h.to_f32());
+                }
+            }
+
+            fn convert_from_bf16_slice(&mut self, src: &[bf16]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, h) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32(h.to_f32());
+                }
+            }
+
+            fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f32();
+                }
+            }
+
+            fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f64();
+                }
+            }
+
+            fn convert_to_f32_slice_daz(&self, dst: &mut [f32]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f32_daz();
+                }
+            }
+
+            fn convert_to_f64_slice_daz(&self, dst: &mut [f64]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f64_daz();
+                }
+            }
+
+            #[inline]
+            fn minimum(&self) -> Option<$ty> {
+                reduce($fmt, self.reinterpret_cast(), softfloat::minimum).map($ty::from_bits)
+            }
+
+            #[inline]
+            fn maximum(&self) -> Option<$ty> {
+                reduce($fmt, self.reinterpret_cast(), softfloat::maximum).map($ty::from_bits)
+            }
+
+            #[inline]
+            fn minimum_number(&self) -> Option<$ty> {
+                reduce($fmt, self.reinterpret_cast(), softfloat::minimum_number).map($ty::from_bits)
+            }
+
+            #[inline]
+            fn maximum_number(&self) -> Option<$ty> {
+                reduce($fmt, self.reinterpret_cast(), softfloat::maximum_number).map($ty::from_bits)
+            }
+
+            #[inline]
+            fn canonicalize_nans(&mut self) {
+                canonicalize_nans($fmt, self.reinterpret_cast_mut());
+            }
+
+            #[inline]
+            fn max_ulp_error(&self, expected: &[f32]) -> u32 {
+                assert_eq!(
+                    self.len(),
+                    expected.len(),
+                    "actual and expected slices have different lengths"
+                );
+                let expected = expected.iter().map(|&x| $ty::from_f32(x).to_bits());
+                max_ulp_error($fmt, self.reinterpret_cast(), expected)
+            }
+
+            #[inline]
+            fn sort_total(&mut self) {
+                sort_total(self.reinterpret_cast_mut());
+            }
+
+            #[inline]
+            fn sort_unstable_total(&mut self) {
+                sort_total(self.reinterpret_cast_mut());
+            }
+
+            #[inline]
+            fn binary_search_total(&self, x: &$ty) -> Result<usize, usize> {
+                binary_search_total($fmt, self.reinterpret_cast(), x.to_bits())
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            #[inline]
+            fn to_f32_vec(&self) -> Vec<f32> {
+                self.iter().map(|x| x.to_f32()).collect()
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            #[inline]
+            fn to_f64_vec(&self) -> Vec<f64> {
+                self.iter().map(|x| x.to_f64()).collect()
+            }
+        }
+    };
+}
+
+impl_half_float_slice!(f16ahp, softfloat::AHP);
+impl_half_float_slice!(dlfloat16, softfloat::DLF16);
+
+macro_rules! impl_fp8_float_slice {
+    ($ty:ident) => {
+        impl Fp8FloatSliceExt for [$ty] {
+            #[inline]
+            fn reinterpret_cast(&self) -> &[u8] {
+                let pointer = self.as_ptr() as *const u8;
+                let length = self.len();
+                // SAFETY: We are reconstructing full length of original slice, using its same
+                // lifetime, and the size of elements are identical
+                unsafe { slice::from_raw_parts(pointer, length) }
+            }
+
+            #[inline]
+            fn reinterpret_cast_mut(&mut self) -> &mut [u8] {
+                let pointer = self.as_mut_ptr() as *mut u8;
+                let length = self.len();
+                // SAFETY: We are reconstructing full length of original slice, using its same
+                // lifetime, and the size of elements are identical
+                unsafe { slice::from_raw_parts_mut(pointer, length) }
+            }
+
+            fn convert_from_f32_slice(&mut self, src: &[f32]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32(*f);
+                }
+            }
+
+            fn convert_from_f64_slice(&mut self, src: &[f64]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f64(*f);
+                }
+            }
+
+            fn convert_from_f32_slice_saturating(&mut self, src: &[f32], saturation: Saturation) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32_saturating(*f, saturation);
+                }
+            }
+
+            fn convert_from_f64_slice_saturating(&mut self, src: &[f64], saturation: Saturation) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f64_saturating(*f, saturation);
+                }
+            }
+
+            fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f32();
+                }
+            }
+
+            fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f64();
+                }
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            #[inline]
+            fn to_f32_vec(&self) -> Vec<f32> {
+                self.iter().map(|x| x.to_f32()).collect()
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            #[inline]
+            fn to_f64_vec(&self) -> Vec<f64> {
+                self.iter().map(|x| x.to_f64()).collect()
+            }
+        }
+    };
+}
+
+impl_fp8_float_slice!(f8e4m3);
+impl_fp8_float_slice!(f8e5m2);
+impl_fp8_float_slice!(f8e4m3fnuz);
+impl_fp8_float_slice!(f8e5m2fnuz);
+
+impl Fp8BitsSliceExt for [u8] {
+    // Since we sealed all the traits involved, these are safe.
+    #[inline]
+    fn reinterpret_cast<F>(&self) -> &[F]
+    where
+        F: crate::private::SealedFp8,
+    {
+        let pointer = self.as_ptr() as *const F;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
+        // and the size of elements are identical
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    #[inline]
+    fn reinterpret_cast_mut<F>(&mut self) -> &mut [F]
+    where
+        F: crate::private::SealedFp8,
+    {
+        let pointer = self.as_mut_ptr() as *mut F;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
+        // and the size of elements are identical
+        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    }
+}
+
+impl Tf32SliceExt for [f32] {
+    #[inline]
+    fn round_to_tf32(&mut self, mode: RoundingMode) {
+        tfloat32::convert::round_slice(self, mode);
+    }
+}
+
+impl Tf32FloatSliceExt for [tf32] {
+    #[inline]
+    fn reinterpret_cast(&self) -> &[f32] {
+        let pointer = self.as_ptr() as *const f32;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
+        // and the size of elements are identical
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+        self.convert_from_f32_slice_round(src, RoundingMode::NearestTiesToEven);
+    }
+
+    fn convert_from_f32_slice_round(&mut self, src: &[f32], mode: RoundingMode) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+        match mode {
+            RoundingMode::NearestTiesToEven => {
+                for (dst, x) in self.iter_mut().zip(src) {
+                    *dst =
+                        tf32::from_bits(tfloat32::convert::f32_bits_to_tf32_nearest(x.to_bits()));
+                }
+            }
+            RoundingMode::TowardZero => {
+                for (dst, x) in self.iter_mut().zip(src) {
+                    *dst =
+                        tf32::from_bits(tfloat32::convert::f32_bits_to_tf32_truncate(x.to_bits()));
+                }
+            }
+            _ => {
+                for (dst, x) in self.iter_mut().zip(src) {
+                    *dst = tf32::from_f32_round(*x, mode);
+                }
+            }
+        }
+    }
+
+    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+        assert_eq!(
+            self.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+        dst.copy_from_slice(self.reinterpret_cast());
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[inline]
+    fn to_f32_vec(&self) -> Vec<f32> {
+        self.reinterpret_cast().to_vec()
+    }
+}
+
+macro_rules! impl_posit_slice {
+    ($ty:ident, $bits:ty) => {
+        impl<const ES: u32> PositSliceExt for [$ty<ES>] {
+            type Bits = $bits;
+
+            #[inline]
+            fn reinterpret_cast(&self) -> &[$bits] {
+                let pointer = self.as_ptr() as *const $bits;
+                let length = self.len();
+                // SAFETY: We are reconstructing full length of original slice, using its same
+                // lifetime, and the size of elements are identical
+                unsafe { slice::from_raw_parts(pointer, length) }
+            }
+
+            #[inline]
+            fn reinterpret_cast_mut(&mut self) -> &mut [$bits] {
+                let pointer = self.as_mut_ptr() as *mut $bits;
+                let length = self.len();
+                // SAFETY: We are reconstructing full length of original slice, using its same
+                // lifetime, and the size of elements are identical
+                unsafe { slice::from_raw_parts_mut(pointer, length) }
+            }
+
+            fn convert_from_f32_slice(&mut self, src: &[f32]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f32(*f);
+                }
+            }
+
+            fn convert_from_f64_slice(&mut self, src: &[f64]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, f) in self.iter_mut().zip(src) {
+                    *dst = $ty::from_f64(*f);
+                }
+            }
+
+            fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f32();
+                }
+            }
+
+            fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                for (dst, x) in dst.iter_mut().zip(self) {
+                    *dst = x.to_f64();
+                }
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            #[inline]
+            fn to_f32_vec(&self) -> Vec<f32> {
+                self.iter().map(|x| x.to_f32()).collect()
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            #[inline]
+            fn to_f64_vec(&self) -> Vec<f64> {
+                self.iter().map(|x| x.to_f64()).collect()
+            }
+        }
+    };
+}
+
+impl_posit_slice!(Posit8, u8);
+impl_posit_slice!(Posit16, u16);
+impl_posit_slice!(Posit32, u32);
+
 #[doc(hidden)]
 #[deprecated(
     since = "1.4.0",
@@ -612,8 +2633,15 @@ pub fn to_bits(bits: &[f16]) -> &[u16] {
 #[allow(clippy::float_cmp)]
 #[cfg(test)]
 mod test {
-    use super::{HalfBitsSliceExt, HalfFloatSliceExt};
-    use crate::{bf16, f16};
+    use super::{
+        Fp8BitsSliceExt, Fp8FloatSliceExt, HalfBitsSliceExt, HalfFloatSliceExt, PositSliceExt,
+        Tf32FloatSliceExt, Tf32SliceExt,
+    };
+    use crate::{
+        bf16, dlfloat16, f16, f16ahp, f8e4m3, f8e4m3fnuz, f8e5m2, f8e5m2fnuz, RoundingMode,
+        Saturation,
+    };
+    use core::{cmp::Ordering, num::FpCategory};
 
     #[test]
     fn test_slice_conversions_f16() {
@@ -670,6 +2698,209 @@ mod test {
         assert_eq!(to_bits, bits);
     }
 
+    #[test]
+    fn test_slice_conversions_f16_bf16() {
+        // Every value, with a remainder after the chunks of four
+        let mut halves = [f16::ZERO; 0xFFFF];
+        for (i, h) in halves.iter_mut().enumerate() {
+            *h = f16::from_bits(i as u16 + 1);
+        }
+        let mut bfloats = [bf16::ZERO; 0xFFFF];
+        bfloats.convert_from_f16_slice(&halves);
+        for (h, b) in halves.iter().zip(&bfloats) {
+            if h.is_nan() {
+                assert!(b.is_nan());
+            } else {
+                assert_eq!(b.to_bits(), h.to_bf16().to_bits());
+            }
+        }
+
+        for (i, b) in bfloats.iter_mut().enumerate() {
+            *b = bf16::from_bits(i as u16 + 1);
+        }
+        halves.convert_from_bf16_slice(&bfloats);
+        for (b, h) in bfloats.iter().zip(&halves) {
+            if b.is_nan() {
+                assert!(h.is_nan());
+            } else {
+                assert_eq!(h.to_bits(), b.to_f16().to_bits());
+            }
+        }
+
+        // Same type copies, and the other 16-bit formats round once through f32
+        let src = [f16::ONE, f16::MAX, f16::from_f32(0.1)];
+        let mut dst = [f16::ZERO; 3];
+        dst.convert_from_f16_slice(&src);
+        assert_eq!(dst, src);
+        let mut dst = [f16ahp::ZERO; 3];
+        dst.convert_from_f16_slice(&src);
+        assert_eq!(
+            dst,
+            [f16ahp::ONE, f16ahp::from_f32(65504.), f16ahp::from_f32(0.1)]
+        );
+        let src = [bf16::ONE, bf16::MAX, bf16::from_f32(1e9)];
+        let mut dst = [bf16::ZERO; 3];
+        dst.convert_from_bf16_slice(&src);
+        assert_eq!(dst, src);
+        let mut dst = [dlfloat16::ZERO; 3];
+        dst.convert_from_bf16_slice(&src);
+        assert_eq!(dst[0], dlfloat16::ONE);
+        assert!(dst[1].is_nan());
+        assert_eq!(dst[2], dlfloat16::from_f32(bf16::from_f32(1e9).to_f32()));
+    }
+
+    #[test]
+    fn test_slice_reductions() {
+        let values = [
+            f16::from_f32(2.),
+            f16::ZERO,
+            f16::NEG_ZERO,
+            f16::NAN,
+            f16::from_f32(-3.),
+        ];
+        assert!(values.minimum().unwrap().is_nan());
+        assert!(values.maximum().unwrap().is_nan());
+        assert_eq!(values.minimum_number(), Some(f16::from_f32(-3.)));
+        assert_eq!(values.maximum_number(), Some(f16::from_f32(2.)));
+        assert_eq!(values[..3].minimum().unwrap().to_bits(), 0x8000);
+        assert_eq!(values[1..3].maximum().unwrap().to_bits(), 0);
+        assert_eq!(
+            values[3..4].minimum_number().unwrap().to_bits(),
+            f16::NAN.to_bits()
+        );
+        assert_eq!(<[f16]>::maximum_number(&[]), None);
+
+        // Signaling NaNs are quieted
+        let signaling = [bf16::ONE, bf16::from_bits(0x7F81)];
+        assert_eq!(signaling.minimum().unwrap().to_bits(), 0x7FC1);
+        assert_eq!(signaling.maximum_number(), Some(bf16::ONE));
+        assert_eq!(<[bf16]>::minimum(&[]), None);
+
+        let ahp = [f16ahp::ONE, f16ahp::MAX, f16ahp::from_f32(-0.5)];
+        assert_eq!(ahp.minimum(), Some(f16ahp::from_f32(-0.5)));
+        assert_eq!(ahp.maximum(), Some(f16ahp::MAX));
+
+        let dlf = [dlfloat16::ONE, dlfloat16::NAN, dlfloat16::from_f32(4.)];
+        assert!(dlf.maximum().unwrap().is_nan());
+        assert_eq!(dlf.maximum_number(), Some(dlfloat16::from_f32(4.)));
+    }
+
+    #[test]
+    fn test_canonicalize_nans() {
+        let mut halves = [
+            f16::from_bits(0x7C01),
+            f16::INFINITY,
+            -f16::nan_with_payload(0x1FF),
+            f16::NEG_ZERO,
+            f16::NAN,
+        ];
+        halves.canonicalize_nans();
+        assert_eq!(
+            halves.reinterpret_cast(),
+            [0x7E00, 0x7C00, 0x7E00, 0x8000, 0x7E00]
+        );
+
+        let mut bfloats = [bf16::from_bits(0xFFFF), bf16::MAX];
+        bfloats.canonicalize_nans();
+        assert_eq!(bfloats.reinterpret_cast(), [0x7FC0, 0x7F7F]);
+
+        let mut dlf = [dlfloat16::from_bits(0xFFFF), dlfloat16::ONE];
+        dlf.canonicalize_nans();
+        assert_eq!(dlf[0].to_bits(), dlfloat16::NAN.to_bits());
+        assert_eq!(dlf[1], dlfloat16::ONE);
+
+        let mut ahp = [f16ahp::from_bits(0xFFFF)];
+        ahp.canonicalize_nans();
+        assert_eq!(ahp[0], f16ahp::MIN);
+    }
+
+    #[test]
+    fn test_sort_total() {
+        // Every f16 value, scrambled by an odd multiplier
+        let mut values = [f16::ZERO; 0x10000];
+        for (i, x) in values.iter_mut().enumerate() {
+            *x = f16::from_bits((i as u16).wrapping_mul(40503));
+        }
+        values.sort_total();
+        for pair in values.windows(2) {
+            assert_eq!(pair[0].total_cmp(&pair[1]), Ordering::Less);
+        }
+        assert_eq!(values[0].to_bits(), 0xFFFF);
+        assert_eq!(values[0xFFFF].to_bits(), 0x7FFF);
+        for (i, x) in values.iter().enumerate() {
+            assert_eq!(values.binary_search_total(x), Ok(i));
+        }
+
+        // Duplicates, and the short slice path
+        let mut bfloats = [
+            bf16::ONE,
+            bf16::NAN,
+            bf16::NEG_ZERO,
+            bf16::ONE,
+            bf16::ZERO,
+            -bf16::ONE,
+        ];
+        let mut long = [bf16::ZERO; 300];
+        for (i, x) in long.iter_mut().enumerate() {
+            *x = bfloats[i % bfloats.len()];
+        }
+        bfloats.sort_unstable_total();
+        assert_eq!(
+            bfloats.reinterpret_cast(),
+            [0xBF80, 0x8000, 0x0000, 0x3F80, 0x3F80, 0x7FC0]
+        );
+        long.sort_total();
+        for (i, x) in long.iter().enumerate() {
+            assert_eq!(x.to_bits(), bfloats[i / 50].to_bits());
+        }
+        assert_eq!(bfloats.binary_search_total(&bf16::from_f32(0.5)), Err(3));
+        assert_eq!(bfloats.binary_search_total(&bf16::INFINITY), Err(5));
+        assert_eq!(bfloats.binary_search_total(&-bf16::NAN), Err(0));
+
+        let mut ahp = [
+            f16ahp::MAX,
+            f16ahp::MIN,
+            f16ahp::from_f32(-0.0),
+            f16ahp::from_f32(0.0),
+        ];
+        ahp.sort_total();
+        assert_eq!(ahp.reinterpret_cast(), [0xFFFF, 0x8000, 0x0000, 0x7FFF]);
+    }
+
+    #[test]
+    fn test_max_ulp_error() {
+        let expected = [0.1f32, -2.5, 1e9, f32::NAN];
+        let mut halves = [f16::ZERO; 4];
+        halves.convert_from_f32_slice(&expected);
+        assert_eq!(halves.max_ulp_error(&expected), 0);
+        halves[0] = halves[0].next_down();
+        halves[1] = halves[1].next_up().next_up();
+        assert_eq!(halves.max_ulp_error(&expected), 2);
+        halves[3] = f16::ONE;
+        assert_eq!(halves.max_ulp_error(&expected), u32::MAX);
+
+        let bfloats = [bf16::from_f32(1.0).next_up(); 5];
+        assert_eq!(bfloats.max_ulp_error(&[1.0; 5]), 1);
+        assert_eq!(bfloats[..0].max_ulp_error(&[]), 0);
+
+        let ahp = [f16ahp::MAX, f16ahp::from_f32(70000.)];
+        assert_eq!(ahp.max_ulp_error(&[131008., 70000.]), 0);
+        assert_eq!(ahp.max_ulp_error(&[65504., 70000.]), 0x400);
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_ulp_error_len_mismatch_panics() {
+        let _ = [bf16::ONE; 2].max_ulp_error(&[1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_f16_slice_len_mismatch_panics() {
+        let mut buf = [bf16::ZERO; 3];
+        buf.convert_from_f16_slice(&[f16::ONE; 2]);
+    }
+
     #[test]
     fn test_mutablility_bf16() {
         let mut bits_array = [bf16::PI.to_bits()];
@@ -950,4 +3181,387 @@ mod test {
         let mut slice2 = [0f64; 4];
         slice1.convert_to_f64_slice(&mut slice2);
     }
+
+    #[test]
+    fn slice_convert_round() {
+        use crate::RoundingMode;
+
+        let vf32 = [0.1f32, -0.1, 1e6, -1e6, 3.0];
+        let mut lower = [f16::ZERO; 5];
+        let mut upper = [f16::ZERO; 5];
+        lower.convert_from_f32_slice_round(&vf32, RoundingMode::TowardNegative);
+        upper.convert_from_f32_slice_round(&vf32, RoundingMode::TowardPositive);
+        for i in 0..vf32.len() {
+            assert!(lower[i].to_f32() <= vf32[i] && vf32[i] <= upper[i].to_f32());
+            assert_eq!(
+                lower[i],
+                f16::from_f32_round(vf32[i], RoundingMode::TowardNegative)
+            );
+        }
+        assert_eq!(lower[2], f16::MAX);
+        assert_eq!(upper[3], f16::MIN);
+
+        let vf64 = [0.1f64, -0.1, 1e300, 3.0];
+        let mut truncated = [bf16::ZERO; 4];
+        truncated.convert_from_f64_slice_round(&vf64, RoundingMode::TowardZero);
+        for i in 0..vf64.len() {
+            assert!(truncated[i].to_f64().abs() <= vf64[i].abs());
+        }
+        assert_eq!(truncated[2], bf16::MAX);
+        assert_eq!(truncated[3], bf16::from_f32(3.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_f32_slice_round_len_mismatch_panics() {
+        let mut slice1 = [f16::ZERO; 3];
+        let slice2 = [0f32; 4];
+        slice1.convert_from_f32_slice_round(&slice2, crate::RoundingMode::TowardZero);
+    }
+
+    #[test]
+    fn slice_convert_stochastic() {
+        fn xorshift(seed: u32) -> impl FnMut() -> u32 {
+            let mut state = seed;
+            move || {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state
+            }
+        }
+
+        let vf32 = [1.0f32 + 0.3 * bf16::EPSILON.to_f32(); 1000];
+        let mut a = [bf16::ZERO; 1000];
+        let mut b = [bf16::ZERO; 1000];
+        a.convert_from_f32_slice_stochastic(&vf32, xorshift(7));
+        b.convert_from_f32_slice_stochastic(&vf32, xorshift(7));
+        assert_eq!(a, b);
+
+        // Same random sequence as the scalar conversion
+        let mut rng = xorshift(7);
+        for x in &a {
+            assert_eq!(*x, bf16::from_f32_stochastic(vf32[0], rng()));
+        }
+
+        // Unbiased on average
+        let mean = a.iter().map(|x| x.to_f64()).sum::<f64>() / a.len() as f64;
+        assert!((mean - vf32[0] as f64).abs() < 0.1 * bf16::EPSILON.to_f64());
+
+        let vf32 = [0.1f32, -0.1, 3.0, 1e-7];
+        let mut c = [f16::ZERO; 4];
+        c.convert_from_f32_slice_stochastic(&vf32, xorshift(1));
+        for (x, f) in c.iter().zip(&vf32) {
+            assert!((x.to_f32() - f).abs() <= f16::EPSILON.to_f32() * f.abs().max(1e-4));
+        }
+    }
+
+    #[test]
+    fn slice_convert_saturating() {
+        use crate::Saturation;
+
+        let keep = Saturation::default();
+        let clamp = Saturation {
+            clamp_infinity: true,
+            nan_to_zero: true,
+        };
+        let vf32 = [
+            1e6f32,
+            -1e6,
+            65519.0,
+            65520.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            2.0,
+            -3.0,
+        ];
+        let mut buf = [f16::ZERO; 9];
+        buf.convert_from_f32_slice_saturating(&vf32, keep);
+        assert_eq!(
+            &buf[..6],
+            &[
+                f16::MAX,
+                f16::MIN,
+                f16::MAX,
+                f16::MAX,
+                f16::INFINITY,
+                f16::NEG_INFINITY
+            ]
+        );
+        assert!(buf[6].is_nan());
+        assert_eq!(&buf[7..], &[f16::from_f32(2.0), f16::from_f32(-3.0)]);
+        for (x, f) in buf.iter().zip(&vf32) {
+            let scalar = f16::from_f32_saturating(*f, keep);
+            assert!(x.to_bits() == scalar.to_bits());
+        }
+
+        buf.convert_from_f32_slice_saturating(&vf32, clamp);
+        assert_eq!(&buf[4..7], &[f16::MAX, f16::MIN, f16::ZERO]);
+
+        let vf64 = [1e300f64, -1e300, f64::INFINITY, f64::NAN, 0.5];
+        let mut buf = [bf16::ZERO; 5];
+        buf.convert_from_f64_slice_saturating(&vf64, clamp);
+        assert_eq!(
+            buf,
+            [
+                bf16::MAX,
+                bf16::MIN,
+                bf16::MAX,
+                bf16::ZERO,
+                bf16::from_f32(0.5)
+            ]
+        );
+        buf.convert_from_f64_slice_saturating(&vf64, keep);
+        assert_eq!(&buf[..3], &[bf16::MAX, bf16::MIN, bf16::INFINITY]);
+        assert!(buf[3].is_nan());
+    }
+
+    #[test]
+    fn slice_convert_ftz_daz() {
+        let tiny32 = f16::MIN_POSITIVE.to_f32() / 4.0;
+        let vf32 = [tiny32, -tiny32, 1e-40, -1e-40, 65504.0, f32::NAN, -0.0, 1.0];
+        let mut buf = [f16::ONE; 8];
+        buf.convert_from_f32_slice_ftz(&vf32);
+        for (x, f) in buf.iter().zip(&vf32) {
+            let scalar = f16::from_f32_ftz(*f);
+            assert!(x.to_bits() == scalar.to_bits());
+            assert!(x.classify() != FpCategory::Subnormal);
+        }
+        assert_eq!(buf[1].to_bits(), f16::NEG_ZERO.to_bits());
+        assert_eq!(buf[3].to_bits(), f16::NEG_ZERO.to_bits());
+
+        let vf64 = [1e-300f64, -1e-300, bf16::MIN_POSITIVE.to_f64() / 4.0, 3.0];
+        let mut buf = [bf16::ONE; 4];
+        buf.convert_from_f64_slice_ftz(&vf64);
+        assert_eq!(
+            buf,
+            [bf16::ZERO, bf16::ZERO, bf16::ZERO, bf16::from_f64(3.0)]
+        );
+        assert_eq!(buf[1].to_bits(), bf16::NEG_ZERO.to_bits());
+
+        let halves = [
+            f16::MIN_POSITIVE_SUBNORMAL,
+            -f16::MAX_SUBNORMAL,
+            f16::MIN_POSITIVE,
+            f16::NEG_INFINITY,
+        ];
+        let mut out32 = [1f32; 4];
+        halves.convert_to_f32_slice_daz(&mut out32);
+        let mut out64 = [1f64; 4];
+        halves.convert_to_f64_slice_daz(&mut out64);
+        for ((h, a), b) in halves.iter().zip(&out32).zip(&out64) {
+            assert!(a.to_bits() == h.to_f32_daz().to_bits());
+            assert!(b.to_bits() == h.to_f64_daz().to_bits());
+        }
+        assert_eq!(out32[1].to_bits(), (-0f32).to_bits());
+        assert_eq!(out64[2], f16::MIN_POSITIVE.to_f64());
+
+        let halves = [
+            bf16::MAX_SUBNORMAL,
+            -bf16::MIN_POSITIVE_SUBNORMAL,
+            bf16::ONE,
+        ];
+        let mut out32 = [1f32; 3];
+        halves.convert_to_f32_slice_daz(&mut out32);
+        assert_eq!(out32[1].to_bits(), (-0f32).to_bits());
+        assert_eq!(out32, [0., 0., 1.]);
+    }
+
+    #[test]
+    fn slice_convert_with_status() {
+        let vf32 = [1.0f32, 0.5, -2.0, f32::INFINITY, f32::NAN];
+        let mut buf = [f16::ZERO; 5];
+        assert!(buf.convert_from_f32_slice_with_status(&vf32).is_empty());
+
+        let vf32 = [1.0f32, 0.1, 1e-10, 1e6, f32::from_bits(0x7F80_0001)];
+        let status = buf.convert_from_f32_slice_with_status(&vf32);
+        for (x, f) in buf.iter().zip(&vf32) {
+            let scalar = f16::from_f32_with_status(*f).0;
+            assert!(x.to_bits() == scalar.to_bits() || (x.is_nan() && scalar.is_nan()));
+        }
+        assert!(status.inexact && status.underflow && status.overflow && status.invalid);
+        assert!(!status.division_by_zero);
+
+        let vf64 = [1.0f64, 3.0, 1e300];
+        let mut buf = [bf16::ZERO; 3];
+        assert!(buf[..2]
+            .convert_from_f64_slice_with_status(&vf64[..2])
+            .is_empty());
+        let status = buf.convert_from_f64_slice_with_status(&vf64);
+        assert_eq!(buf[2], bf16::INFINITY);
+        assert!(status.overflow && status.inexact && !status.underflow);
+    }
+
+    #[test]
+    fn slice_convert_fp8() {
+        let vf32 = [1.0f32, -0.3, 500.0, f32::NEG_INFINITY, 1e-10];
+        let mut buf = [f8e4m3::ZERO; 5];
+        buf.convert_from_f32_slice(&vf32);
+        for (x, f) in buf.iter().zip(&vf32) {
+            assert_eq!(x.to_bits(), f8e4m3::from_f32(*f).to_bits());
+        }
+        let clamp = Saturation {
+            clamp_infinity: true,
+            nan_to_zero: false,
+        };
+        buf.convert_from_f32_slice_saturating(&vf32, clamp);
+        assert_eq!(buf[2], f8e4m3::MAX);
+        assert_eq!(buf[3], f8e4m3::MIN);
+
+        let mut out = [0f32; 5];
+        buf.convert_to_f32_slice(&mut out);
+        assert_eq!(out, [1.0, -0.3125, 448.0, -448.0, 0.0]);
+
+        let vf64 = [2.0f64, -1e300];
+        let mut buf = [f8e5m2::ZERO; 2];
+        buf.convert_from_f64_slice(&vf64);
+        assert_eq!(buf, [f8e5m2::from_f32(2.0), f8e5m2::NEG_INFINITY]);
+        buf.convert_from_f64_slice_saturating(&vf64, Saturation::default());
+        assert_eq!(buf[1], f8e5m2::MIN);
+        let mut out = [0f64; 2];
+        buf.convert_to_f64_slice(&mut out);
+        assert_eq!(out, [2.0, -57344.0]);
+
+        let mut bits = [0x40u8, 0x80];
+        assert_eq!(bits.reinterpret_cast::<f8e4m3fnuz>()[0], f8e4m3fnuz::ONE);
+        assert!(bits.reinterpret_cast::<f8e4m3fnuz>()[1].is_nan());
+        bits.reinterpret_cast_mut::<f8e5m2fnuz>()[1] = f8e5m2fnuz::ONE;
+        assert_eq!(bits, [0x40, 0x40]);
+        let floats = [f8e5m2fnuz::ONE; 2];
+        assert_eq!(floats.reinterpret_cast(), [0x40, 0x40]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_f32_slice_fp8_len_mismatch_panics() {
+        let mut slice1 = [f8e4m3::ZERO; 3];
+        let slice2 = [0f32; 4];
+        slice1.convert_from_f32_slice(&slice2);
+    }
+
+    #[test]
+    fn test_slice_conversions_alt16() {
+        let vf32 = [1.0f32, 100000.0, f32::INFINITY, 1e-7];
+        let mut buf = [f16ahp::ZERO; 4];
+        let status = buf.convert_from_f32_slice_with_status(&vf32);
+        assert_eq!(
+            buf[..3],
+            [f16ahp::ONE, f16ahp::from_bits(0x7E1A), f16ahp::MAX]
+        );
+        assert!(status.invalid && status.inexact);
+        assert_eq!(buf[3].classify(), FpCategory::Subnormal);
+        buf.convert_from_f32_slice_ftz(&vf32);
+        assert_eq!(buf[3], f16ahp::ZERO);
+        let mut out = [0f32; 4];
+        buf.convert_to_f32_slice(&mut out);
+        assert_eq!(out, [1.0, 99968.0, 131008.0, 0.0]);
+        assert_eq!(buf.reinterpret_cast()[0], 0x3C00);
+
+        let vf64 = [1.5f64, -1e10, f64::NAN];
+        let mut buf = [dlfloat16::ZERO; 3];
+        buf.convert_from_f64_slice(&vf64);
+        assert_eq!(buf[0].to_f64(), 1.5);
+        assert!(buf[1].is_nan() && buf[2].is_nan());
+        buf.convert_from_f64_slice_saturating(&vf64, Saturation::default());
+        assert_eq!(buf[1], dlfloat16::MIN);
+        buf[..1].convert_from_f64_slice_round(&[1.0 + 1e-9], RoundingMode::TowardPositive);
+        assert_eq!(buf[0], dlfloat16::from_bits(dlfloat16::ONE.to_bits() + 1));
+
+        let mut bits = [0x3E00u16, 0x3C00];
+        assert_eq!(bits.reinterpret_cast::<dlfloat16>()[0], dlfloat16::ONE);
+        bits.reinterpret_cast_mut::<f16ahp>()[1] = f16ahp::MAX;
+        assert_eq!(bits, [0x3E00, 0x7FFF]);
+    }
+
+    #[test]
+    fn test_slice_conversions_tf32() {
+        use crate::tf32;
+
+        let values = [
+            1.0f32 + f32::EPSILON,
+            0.1,
+            -f32::MAX,
+            f32::NAN,
+            1.0 + 16384.0 * f32::EPSILON,
+        ];
+        let mut rounded = values;
+        rounded.round_to_tf32(RoundingMode::NearestTiesToEven);
+        let mut truncated = values;
+        truncated.round_to_tf32(RoundingMode::TowardZero);
+        let mut upward = values;
+        upward.round_to_tf32(RoundingMode::TowardPositive);
+        for i in 0..values.len() {
+            let expected = tf32::from_f32(values[i]).to_f32();
+            assert!(rounded[i] == expected || (rounded[i].is_nan() && expected.is_nan()));
+            let expected = tf32::from_f32_round(values[i], RoundingMode::TowardZero).to_f32();
+            assert!(truncated[i] == expected || (truncated[i].is_nan() && expected.is_nan()));
+            let expected = tf32::from_f32_round(values[i], RoundingMode::TowardPositive).to_f32();
+            assert!(upward[i] == expected || (upward[i].is_nan() && expected.is_nan()));
+        }
+        assert_eq!(rounded[2], f32::NEG_INFINITY);
+        assert_eq!(truncated[2], tf32::MIN.to_f32());
+        assert_eq!(upward[0], 1.0 + 8192.0 * f32::EPSILON);
+
+        let mut buf = [tf32::ZERO; 5];
+        buf.convert_from_f32_slice(&values);
+        assert_eq!(buf.reinterpret_cast()[..3], rounded[..3]);
+        buf.convert_from_f32_slice_round(&values, RoundingMode::TowardZero);
+        let mut out = [0f32; 5];
+        buf.convert_to_f32_slice(&mut out);
+        assert_eq!(out[..3], truncated[..3]);
+        assert!(out[3].is_nan());
+        assert_eq!(out[4], 1.0 + 16384.0 * f32::EPSILON);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_f32_slice_tf32_len_mismatch_panics() {
+        let mut buf = [crate::tf32::ZERO; 2];
+        buf.convert_from_f32_slice(&[1.0]);
+    }
+
+    #[test]
+    fn test_slice_conversions_posit() {
+        use crate::{p16, p32, p8};
+
+        let vf32 = [1.0f32, -0.1, 1e30, f32::NAN];
+        let vf64 = [1.0f64, -0.1, 1e30, f64::NAN];
+
+        let mut buf = [p16::ZERO; 4];
+        buf.convert_from_f32_slice(&vf32);
+        assert_eq!(buf[..3], [p16::ONE, p16::from_f32(-0.1), p16::MAX]);
+        assert!(buf[3].is_nar());
+        let mut out = [0f32; 4];
+        buf.convert_to_f32_slice(&mut out);
+        assert_eq!(
+            out[..3],
+            [1.0, p16::from_f32(-0.1).to_f32(), p16::MAX.to_f32()]
+        );
+        assert!(out[3].is_nan());
+        assert_eq!(buf.reinterpret_cast()[0], 0x4000);
+        buf.reinterpret_cast_mut()[0] = 0xC000;
+        assert_eq!(buf[0], p16::NEG_ONE);
+
+        let mut buf = [p32::ZERO; 4];
+        buf.convert_from_f64_slice(&vf64);
+        assert!(buf[3].is_nar());
+        let mut out = [0f64; 4];
+        buf.convert_to_f64_slice(&mut out);
+        assert_eq!(
+            out[..3],
+            [
+                1.0,
+                p32::from_f64(-0.1).to_f64(),
+                p32::from_f64(1e30).to_f64()
+            ]
+        );
+
+        let mut bits = [0x40u8, 0x7F];
+        assert_eq!(bits.reinterpret_cast::<p8>(), [p8::ONE, p8::MAX]);
+        bits.reinterpret_cast_mut::<p8>()[0] = p8::NAR;
+        assert_eq!(bits, [0x80, 0x7F]);
+        let bits = [0x4000u16];
+        assert_eq!(bits.reinterpret_cast::<p16>(), [p16::ONE]);
+    }
 }
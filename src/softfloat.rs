@@ -0,0 +1,1779 @@
+//! Software floating point core operating on the raw bits of narrow binary formats.
+//!
+//! Every operation here computes the exact result with integer arithmetic and then rounds it once,
+//! so results are identical on every target regardless of available hardware support. Formats are
+//! described by a [`Format`] and bits are passed around as `u32` so the same code serves both
+//! [`f16`][crate::f16] and [`bf16`][crate::bf16]. Conversions also serve the narrower and
+//! non-IEEE formats, but arithmetic requires [`Encoding::Ieee`].
+//!
+//! NaN operands are propagated by returning the first NaN operand with its quiet bit set. Invalid
+//! operations that have no NaN operand return the format's default quiet NaN.
+//!
+//! Operations report IEEE 754 exceptions by raising flags in a caller-provided [`Status`].
+
+use core::ops::{BitOr, BitOrAssign};
+
+pub(crate) mod math;
+
+/// Rounding direction used when a result is not exactly representable in the target format
+///
+/// The default mode used by all conversions and arithmetic is
+/// [`NearestTiesToEven`][RoundingMode::NearestTiesToEven].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest representable value, choosing the one with an even least significant
+    /// bit on a tie
+    NearestTiesToEven,
+    /// Round to the nearest representable value, choosing the one with the larger magnitude on a
+    /// tie
+    NearestTiesToAway,
+    /// Round towards zero, i.e. truncate. Overflow results in the largest finite value.
+    TowardZero,
+    /// Round towards +∞. Negative overflow results in the most negative finite value.
+    TowardPositive,
+    /// Round towards −∞. Positive overflow results in the largest finite value.
+    TowardNegative,
+    /// Truncate, then set the least significant bit if the result was inexact
+    ///
+    /// Rounding to odd into a format with at least two more bits of precision than the final
+    /// destination, and then rounding that to nearest, gives the same result as rounding the
+    /// original value to nearest directly. This makes it suitable for cascaded narrowing.
+    /// Overflow results in the largest finite value.
+    ToOdd,
+}
+
+impl Default for RoundingMode {
+    #[inline]
+    fn default() -> Self {
+        RoundingMode::NearestTiesToEven
+    }
+}
+
+/// Controls how saturating conversions treat infinities and NaN
+///
+/// Finite values outside the range of the destination format are always clamped to its largest
+/// finite magnitude, keeping their sign. The default preserves infinities and NaN.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Saturation {
+    /// Clamp ±∞ to the largest finite magnitude, keeping the sign
+    pub clamp_infinity: bool,
+    /// Convert NaN to `+0.0`
+    pub nan_to_zero: bool,
+}
+
+impl Saturation {
+    /// Applies saturation to `bits`, the result of converting `value` with the default rounding
+    #[inline]
+    pub(crate) fn apply(self, fmt: Format, value: f64, bits: u32) -> u32 {
+        if value.is_nan() {
+            if self.nan_to_zero {
+                0
+            } else {
+                bits
+            }
+        } else if (value.is_infinite() && self.clamp_infinity)
+            || (!value.is_infinite() && (fmt.is_nan(bits) || fmt.is_infinite(bits)))
+        {
+            sign_bit(fmt, value.is_sign_negative()) | fmt.max()
+        } else {
+            bits
+        }
+    }
+}
+
+/// IEEE 754 exception flags raised by an operation
+///
+/// Flags are sticky: combining statuses with `|` accumulates every exception raised by a sequence
+/// of operations. Underflow is only signaled when a result is both tiny and inexact, and tininess
+/// is detected before rounding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Status {
+    /// The operation had no meaningful result, such as `∞ - ∞`, or an operand was a signaling NaN
+    pub invalid: bool,
+    /// A finite nonzero value was divided by zero
+    pub division_by_zero: bool,
+    /// The rounded result was too large in magnitude to be represented as a finite value
+    pub overflow: bool,
+    /// The result was smaller in magnitude than the smallest normal value and was inexact
+    pub underflow: bool,
+    /// The rounded result differs from the exact result
+    pub inexact: bool,
+}
+
+impl Status {
+    /// Returns `true` if no exception flag is raised
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert!(f16::from_f32_with_status(0.5).1.is_empty());
+    /// assert!(!f16::from_f32_with_status(0.1).1.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        !(self.invalid || self.division_by_zero || self.overflow || self.underflow || self.inexact)
+    }
+}
+
+impl BitOr for Status {
+    type Output = Status;
+
+    #[inline]
+    fn bitor(self, rhs: Status) -> Status {
+        Status {
+            invalid: self.invalid || rhs.invalid,
+            division_by_zero: self.division_by_zero || rhs.division_by_zero,
+            overflow: self.overflow || rhs.overflow,
+            underflow: self.underflow || rhs.underflow,
+            inexact: self.inexact || rhs.inexact,
+        }
+    }
+}
+
+impl BitOrAssign for Status {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Status) {
+        *self = *self | rhs;
+    }
+}
+
+/// The ten classes of floating point data distinguished by the IEEE 754 `class` operation
+///
+/// Unlike [`FpCategory`][core::num::FpCategory], this separates signaling from quiet NaN and
+/// includes the sign of every non-NaN value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FpClass {
+    /// A NaN that signals the invalid operation exception when used as an operand
+    SignalingNaN,
+    /// A NaN that propagates through operations without signaling
+    QuietNaN,
+    /// −∞
+    NegativeInfinity,
+    /// A negative normal number
+    NegativeNormal,
+    /// A negative subnormal number
+    NegativeSubnormal,
+    /// `-0.0`
+    NegativeZero,
+    /// `+0.0`
+    PositiveZero,
+    /// A positive subnormal number
+    PositiveSubnormal,
+    /// A positive normal number
+    PositiveNormal,
+    /// +∞
+    PositiveInfinity,
+}
+
+/// A rounding rule applied by [`round_pack`]
+pub(crate) trait Round: Copy {
+    /// Whether an inexact truncated result should be incremented, given the discarded `shift` low
+    /// bits `rem` and the encoding `r` of the truncated result, whose parity breaks ties
+    fn increment(self, sign: bool, r: u128, rem: u128, shift: u32) -> bool;
+
+    /// Whether the least significant bit of an inexact truncated result should be set
+    #[inline]
+    fn jam(self) -> bool {
+        false
+    }
+
+    /// Whether values beyond the finite range round to infinity rather than the largest finite
+    /// value
+    fn overflows_to_infinity(self, sign: bool) -> bool;
+}
+
+impl Round for RoundingMode {
+    #[inline]
+    fn increment(self, sign: bool, r: u128, rem: u128, shift: u32) -> bool {
+        let half = 1u128 << (shift - 1);
+        match self {
+            RoundingMode::NearestTiesToEven => rem > half || (rem == half && r & 1 != 0),
+            RoundingMode::NearestTiesToAway => rem >= half,
+            RoundingMode::TowardZero | RoundingMode::ToOdd => false,
+            RoundingMode::TowardPositive => rem != 0 && !sign,
+            RoundingMode::TowardNegative => rem != 0 && sign,
+        }
+    }
+
+    #[inline]
+    fn jam(self) -> bool {
+        self == RoundingMode::ToOdd
+    }
+
+    #[inline]
+    fn overflows_to_infinity(self, sign: bool) -> bool {
+        match self {
+            RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => true,
+            RoundingMode::TowardZero | RoundingMode::ToOdd => false,
+            RoundingMode::TowardPositive => !sign,
+            RoundingMode::TowardNegative => sign,
+        }
+    }
+}
+
+/// Stochastic rounding driven by 32 uniformly distributed random bits
+///
+/// An inexact result is rounded away from zero with probability equal to its distance from the
+/// truncated value, in units of the last place, resolved to 32 bits.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Stochastic(pub u32);
+
+impl Round for Stochastic {
+    #[inline]
+    fn increment(self, _sign: bool, _r: u128, rem: u128, shift: u32) -> bool {
+        // The discarded bits as a 32-bit binary fraction of the last place
+        let frac = if shift >= 32 {
+            (rem >> (shift - 32)) as u32
+        } else {
+            (rem << (32 - shift)) as u32
+        };
+        self.0 < frac
+    }
+
+    #[inline]
+    fn overflows_to_infinity(self, _sign: bool) -> bool {
+        true
+    }
+}
+
+/// Rounding used by arithmetic, which is always to nearest
+const NEAREST: RoundingMode = RoundingMode::NearestTiesToEven;
+
+/// How a format uses its largest and smallest exponents
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Encoding {
+    /// IEEE 754: the all-ones exponent holds ±∞ and NaN, and the zero exponent holds subnormals
+    Ieee,
+    /// No ±∞ or NaN: the all-ones exponent holds normal values, as in Arm alternative
+    /// half-precision and the MX element types. Results that overflow saturate, and converting ±∞
+    /// or NaN is invalid.
+    Alternative,
+    /// IBM DLFloat: the all-ones pattern is the only NaN and also stands in for ±∞, and the zero
+    /// exponent holds normal values, so there are no subnormals and results below the smallest
+    /// normal flush to zero
+    DlFloat,
+    /// OCP `E4M3FN`: the all-ones exponent holds normal values except for the all-ones pattern,
+    /// which is NaN and also stands in for ±∞
+    Fn,
+    /// Like [`Fn`][Encoding::Fn], but without negative zero: its pattern is the only NaN, which
+    /// has no sign
+    Fnuz,
+}
+
+/// A binary floating point format with an implicit leading significand bit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Format {
+    pub exp_bits: u32,
+    pub man_bits: u32,
+    pub bias: i32,
+    pub encoding: Encoding,
+}
+
+/// IEEE 754 `binary16`
+pub(crate) const F16: Format = Format {
+    exp_bits: 5,
+    man_bits: 10,
+    bias: 15,
+    encoding: Encoding::Ieee,
+};
+
+/// `bfloat16`
+pub(crate) const BF16: Format = Format {
+    exp_bits: 8,
+    man_bits: 7,
+    bias: 127,
+    encoding: Encoding::Ieee,
+};
+
+/// NVIDIA TensorFloat-32, whose 19 bits are stored in the upper bits of an `f32`
+pub(crate) const TF32: Format = Format {
+    exp_bits: 8,
+    man_bits: 10,
+    bias: 127,
+    encoding: Encoding::Ieee,
+};
+
+/// Arm alternative half-precision
+pub(crate) const AHP: Format = Format {
+    exp_bits: 5,
+    man_bits: 10,
+    bias: 15,
+    encoding: Encoding::Alternative,
+};
+
+/// IBM DLFloat16
+pub(crate) const DLF16: Format = Format {
+    exp_bits: 6,
+    man_bits: 9,
+    bias: 31,
+    encoding: Encoding::DlFloat,
+};
+
+/// OCP `E4M3` (`E4M3FN`)
+pub(crate) const E4M3: Format = Format {
+    exp_bits: 4,
+    man_bits: 3,
+    bias: 7,
+    encoding: Encoding::Fn,
+};
+
+/// OCP `E5M2`
+pub(crate) const E5M2: Format = Format {
+    exp_bits: 5,
+    man_bits: 2,
+    bias: 15,
+    encoding: Encoding::Ieee,
+};
+
+/// `E4M3FNUZ`
+pub(crate) const E4M3FNUZ: Format = Format {
+    exp_bits: 4,
+    man_bits: 3,
+    bias: 8,
+    encoding: Encoding::Fnuz,
+};
+
+/// `E5M2FNUZ`
+pub(crate) const E5M2FNUZ: Format = Format {
+    exp_bits: 5,
+    man_bits: 2,
+    bias: 16,
+    encoding: Encoding::Fnuz,
+};
+
+/// OCP `E2M3`, an element type of MXFP6
+pub(crate) const E2M3: Format = Format {
+    exp_bits: 2,
+    man_bits: 3,
+    bias: 1,
+    encoding: Encoding::Alternative,
+};
+
+/// OCP `E3M2`, an element type of MXFP6
+pub(crate) const E3M2: Format = Format {
+    exp_bits: 3,
+    man_bits: 2,
+    bias: 3,
+    encoding: Encoding::Alternative,
+};
+
+/// OCP `E2M1`, the element type of MXFP4
+pub(crate) const E2M1: Format = Format {
+    exp_bits: 2,
+    man_bits: 1,
+    bias: 1,
+    encoding: Encoding::Alternative,
+};
+
+impl Format {
+    /// Returns `true` if both describe the same format, which unlike `==` works in constants
+    #[inline]
+    pub(crate) const fn same(self, other: Format) -> bool {
+        self.exp_bits == other.exp_bits
+            && self.man_bits == other.man_bits
+            && self.bias == other.bias
+            && self.encoding as u8 == other.encoding as u8
+    }
+
+    /// Unbiased exponent of the smallest normal value
+    #[inline]
+    pub(crate) const fn emin(self) -> i32 {
+        1 - self.bias
+    }
+
+    #[inline]
+    pub(crate) const fn sign_mask(self) -> u32 {
+        1 << (self.exp_bits + self.man_bits)
+    }
+
+    #[inline]
+    pub(crate) const fn exp_mask(self) -> u32 {
+        ((1 << self.exp_bits) - 1) << self.man_bits
+    }
+
+    #[inline]
+    pub(crate) const fn man_mask(self) -> u32 {
+        (1 << self.man_bits) - 1
+    }
+
+    #[inline]
+    pub(crate) const fn quiet_bit(self) -> u32 {
+        1 << (self.man_bits - 1)
+    }
+
+    #[inline]
+    pub(crate) const fn default_nan(self) -> u32 {
+        self.exp_mask() | self.quiet_bit()
+    }
+
+    /// Magnitude of the largest finite value
+    #[inline]
+    pub(crate) const fn max(self) -> u32 {
+        match self.encoding {
+            Encoding::Ieee => self.exp_mask() - 1,
+            Encoding::Alternative | Encoding::Fnuz => self.exp_mask() | self.man_mask(),
+            Encoding::DlFloat | Encoding::Fn => (self.exp_mask() | self.man_mask()) - 1,
+        }
+    }
+
+    /// Unbiased exponent of the largest finite value
+    #[inline]
+    pub(crate) const fn emax(self) -> i32 {
+        (self.max() >> self.man_bits) as i32 - self.bias
+    }
+
+    /// The magnitude that ±∞ converts to: ∞ itself, the NaN of formats that use it in place of ∞,
+    /// or the largest finite value for formats without either
+    ///
+    /// For [`Encoding::Fnuz`] this is the sign bit, so adding a sign leaves it unchanged.
+    #[inline]
+    pub(crate) const fn infinity(self) -> u32 {
+        match self.encoding {
+            Encoding::Ieee => self.exp_mask(),
+            Encoding::Alternative => self.max(),
+            _ => self.max() + 1,
+        }
+    }
+
+    /// Zero with `sign`, which is always positive in formats without a negative zero
+    #[inline]
+    pub(crate) const fn zero(self, sign: bool) -> u32 {
+        match self.encoding {
+            Encoding::Fnuz => 0,
+            _ => (sign as u32) << (self.exp_bits + self.man_bits),
+        }
+    }
+
+    /// Negates `bits`, leaving the unsigned zero and NaN of [`Encoding::Fnuz`] alone
+    #[inline]
+    pub(crate) const fn neg(self, bits: u32) -> u32 {
+        match self.encoding {
+            Encoding::Fnuz if bits & !self.sign_mask() == 0 => bits,
+            _ => bits ^ self.sign_mask(),
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn is_nan(self, bits: u32) -> bool {
+        match self.encoding {
+            Encoding::Ieee => bits & !self.sign_mask() > self.exp_mask(),
+            Encoding::Alternative => false,
+            Encoding::DlFloat | Encoding::Fn => {
+                bits & !self.sign_mask() == self.exp_mask() | self.man_mask()
+            }
+            Encoding::Fnuz => bits == self.sign_mask(),
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn is_infinite(self, bits: u32) -> bool {
+        match self.encoding {
+            Encoding::Ieee => bits & !self.sign_mask() == self.exp_mask(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` for subnormal values, which only exist in formats with an IEEE 754 style
+    /// zero exponent
+    #[inline]
+    pub(crate) const fn is_subnormal(self, bits: u32) -> bool {
+        match self.encoding {
+            Encoding::DlFloat => false,
+            _ => bits & self.exp_mask() == 0 && bits & self.man_mask() != 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn is_signaling(self, bits: u32) -> bool {
+        match self.encoding {
+            Encoding::Ieee => self.is_nan(bits) && bits & self.quiet_bit() == 0,
+            _ => false,
+        }
+    }
+}
+
+/// An unpacked operand
+///
+/// Finite nonzero values are `m * 2^e` with `m` normalized so its most significant bit is bit 31.
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Zero,
+    Finite(u64, i32),
+    Inf,
+    Nan,
+}
+
+#[inline]
+fn unpack(fmt: Format, bits: u32) -> (bool, Kind) {
+    let sign = bits & fmt.sign_mask() != 0;
+    let exp = (bits & fmt.exp_mask()) >> fmt.man_bits;
+    let man = bits & fmt.man_mask();
+
+    let kind = if fmt.is_nan(bits) {
+        Kind::Nan
+    } else if fmt.is_infinite(bits) {
+        Kind::Inf
+    } else if exp == 0 && (man == 0 || fmt.encoding != Encoding::DlFloat) {
+        if man == 0 {
+            Kind::Zero
+        } else {
+            normalized(man as u64, fmt.emin() - fmt.man_bits as i32)
+        }
+    } else {
+        let m = (man | (1 << fmt.man_bits)) as u64;
+        normalized(m, exp as i32 - fmt.bias - fmt.man_bits as i32)
+    };
+    (sign, kind)
+}
+
+#[inline]
+fn normalized(m: u64, e: i32) -> Kind {
+    let shift = m.leading_zeros() - 32;
+    Kind::Finite(m << shift, e - shift as i32)
+}
+
+#[inline]
+fn sign_bit(fmt: Format, sign: bool) -> u32 {
+    if sign {
+        fmt.sign_mask()
+    } else {
+        0
+    }
+}
+
+/// Rounds the value `(-1)^sign * m * 2^e` to a representable value using `rounding`
+///
+/// `m` must be nonzero. Any inexact low-order bits below `m` must have been OR-ed into the least
+/// significant bit of `m` (a sticky bit), which requires `m` to carry at least two more bits of
+/// precision than the format.
+pub(crate) fn round_pack<R: Round>(
+    fmt: Format,
+    rounding: R,
+    sign: bool,
+    m: u64,
+    e: i32,
+    status: &mut Status,
+) -> u32 {
+    debug_assert!(m != 0);
+    let sign_bits = sign_bit(fmt, sign);
+    let dlfloat = fmt.encoding == Encoding::DlFloat;
+
+    // Normalize so that value = m * 2^e with m in [2^63, 2^64), i.e. value in [2^exp, 2^(exp+1))
+    let lz = m.leading_zeros();
+    let m = m << lz;
+    let exp = e.saturating_add(63 - lz as i32);
+
+    // Overflow before rounding
+    if exp > fmt.emax() {
+        return overflow(fmt, rounding, sign, status);
+    }
+    // Without subnormals, anything below the smallest normal after rounding flushes to zero
+    if dlfloat && exp < -fmt.bias {
+        status.underflow = true;
+        status.inexact = true;
+        return sign_bits;
+    }
+
+    // Number of low bits of m that are below the target precision. Subnormal results lose one
+    // additional bit for every binade below the normal range. Anything past 65 bits is less than
+    // half of the smallest subnormal and rounds the same way.
+    let precision = fmt.man_bits + 1;
+    let shift = if exp >= fmt.emin() || dlfloat {
+        64 - precision
+    } else {
+        (64 - precision)
+            .saturating_add((fmt.emin() - exp) as u32)
+            .min(65)
+    };
+    let m = m as u128;
+    let r = (m >> shift) as u32;
+    let rem = m & ((1u128 << shift) - 1);
+    if rem != 0 {
+        status.inexact = true;
+        status.underflow |= exp < fmt.emin() && !dlfloat;
+    }
+
+    // The hidden bit of a normal significand adds one to the biased exponent, and rounding up to
+    // the next power of two carries into the exponent naturally. Subnormal results use a biased
+    // exponent of zero, and rounding up to the smallest normal carries correctly as well. Ties
+    // are broken on the encoding rather than the significand, which only differs for formats
+    // without fraction bits.
+    let mut bits = if dlfloat {
+        (((exp + fmt.bias) as u32) << fmt.man_bits) + r - (1 << fmt.man_bits)
+    } else if exp >= fmt.emin() {
+        (((exp + fmt.bias - 1) as u32) << fmt.man_bits) + r
+    } else {
+        r
+    };
+    if rounding.increment(sign, bits as u128, rem, shift) {
+        bits += 1;
+    } else if rounding.jam() && rem != 0 {
+        bits |= 1;
+    }
+
+    if bits > fmt.max() {
+        overflow(fmt, rounding, sign, status)
+    } else if bits == 0 {
+        if dlfloat {
+            // Rounded to the one normal significand whose pattern is taken by zero
+            status.underflow = true;
+            status.inexact = true;
+        }
+        fmt.zero(sign)
+    } else {
+        sign_bits | bits
+    }
+}
+
+/// Returns the result of a value that is too large for `fmt`, raising the matching exceptions
+fn overflow<R: Round>(fmt: Format, rounding: R, sign: bool, status: &mut Status) -> u32 {
+    let sign_bits = sign_bit(fmt, sign);
+    if fmt.encoding == Encoding::Alternative {
+        // As on Arm, which saturates and signals an invalid operation instead of an overflow
+        status.invalid = true;
+        return sign_bits | fmt.max();
+    }
+    status.overflow = true;
+    status.inexact = true;
+    if rounding.overflows_to_infinity(sign) {
+        sign_bits | fmt.infinity()
+    } else {
+        sign_bits | fmt.max()
+    }
+}
+
+/// Converts ±∞, or a quiet NaN without payload if `nan` is set, into `fmt`
+///
+/// Formats without ±∞ or NaN saturate ±∞ to the largest finite value and convert NaN to zero,
+/// which are both invalid.
+pub(crate) fn special(fmt: Format, sign: bool, nan: bool, status: &mut Status) -> u32 {
+    let sign_bits = sign_bit(fmt, sign);
+    match fmt.encoding {
+        Encoding::Ieee if nan => sign_bits | fmt.default_nan(),
+        Encoding::Alternative if nan => {
+            status.invalid = true;
+            sign_bits
+        }
+        Encoding::Alternative => {
+            status.invalid = true;
+            sign_bits | fmt.max()
+        }
+        _ => sign_bits | fmt.infinity(),
+    }
+}
+
+/// Converts the bits of a wider IEEE 754 binary format into `fmt`
+///
+/// NaN values keep the sign and the most significant bits of their payload, and are always made
+/// quiet. Converting a signaling NaN is invalid.
+fn from_ieee<R: Round>(
+    fmt: Format,
+    rounding: R,
+    bits: u64,
+    exp_bits: u32,
+    man_bits: u32,
+    status: &mut Status,
+) -> u32 {
+    let sign = (bits >> (exp_bits + man_bits)) & 1 != 0;
+    let exp = ((bits >> man_bits) & ((1 << exp_bits) - 1)) as i32;
+    let man = bits & ((1 << man_bits) - 1);
+    let bias = (1 << (exp_bits - 1)) - 1;
+
+    if exp == (1 << exp_bits) - 1 {
+        if man == 0 {
+            return special(fmt, sign, false, status);
+        }
+        status.invalid |= man & (1 << (man_bits - 1)) == 0;
+        return if fmt.encoding == Encoding::Ieee {
+            let payload = (man >> (man_bits - fmt.man_bits)) as u32;
+            sign_bit(fmt, sign) | fmt.exp_mask() | fmt.quiet_bit() | payload
+        } else {
+            special(fmt, sign, true, status)
+        };
+    }
+    if exp == 0 && man == 0 {
+        return fmt.zero(sign);
+    }
+    let (m, e) = if exp == 0 {
+        (man, 1 - bias - man_bits as i32)
+    } else {
+        (man | (1 << man_bits), exp - bias - man_bits as i32)
+    };
+    round_pack(fmt, rounding, sign, m, e, status)
+}
+
+/// Converts an `f32` into `fmt`, rounding once
+#[inline]
+pub(crate) fn from_f32<R: Round>(fmt: Format, value: f32, rounding: R) -> u32 {
+    from_f32_status(fmt, value, rounding, &mut Status::default())
+}
+
+/// Converts an `f32` into `fmt`, rounding once and raising exceptions in `status`
+#[inline]
+pub(crate) fn from_f32_status<R: Round>(
+    fmt: Format,
+    value: f32,
+    rounding: R,
+    status: &mut Status,
+) -> u32 {
+    from_ieee(fmt, rounding, value.to_bits() as u64, 8, 23, status)
+}
+
+/// Converts an `f64` into `fmt`, rounding once
+#[inline]
+pub(crate) fn from_f64<R: Round>(fmt: Format, value: f64, rounding: R) -> u32 {
+    from_f64_status(fmt, value, rounding, &mut Status::default())
+}
+
+/// Converts an `f64` into `fmt`, rounding once and raising exceptions in `status`
+#[inline]
+pub(crate) fn from_f64_status<R: Round>(
+    fmt: Format,
+    value: f64,
+    rounding: R,
+    status: &mut Status,
+) -> u32 {
+    from_ieee(fmt, rounding, value.to_bits(), 11, 52, status)
+}
+
+/// Rounds the bits of an `f32` to the nearest value whose low `discarded` fraction bits are zero,
+/// ties to even, for the formats that keep the `f32` exponent such as bf16 and TF32
+///
+/// This is branch-free so that loops over slices vectorize. Carries out of the fraction
+/// increment the exponent, which turns values beyond the largest value of the narrower format
+/// into ±∞, and NaN is made quiet before truncating so that payloads only in the discarded bits
+/// remain NaN.
+#[inline]
+pub(crate) fn round_f32_bits_nearest(x: u32, discarded: u32) -> u32 {
+    let nan = x & 0x7FFF_FFFF > 0x7F80_0000;
+    let half = (1 << (discarded - 1)) - 1;
+    let rounded = x.wrapping_add(half + ((x >> discarded) & 1));
+    let bits = if nan { x | 0x0040_0000 } else { rounded };
+    bits & !((1 << discarded) - 1)
+}
+
+/// Converts `bits` of `fmt` exactly into an `f32`
+///
+/// NaN values keep their sign, if the format gives them one, and for IEEE 754 formats the payload,
+/// and are made quiet. The
+/// values of every supported format are within the range of `f32`.
+pub(crate) fn to_f32(fmt: Format, bits: u32) -> f32 {
+    let (sign, kind) = unpack(fmt, bits);
+    let sign = (sign as u32) << 31;
+    match kind {
+        Kind::Zero => f32::from_bits(sign),
+        Kind::Inf => f32::from_bits(sign | 0x7F80_0000),
+        Kind::Nan => {
+            let (sign, payload) = match fmt.encoding {
+                Encoding::Ieee => (sign, (bits & fmt.man_mask()) << (23 - fmt.man_bits)),
+                Encoding::Fnuz => (0, 0),
+                _ => (sign, 0),
+            };
+            f32::from_bits(sign | 0x7FC0_0000 | payload)
+        }
+        Kind::Finite(m, e) => {
+            // m has at most 12 significant bits, so the product is exact
+            let scale = f64::from_bits(((e + 1023) as u64) << 52);
+            let v = (m as f64 * scale) as f32;
+            f32::from_bits(sign | v.to_bits())
+        }
+    }
+}
+
+/// Converts `bits` of `fmt` exactly into an `f64`
+///
+/// NaN values are converted like [`to_f32`] does.
+pub(crate) fn to_f64(fmt: Format, bits: u32) -> f64 {
+    let (sign, kind) = unpack(fmt, bits);
+    let sign = (sign as u64) << 63;
+    match kind {
+        Kind::Zero => f64::from_bits(sign),
+        Kind::Inf => f64::from_bits(sign | 0x7FF0_0000_0000_0000),
+        Kind::Nan => {
+            let (sign, payload) = match fmt.encoding {
+                Encoding::Ieee => (
+                    sign,
+                    ((bits & fmt.man_mask()) as u64) << (52 - fmt.man_bits),
+                ),
+                Encoding::Fnuz => (0, 0),
+                _ => (sign, 0),
+            };
+            f64::from_bits(sign | 0x7FF8_0000_0000_0000 | payload)
+        }
+        Kind::Finite(m, e) => {
+            let scale = f64::from_bits(((e + 1023) as u64) << 52);
+            f64::from_bits(sign | (m as f64 * scale).to_bits())
+        }
+    }
+}
+
+/// Propagates the first NaN operand, which is invalid if either operand is a signaling NaN
+#[inline]
+fn propagate_nan(fmt: Format, a: u32, b: u32, status: &mut Status) -> u32 {
+    status.invalid |= fmt.is_signaling(a) || fmt.is_signaling(b);
+    if fmt.is_nan(a) {
+        a | fmt.quiet_bit()
+    } else {
+        b | fmt.quiet_bit()
+    }
+}
+
+/// Returns the default NaN for an invalid operation
+#[inline]
+fn invalid(fmt: Format, status: &mut Status) -> u32 {
+    status.invalid = true;
+    fmt.default_nan()
+}
+
+/// Adds two exact finite nonzero terms `mx * 2^ex` and `my * 2^ey` and rounds the sum
+#[allow(clippy::too_many_arguments)]
+fn add_terms(
+    fmt: Format,
+    sx: bool,
+    mx: u64,
+    ex: i32,
+    sy: bool,
+    my: u64,
+    ey: i32,
+    status: &mut Status,
+) -> u32 {
+    // Place both terms with their most significant bit at bit 125, leaving room for a carry and
+    // plenty of guard bits below the target precision.
+    let lx = mx.leading_zeros();
+    let ly = my.leading_zeros();
+    let (mut x, mut ex) = (((mx << lx) as u128) << 62, ex - lx as i32 - 62);
+    let (mut y, mut ey) = (((my << ly) as u128) << 62, ey - ly as i32 - 62);
+    let (mut sx, mut sy) = (sx, sy);
+    if ex < ey {
+        core::mem::swap(&mut x, &mut y);
+        core::mem::swap(&mut ex, &mut ey);
+        core::mem::swap(&mut sx, &mut sy);
+    }
+
+    // Align the smaller term, collapsing everything shifted out into a sticky bit
+    let d = ex.wrapping_sub(ey) as u32;
+    let y = if d == 0 {
+        y
+    } else if d >= 127 {
+        1
+    } else {
+        (y >> d) | ((y & ((1u128 << d) - 1)) != 0) as u128
+    };
+
+    let (sign, m) = if sx == sy {
+        (sx, x + y)
+    } else if x >= y {
+        (sx, x - y)
+    } else {
+        (sy, y - x)
+    };
+
+    // Exact cancellation always produces +0 when rounding to nearest
+    if m == 0 {
+        return 0;
+    }
+
+    // Narrow to 64 bits for rounding, keeping a sticky bit
+    let lz = m.leading_zeros();
+    let m = m << lz;
+    let hi = (m >> 64) as u64 | (m as u64 != 0) as u64;
+    round_pack(fmt, NEAREST, sign, hi, ex - lz as i32 + 64, status)
+}
+
+/// Computes `a + b`, rounded once
+pub(crate) fn add(fmt: Format, a: u32, b: u32, status: &mut Status) -> u32 {
+    let (sa, ka) = unpack(fmt, a);
+    let (sb, kb) = unpack(fmt, b);
+    match (ka, kb) {
+        (Kind::Nan, _) | (_, Kind::Nan) => propagate_nan(fmt, a, b, status),
+        (Kind::Inf, Kind::Inf) if sa != sb => invalid(fmt, status),
+        (Kind::Inf, _) => a,
+        (_, Kind::Inf) => b,
+        (Kind::Zero, Kind::Zero) => sign_bit(fmt, sa && sb),
+        (Kind::Zero, _) => b,
+        (_, Kind::Zero) => a,
+        (Kind::Finite(ma, ea), Kind::Finite(mb, eb)) => {
+            add_terms(fmt, sa, ma, ea, sb, mb, eb, status)
+        }
+    }
+}
+
+/// Computes `a - b`, rounded once
+pub(crate) fn sub(fmt: Format, a: u32, b: u32, status: &mut Status) -> u32 {
+    // Negating a NaN operand would change the propagated payload
+    if fmt.is_nan(b) {
+        propagate_nan(fmt, a, b, status)
+    } else {
+        add(fmt, a, b ^ fmt.sign_mask(), status)
+    }
+}
+
+/// Computes `a * b`, rounded once
+pub(crate) fn mul(fmt: Format, a: u32, b: u32, status: &mut Status) -> u32 {
+    let (sa, ka) = unpack(fmt, a);
+    let (sb, kb) = unpack(fmt, b);
+    let sign = sign_bit(fmt, sa != sb);
+    match (ka, kb) {
+        (Kind::Nan, _) | (_, Kind::Nan) => propagate_nan(fmt, a, b, status),
+        (Kind::Inf, Kind::Zero) | (Kind::Zero, Kind::Inf) => invalid(fmt, status),
+        (Kind::Inf, _) | (_, Kind::Inf) => sign | fmt.exp_mask(),
+        (Kind::Zero, _) | (_, Kind::Zero) => sign,
+        // Both significands are below 2^32, so the product is exact in 64 bits
+        (Kind::Finite(ma, ea), Kind::Finite(mb, eb)) => {
+            round_pack(fmt, NEAREST, sa != sb, ma * mb, ea + eb, status)
+        }
+    }
+}
+
+/// Computes `a / b`, rounded once
+pub(crate) fn div(fmt: Format, a: u32, b: u32, status: &mut Status) -> u32 {
+    let (sa, ka) = unpack(fmt, a);
+    let (sb, kb) = unpack(fmt, b);
+    let sign = sign_bit(fmt, sa != sb);
+    match (ka, kb) {
+        (Kind::Nan, _) | (_, Kind::Nan) => propagate_nan(fmt, a, b, status),
+        (Kind::Inf, Kind::Inf) | (Kind::Zero, Kind::Zero) => invalid(fmt, status),
+        (Kind::Finite(..), Kind::Zero) => {
+            status.division_by_zero = true;
+            sign | fmt.exp_mask()
+        }
+        (Kind::Inf, _) => sign | fmt.exp_mask(),
+        (Kind::Zero, _) | (_, Kind::Inf) => sign,
+        (Kind::Finite(ma, ea), Kind::Finite(mb, eb)) => {
+            // Both significands are in [2^31, 2^32), so the quotient has at least 32 bits
+            let n = ma << 32;
+            let q = n / mb;
+            let sticky = (n % mb != 0) as u64;
+            round_pack(
+                fmt,
+                NEAREST,
+                sa != sb,
+                (q << 1) | sticky,
+                ea - eb - 33,
+                status,
+            )
+        }
+    }
+}
+
+/// Computes the remainder of `a / b` truncated towards zero, like the `%` operator on primitive
+/// floats
+///
+/// The result is always exactly representable, so no rounding takes place.
+pub(crate) fn rem(fmt: Format, a: u32, b: u32, status: &mut Status) -> u32 {
+    let (sa, ka) = unpack(fmt, a);
+    let (_, kb) = unpack(fmt, b);
+    match (ka, kb) {
+        (Kind::Nan, _) | (_, Kind::Nan) => propagate_nan(fmt, a, b, status),
+        (Kind::Inf, _) | (_, Kind::Zero) => invalid(fmt, status),
+        (Kind::Zero, _) | (_, Kind::Inf) => a,
+        (Kind::Finite(ma, ea), Kind::Finite(mb, eb)) => {
+            // Significands share the same normalization, so a smaller exponent means |a| < |b|
+            if ea < eb {
+                return a;
+            }
+            let mut r = ma % mb;
+            for _ in 0..(ea - eb) {
+                r = (r << 1) % mb;
+            }
+            if r == 0 {
+                sign_bit(fmt, sa)
+            } else {
+                round_pack(fmt, NEAREST, sa, r, eb, status)
+            }
+        }
+    }
+}
+
+/// Integer square root of `n`, returning the root and whether it was inexact
+fn isqrt(n: u128) -> (u128, bool) {
+    let mut rem = n;
+    let mut root = 0u128;
+    let mut bit = 1u128 << ((127 - n.leading_zeros()) & !1);
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    (root, rem != 0)
+}
+
+/// Computes the square root of `a`, rounded once
+pub(crate) fn sqrt(fmt: Format, a: u32, status: &mut Status) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, status),
+        (_, Kind::Zero) => a,
+        (true, _) => invalid(fmt, status),
+        (false, Kind::Inf) => a,
+        (false, Kind::Finite(m, e)) => {
+            // Make the exponent even so it can be halved exactly
+            let (m, e) = if e & 1 != 0 { (m << 1, e - 1) } else { (m, e) };
+            let (root, inexact) = isqrt((m as u128) << 64);
+            let root = ((root as u64) << 1) | inexact as u64;
+            round_pack(fmt, NEAREST, false, root, (e - 64) / 2 - 1, status)
+        }
+    }
+}
+
+/// Computes `a * b + c`, rounded once
+pub(crate) fn mul_add(fmt: Format, a: u32, b: u32, c: u32, status: &mut Status) -> u32 {
+    let (sa, ka) = unpack(fmt, a);
+    let (sb, kb) = unpack(fmt, b);
+    let (sc, kc) = unpack(fmt, c);
+    let sp = sa != sb;
+    status.invalid |= fmt.is_signaling(c);
+
+    match (ka, kb, kc) {
+        (Kind::Nan, _, _) | (_, Kind::Nan, _) => propagate_nan(fmt, a, b, status),
+        (Kind::Inf, Kind::Zero, _) | (Kind::Zero, Kind::Inf, _) => invalid(fmt, status),
+        (_, _, Kind::Nan) => c | fmt.quiet_bit(),
+        (Kind::Inf, _, _) | (_, Kind::Inf, _) => match kc {
+            Kind::Inf if sc != sp => invalid(fmt, status),
+            _ => sign_bit(fmt, sp) | fmt.exp_mask(),
+        },
+        (_, _, Kind::Inf) => c,
+        (Kind::Zero, _, Kind::Zero) | (_, Kind::Zero, Kind::Zero) => sign_bit(fmt, sp && sc),
+        (Kind::Zero, _, _) | (_, Kind::Zero, _) => c,
+        (Kind::Finite(ma, ea), Kind::Finite(mb, eb), Kind::Zero) => {
+            round_pack(fmt, NEAREST, sp, ma * mb, ea + eb, status)
+        }
+        (Kind::Finite(ma, ea), Kind::Finite(mb, eb), Kind::Finite(mc, ec)) => {
+            add_terms(fmt, sp, ma * mb, ea + eb, sc, mc, ec, status)
+        }
+    }
+}
+
+/// Rounds `a` to an integral value in the same format, without raising any exception
+///
+/// Only meaningful for formats with IEEE encoding.
+pub(crate) const fn round_to_integral(fmt: Format, a: u32, rounding: RoundingMode) -> u32 {
+    let sign = a & fmt.sign_mask();
+    let mag = a & !fmt.sign_mask();
+    if mag > fmt.exp_mask() {
+        return a | fmt.quiet_bit();
+    }
+    let e = (mag >> fmt.man_bits) as i32 - fmt.bias;
+    if e >= fmt.man_bits as i32 || mag == 0 {
+        // Already integral, or ±∞
+        return a;
+    }
+    let one = (fmt.bias as u32) << fmt.man_bits;
+    if e < 0 {
+        // 0 < |a| < 1 rounds to ±0 or ±1
+        let half = one - (1 << fmt.man_bits);
+        let up = match rounding {
+            RoundingMode::NearestTiesToEven => mag > half,
+            RoundingMode::NearestTiesToAway => mag >= half,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => sign == 0,
+            RoundingMode::TowardNegative => sign != 0,
+            RoundingMode::ToOdd => true,
+        };
+        return if up { sign | one } else { sign };
+    }
+    let frac = fmt.man_mask() >> e;
+    if mag & frac == 0 {
+        return a;
+    }
+    let unit = frac + 1;
+    // The bias is odd, so the lowest exponent bit doubles as the implicit integer bit when e == 0
+    let odd = mag & unit != 0;
+    let truncated = mag & !frac;
+    let up = match rounding {
+        RoundingMode::NearestTiesToEven => {
+            let rest = mag & frac;
+            rest > unit >> 1 || (rest == unit >> 1 && odd)
+        }
+        RoundingMode::NearestTiesToAway => mag & (unit >> 1) != 0,
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => sign == 0,
+        RoundingMode::TowardNegative => sign != 0,
+        RoundingMode::ToOdd => !odd,
+    };
+    sign | if up { truncated + unit } else { truncated }
+}
+
+/// Computes `a * 2^n`, rounded once
+pub(crate) fn scalbn(fmt: Format, a: u32, n: i32, status: &mut Status) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, status),
+        (_, Kind::Zero) | (_, Kind::Inf) => a,
+        (sign, Kind::Finite(m, e)) => {
+            // Any larger scale overflows or underflows all the same
+            let n = n.clamp(-0x1_0000, 0x1_0000);
+            round_pack(fmt, NEAREST, sign, m, e + n, status)
+        }
+    }
+}
+
+/// Returns the IEEE 754 class of `a`
+pub(crate) const fn class(fmt: Format, a: u32) -> FpClass {
+    let negative = a & fmt.sign_mask() != 0;
+    if fmt.is_nan(a) {
+        if fmt.is_signaling(a) {
+            FpClass::SignalingNaN
+        } else {
+            FpClass::QuietNaN
+        }
+    } else if fmt.is_infinite(a) {
+        if negative {
+            FpClass::NegativeInfinity
+        } else {
+            FpClass::PositiveInfinity
+        }
+    } else if a & !fmt.sign_mask() == 0 {
+        if negative {
+            FpClass::NegativeZero
+        } else {
+            FpClass::PositiveZero
+        }
+    } else if fmt.is_subnormal(a) {
+        if negative {
+            FpClass::NegativeSubnormal
+        } else {
+            FpClass::PositiveSubnormal
+        }
+    } else if negative {
+        FpClass::NegativeNormal
+    } else {
+        FpClass::PositiveNormal
+    }
+}
+
+/// Maps `a` to an integer that sorts in the IEEE 754 total order: negative NaNs, −∞, negative
+/// numbers, `-0.0`, `+0.0`, positive numbers, +∞ and positive NaNs, with signaling NaNs closer to
+/// the numbers than quiet NaNs of the same sign
+#[inline]
+pub(crate) const fn total_order_key(fmt: Format, a: u32) -> i32 {
+    let mag = (a & !fmt.sign_mask()) as i32;
+    if a & fmt.sign_mask() != 0 {
+        -1 - mag
+    } else {
+        mag
+    }
+}
+
+/// Returns the number of representable values between `a` and `b`, counting `-0.0` and `+0.0`
+/// as one value
+///
+/// Two NaNs are zero apart, and a NaN is `u32::MAX` apart from any other value.
+#[inline]
+pub(crate) const fn ulp_distance(fmt: Format, a: u32, b: u32) -> u32 {
+    match (fmt.is_nan(a), fmt.is_nan(b)) {
+        (true, true) => 0,
+        (false, false) => (signed_magnitude(fmt, a) - signed_magnitude(fmt, b)).unsigned_abs(),
+        _ => u32::MAX,
+    }
+}
+
+/// Returns the magnitude bits of `a` negated if its sign is set, which orders values by their bits
+#[inline]
+pub(crate) const fn signed_magnitude(fmt: Format, a: u32) -> i32 {
+    let mag = (a & !fmt.sign_mask()) as i32;
+    if a & fmt.sign_mask() != 0 {
+        -mag
+    } else {
+        mag
+    }
+}
+
+/// Returns the smaller of `a` and `b`, or the larger if `max` is set, with `-0.0` less than `+0.0`
+///
+/// With `number_wins`, a NaN operand is ignored unless both are NaN. Otherwise any NaN operand
+/// makes the result a quiet NaN.
+#[inline]
+const fn min_max(fmt: Format, a: u32, b: u32, number_wins: bool, max: bool) -> u32 {
+    let a_nan = fmt.is_nan(a);
+    let b_nan = fmt.is_nan(b);
+    if a_nan || b_nan {
+        if number_wins && !(a_nan && b_nan) {
+            if a_nan {
+                b
+            } else {
+                a
+            }
+        } else if a_nan {
+            a | fmt.quiet_bit()
+        } else {
+            b | fmt.quiet_bit()
+        }
+    } else if (total_order_key(fmt, a) < total_order_key(fmt, b)) != max {
+        a
+    } else {
+        b
+    }
+}
+
+/// IEEE 754-2019 `minimum`: NaN if either operand is NaN, and `-0.0` is less than `+0.0`
+#[inline]
+pub(crate) const fn minimum(fmt: Format, a: u32, b: u32) -> u32 {
+    min_max(fmt, a, b, false, false)
+}
+
+/// IEEE 754-2019 `maximum`: NaN if either operand is NaN, and `+0.0` is greater than `-0.0`
+#[inline]
+pub(crate) const fn maximum(fmt: Format, a: u32, b: u32) -> u32 {
+    min_max(fmt, a, b, false, true)
+}
+
+/// IEEE 754-2019 `minimumNumber`: the number if only one operand is NaN
+#[inline]
+pub(crate) const fn minimum_number(fmt: Format, a: u32, b: u32) -> u32 {
+    min_max(fmt, a, b, true, false)
+}
+
+/// IEEE 754-2019 `maximumNumber`: the number if only one operand is NaN
+#[inline]
+pub(crate) const fn maximum_number(fmt: Format, a: u32, b: u32) -> u32 {
+    min_max(fmt, a, b, true, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    // Shorthands for the operations that ignore exception flags
+    fn add(fmt: Format, a: u32, b: u32) -> u32 {
+        super::add(fmt, a, b, &mut Status::default())
+    }
+
+    fn sub(fmt: Format, a: u32, b: u32) -> u32 {
+        super::sub(fmt, a, b, &mut Status::default())
+    }
+
+    fn mul(fmt: Format, a: u32, b: u32) -> u32 {
+        super::mul(fmt, a, b, &mut Status::default())
+    }
+
+    fn div(fmt: Format, a: u32, b: u32) -> u32 {
+        super::div(fmt, a, b, &mut Status::default())
+    }
+
+    fn rem(fmt: Format, a: u32, b: u32) -> u32 {
+        super::rem(fmt, a, b, &mut Status::default())
+    }
+
+    fn sqrt(fmt: Format, a: u32) -> u32 {
+        super::sqrt(fmt, a, &mut Status::default())
+    }
+
+    fn mul_add(fmt: Format, a: u32, b: u32, c: u32) -> u32 {
+        super::mul_add(fmt, a, b, c, &mut Status::default())
+    }
+
+    /// Decodes bits exactly into an `f64`
+    fn to_f64(fmt: Format, bits: u32) -> f64 {
+        let (sign, kind) = unpack(fmt, bits);
+        let v = match kind {
+            Kind::Zero => 0.0,
+            Kind::Inf => f64::INFINITY,
+            Kind::Nan => f64::NAN,
+            Kind::Finite(m, e) => m as f64 * 2f64.powi(e),
+        };
+        if sign {
+            -v
+        } else {
+            v
+        }
+    }
+
+    /// Rounds `x` to the nearest value of `fmt` by searching the ordered bit patterns. `tie`
+    /// breaks exact midpoints for a value that is known to lie slightly above (`Greater`) or below
+    /// (`Less`) `x`.
+    fn nearest(fmt: Format, x: f64, tie: core::cmp::Ordering) -> u32 {
+        use core::cmp::Ordering;
+        if x.is_nan() {
+            return fmt.default_nan();
+        }
+        let sign = sign_bit(fmt, x.is_sign_negative());
+        let ax = x.abs();
+        // Binary search for the largest finite pattern <= ax
+        let (mut lo, mut hi) = (0u32, fmt.exp_mask() - 1);
+        while lo < hi {
+            // `u32::div_ceil` is newer than the minimum supported Rust version
+            #[allow(clippy::manual_div_ceil)]
+            let mid = (lo + hi + 1) / 2;
+            if to_f64(fmt, mid) <= ax {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        let below = lo;
+        let above = lo + 1;
+        let vb = to_f64(fmt, below);
+        if vb == ax {
+            return sign | below;
+        }
+        // The value one ulp above the largest finite value decides overflow
+        let va = if above == fmt.exp_mask() {
+            2.0 * to_f64(fmt, below) - to_f64(fmt, below - 1)
+        } else {
+            to_f64(fmt, above)
+        };
+        let mid = (vb + va) / 2.0;
+        let up = match ax.partial_cmp(&mid).unwrap() {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => match tie {
+                Ordering::Equal => below & 1 != 0,
+                // tie direction is relative to x, so mirror for negative values
+                Ordering::Greater => !x.is_sign_negative(),
+                Ordering::Less => x.is_sign_negative(),
+            },
+        };
+        sign | if up { above } else { below }
+    }
+
+    fn same(fmt: Format, a: u32, b: u32) -> bool {
+        if fmt.is_nan(a) {
+            fmt.is_nan(b)
+        } else {
+            a == b
+        }
+    }
+
+    // Computing in f64 and rounding once more is correctly rounded for +, -, *, / and sqrt because
+    // f64 carries more than twice the precision of either format plus two bits.
+    fn check_binary(fmt: Format, a: u16, b: u16) -> bool {
+        let (a, b) = (a as u32 & 0xFFFF, b as u32 & 0xFFFF);
+        let (x, y) = (to_f64(fmt, a), to_f64(fmt, b));
+        let eq = core::cmp::Ordering::Equal;
+        let mut ok = same(fmt, add(fmt, a, b), nearest(fmt, x + y, eq))
+            && same(fmt, sub(fmt, a, b), nearest(fmt, x - y, eq))
+            && same(fmt, mul(fmt, a, b), nearest(fmt, x * y, eq))
+            && same(fmt, div(fmt, a, b), nearest(fmt, x / y, eq))
+            && same(fmt, rem(fmt, a, b), nearest(fmt, x % y, eq));
+        // Signed zero results must also match
+        for &(r, v) in &[(add(fmt, a, b), x + y), (mul(fmt, a, b), x * y)] {
+            if v == 0.0 {
+                ok &= (r & fmt.sign_mask() != 0) == v.is_sign_negative();
+            }
+        }
+        ok
+    }
+
+    #[quickcheck]
+    fn qc_f16_binary_ops(a: u16, b: u16) -> bool {
+        check_binary(F16, a, b)
+    }
+
+    #[quickcheck]
+    fn qc_bf16_binary_ops(a: u16, b: u16) -> bool {
+        check_binary(BF16, a, b)
+    }
+
+    #[quickcheck]
+    fn qc_f16_binary_ops_close_exponents(a: u16, b: u16) -> bool {
+        // Force exponents near each other to exercise cancellation and carries
+        check_binary(F16, a, (b & 0x87FF) | (a & 0x7800))
+    }
+
+    #[quickcheck]
+    fn qc_bf16_binary_ops_close_exponents(a: u16, b: u16) -> bool {
+        check_binary(BF16, a, (b & 0x807F) | (a & 0x7F80))
+    }
+
+    #[test]
+    fn binary_ops_against_all_f16_values() {
+        // Every f16 value against a handful of operands, including subnormals and specials
+        for &b in &[
+            0x0001u32, 0x03FF, 0x0400, 0x3C00, 0x3C01, 0xC200, 0x7BFF, 0x7C00, 0x8000,
+        ] {
+            for a in 0..=0xFFFFu32 {
+                assert!(check_binary(F16, a as u16, b as u16), "{:04X} {:04X}", a, b);
+            }
+        }
+    }
+
+    fn check_sqrt(fmt: Format) {
+        for a in 0..=0xFFFFu32 {
+            let expected = nearest(fmt, to_f64(fmt, a).sqrt(), core::cmp::Ordering::Equal);
+            assert!(same(fmt, sqrt(fmt, a), expected), "sqrt {:04X}", a);
+        }
+        assert_eq!(sqrt(fmt, fmt.sign_mask()), fmt.sign_mask());
+    }
+
+    #[test]
+    fn exhaustive_sqrt() {
+        check_sqrt(F16);
+        check_sqrt(BF16);
+    }
+
+    fn check_round_to_integral(fmt: Format) {
+        for a in 0..=0xFFFFu32 {
+            let x = to_f64(fmt, a);
+            let ties_even = if (x - x.trunc()).abs() == 0.5 {
+                2.0 * (x / 2.0).round()
+            } else {
+                x.round()
+            };
+            let to_odd = if x.trunc() != x && x.trunc() % 2.0 == 0.0 {
+                x.trunc() + x.signum()
+            } else {
+                x.trunc()
+            };
+            for &(mode, r) in &[
+                (RoundingMode::NearestTiesToEven, ties_even),
+                (RoundingMode::NearestTiesToAway, x.round()),
+                (RoundingMode::TowardZero, x.trunc()),
+                (RoundingMode::TowardPositive, x.ceil()),
+                (RoundingMode::TowardNegative, x.floor()),
+                (RoundingMode::ToOdd, to_odd),
+            ] {
+                let expected = nearest(fmt, r, core::cmp::Ordering::Equal);
+                let actual = round_to_integral(fmt, a, mode);
+                assert!(same(fmt, actual, expected), "{:?} {:04X}", mode, a);
+                assert_eq!(actual & fmt.sign_mask(), a & fmt.sign_mask());
+            }
+        }
+    }
+
+    #[test]
+    fn exhaustive_round_to_integral() {
+        check_round_to_integral(F16);
+        check_round_to_integral(BF16);
+    }
+
+    fn check_mul_add(fmt: Format, a: u16, b: u16, c: u16) -> bool {
+        let (a, b, c) = (a as u32, b as u32, c as u32);
+        let (x, y, z) = (to_f64(fmt, a), to_f64(fmt, b), to_f64(fmt, c));
+        // The product is exact in f64, and the error of the sum is recovered exactly with TwoSum
+        // so it can break ties of the final rounding.
+        let p = x * y;
+        let s = p + z;
+        let expected = if s.is_finite() && p.is_finite() {
+            let pp = s - z;
+            let zz = s - pp;
+            let err = (p - pp) + (z - zz);
+            let tie = err.partial_cmp(&0.0).unwrap();
+            let r = nearest(fmt, s, tie);
+            if s == 0.0 && err == 0.0 {
+                // Exact zero sums keep IEEE sign rules
+                sign_bit(
+                    fmt,
+                    p == 0.0 && p.is_sign_negative() && z.is_sign_negative(),
+                )
+            } else {
+                r
+            }
+        } else {
+            nearest(fmt, s, core::cmp::Ordering::Equal)
+        };
+        same(fmt, mul_add(fmt, a, b, c), expected)
+    }
+
+    #[quickcheck]
+    fn qc_f16_mul_add(a: u16, b: u16, c: u16) -> bool {
+        check_mul_add(F16, a, b, c)
+    }
+
+    #[quickcheck]
+    fn qc_bf16_mul_add(a: u16, b: u16, c: u16) -> bool {
+        check_mul_add(BF16, a, b, c)
+    }
+
+    #[quickcheck]
+    fn qc_f16_mul_add_close_exponents(a: u16, b: u16, c: u16) -> bool {
+        // Keep the product and addend in the same range so rounding of the sum matters
+        let c = (c & 0x83FF) | (((a & 0x7C00) + (b & 0x7C00)).wrapping_sub(0x3C00) & 0x7C00);
+        check_mul_add(F16, a, b, c)
+    }
+
+    #[test]
+    fn mul_add_rounds_once() {
+        // (1 + 3 * 2^-10)^2 - 1 = 6 * 2^-10 + 9 * 2^-20, which the separately rounded product
+        // loses entirely
+        assert_eq!(mul_add(F16, 0x3C03, 0x3C03, 0xBC00), 0x1E02);
+        assert_eq!(add(F16, mul(F16, 0x3C03, 0x3C03), 0xBC00), 0x1E00);
+    }
+
+    const MODES: [RoundingMode; 6] = [
+        RoundingMode::NearestTiesToEven,
+        RoundingMode::NearestTiesToAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+        RoundingMode::ToOdd,
+    ];
+
+    /// Checks every rounding mode of a conversion against the two representable values that
+    /// bracket `x`
+    fn check_conversion(fmt: Format, x: f64, convert: impl Fn(RoundingMode) -> u32) -> bool {
+        if x.is_nan() {
+            return MODES.iter().all(|&mode| fmt.is_nan(convert(mode)));
+        }
+        let down = convert(RoundingMode::TowardNegative);
+        let up = convert(RoundingMode::TowardPositive);
+        let (vd, vu) = (to_f64(fmt, down), to_f64(fmt, up));
+        if vd == x {
+            return MODES.iter().all(|&mode| convert(mode) == down) && up == down;
+        }
+        if !(vd < x && x < vu) || (up != down + 1 && down != up + 1 && vd != 0.0 && vu != 0.0) {
+            return false;
+        }
+        let (small, large) = if x > 0.0 { (down, up) } else { (up, down) };
+        let mid = (vd + vu) / 2.0;
+        let away = if x.abs() == mid.abs() {
+            large
+        } else {
+            nearest(fmt, x, core::cmp::Ordering::Equal)
+        };
+        let odd = if down & 1 != 0 { down } else { up };
+        convert(RoundingMode::NearestTiesToEven) == nearest(fmt, x, core::cmp::Ordering::Equal)
+            && (vu.is_infinite() || convert(RoundingMode::NearestTiesToAway) == away)
+            && convert(RoundingMode::TowardZero) == small
+            && convert(RoundingMode::ToOdd) == odd
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_rounding_modes(bits: u32) -> bool {
+        let x = f32::from_bits(bits);
+        check_conversion(F16, x as f64, |mode| from_f32(F16, x, mode))
+            && check_conversion(BF16, x as f64, |mode| from_f32(BF16, x, mode))
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_rounding_modes_in_range(bits: u32) -> bool {
+        // Keep exponents within and just around the f16 range
+        let exp = 127 - 28 + (bits >> 23) % 48;
+        let x = f32::from_bits((bits & 0x807F_FFFF) | (exp << 23));
+        check_conversion(F16, x as f64, |mode| from_f32(F16, x, mode))
+    }
+
+    #[quickcheck]
+    fn qc_from_f64_rounding_modes(bits: u64) -> bool {
+        let x = f64::from_bits(bits);
+        check_conversion(F16, x, |mode| from_f64(F16, x, mode))
+            && check_conversion(BF16, x, |mode| from_f64(BF16, x, mode))
+    }
+
+    #[quickcheck]
+    fn qc_from_f64_rounding_modes_in_range(bits: u64) -> bool {
+        let exp = 1023 - 28 + (bits >> 52) % 48;
+        let x = f64::from_bits((bits & 0x800F_FFFF_FFFF_FFFF) | (exp << 52));
+        let exp = 1023 - 136 + (bits >> 52) % 270;
+        let y = f64::from_bits((bits & 0x800F_FFFF_FFFF_FFFF) | (exp << 52));
+        check_conversion(F16, x, |mode| from_f64(F16, x, mode))
+            && check_conversion(BF16, y, |mode| from_f64(BF16, y, mode))
+    }
+
+    #[test]
+    fn rounding_mode_ties_and_overflow() {
+        // 1 + 2^-11 is halfway between 1 and the next f16
+        let tie = 1.0 + 2f32.powi(-11);
+        assert_eq!(from_f32(F16, tie, RoundingMode::NearestTiesToEven), 0x3C00);
+        assert_eq!(from_f32(F16, tie, RoundingMode::NearestTiesToAway), 0x3C01);
+        assert_eq!(from_f32(F16, -tie, RoundingMode::NearestTiesToAway), 0xBC01);
+        assert_eq!(from_f32(F16, tie, RoundingMode::ToOdd), 0x3C01);
+        assert_eq!(from_f32(F16, 1e6, RoundingMode::NearestTiesToEven), 0x7C00);
+        assert_eq!(from_f32(F16, 1e6, RoundingMode::TowardZero), 0x7BFF);
+        assert_eq!(from_f32(F16, 1e6, RoundingMode::ToOdd), 0x7BFF);
+        assert_eq!(from_f32(F16, -1e6, RoundingMode::TowardPositive), 0xFBFF);
+        assert_eq!(from_f32(F16, -1e6, RoundingMode::TowardNegative), 0xFC00);
+        assert_eq!(from_f32(F16, 1e-10, RoundingMode::TowardPositive), 0x0001);
+        assert_eq!(from_f32(F16, -1e-10, RoundingMode::TowardPositive), 0x8000);
+        assert_eq!(from_f32(F16, 1e-10, RoundingMode::ToOdd), 0x0001);
+        assert_eq!(
+            from_f32(F16, f32::INFINITY, RoundingMode::TowardZero),
+            0x7C00
+        );
+    }
+
+    #[test]
+    fn round_to_odd_prevents_double_rounding() {
+        // Narrowing f64 -> f32 -> f16 to nearest can double round, but rounding to odd first
+        // gives the correctly rounded result.
+        let x = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+        let direct = from_f64(F16, x, RoundingMode::NearestTiesToEven);
+        assert_eq!(direct, 0x3C01);
+        assert_eq!(
+            from_f32(F16, x as f32, RoundingMode::NearestTiesToEven),
+            0x3C00
+        );
+        let odd = from_f64(
+            Format {
+                exp_bits: 8,
+                man_bits: 23,
+                bias: 127,
+                encoding: Encoding::Ieee,
+            },
+            x,
+            RoundingMode::ToOdd,
+        );
+        let odd = f32::from_bits(odd);
+        assert_eq!(from_f32(F16, odd, RoundingMode::NearestTiesToEven), direct);
+    }
+
+    #[test]
+    fn stochastic_rounding_probability() {
+        // Evenly spaced random values round up in exact proportion to the discarded fraction
+        for &(fmt, one) in &[(F16, 0x3C00u32), (BF16, 0x3F80)] {
+            let ulp = to_f64(fmt, one + 1) - 1.0;
+            for &(frac, expected) in &[(0.0, 0), (0.25, 64), (0.5, 128), (0.75, 192)] {
+                let x = (1.0 + ulp * frac) as f32;
+                let ups = (0..256u32)
+                    .filter(|i| from_f32(fmt, x, Stochastic(i << 24)) == one + 1)
+                    .count();
+                assert_eq!(ups, expected);
+                let x = -x;
+                let ups = (0..256u32)
+                    .filter(|i| {
+                        from_f32(fmt, x, Stochastic(i << 24)) == (one + 1) | fmt.sign_mask()
+                    })
+                    .count();
+                assert_eq!(ups, expected);
+            }
+        }
+        // Subnormal results and overflow
+        assert_eq!(from_f32(F16, 2f32.powi(-26), Stochastic(0)), 0x0001);
+        assert_eq!(from_f32(F16, 2f32.powi(-26), Stochastic(u32::MAX)), 0x0000);
+        assert_eq!(from_f32(F16, 65520.0, Stochastic(0)), 0x7C00);
+        assert_eq!(from_f32(F16, 65520.0, Stochastic(u32::MAX)), 0x7BFF);
+        assert!(F16.is_nan(from_f32(F16, f32::NAN, Stochastic(0))));
+    }
+
+    #[quickcheck]
+    fn qc_stochastic_rounds_to_neighbor(bits: u32, random: u32) -> bool {
+        let x = f32::from_bits(bits);
+        let r = from_f32(F16, x, Stochastic(random));
+        if x.is_nan() {
+            return F16.is_nan(r);
+        }
+        let down = from_f32(F16, x, RoundingMode::TowardZero);
+        r == down || to_f64(F16, r).abs() == to_f64(F16, down + 1).abs()
+    }
+
+    #[test]
+    fn special_values() {
+        let nan = F16.default_nan();
+        assert_eq!(add(F16, 0x7C00, 0xFC00), nan);
+        assert_eq!(mul(F16, 0x7C00, 0x0000), nan);
+        assert_eq!(div(F16, 0x0000, 0x8000), nan);
+        assert_eq!(div(F16, 0x3C00, 0x8000), 0xFC00);
+        assert_eq!(rem(F16, 0x7C00, 0x3C00), nan);
+        assert_eq!(sqrt(F16, 0xBC00), nan);
+        assert_eq!(mul_add(F16, 0x7C00, 0x3C00, 0xFC00), nan);
+        // NaN payloads are quieted and propagated from the first NaN operand
+        assert_eq!(add(F16, 0x7C01, 0x7E02), 0x7E01);
+        assert_eq!(sub(F16, 0x3C00, 0xFC01), 0xFE01);
+        // Signed zeros
+        assert_eq!(add(F16, 0x8000, 0x8000), 0x8000);
+        assert_eq!(add(F16, 0x8000, 0x0000), 0x0000);
+        assert_eq!(sub(F16, 0x3C00, 0x3C00), 0x0000);
+        assert_eq!(rem(F16, 0xC000, 0x3C00), 0x8000);
+    }
+
+    fn flags(f: impl FnOnce(&mut Status) -> u32) -> Status {
+        let mut status = Status::default();
+        f(&mut status);
+        status
+    }
+
+    #[test]
+    fn exception_flags() {
+        let exact = Status::default();
+        let inexact = Status {
+            inexact: true,
+            ..exact
+        };
+        let invalid = Status {
+            invalid: true,
+            ..exact
+        };
+        let overflow = Status {
+            overflow: true,
+            inexact: true,
+            ..exact
+        };
+        let underflow = Status {
+            underflow: true,
+            inexact: true,
+            ..exact
+        };
+        let mode = RoundingMode::NearestTiesToEven;
+
+        // Conversions
+        assert_eq!(flags(|s| from_f32_status(F16, 1.5, mode, s)), exact);
+        assert_eq!(flags(|s| from_f32_status(F16, 0.1, mode, s)), inexact);
+        assert_eq!(flags(|s| from_f32_status(F16, 65520.0, mode, s)), overflow);
+        assert_eq!(
+            flags(|s| from_f32_status(F16, 1e6, RoundingMode::TowardZero, s)),
+            overflow
+        );
+        assert_eq!(flags(|s| from_f32_status(F16, 1e-10, mode, s)), underflow);
+        // Exact subnormal results do not underflow
+        assert_eq!(
+            flags(|s| from_f32_status(F16, 2f32.powi(-24), mode, s)),
+            exact
+        );
+        assert_eq!(
+            flags(|s| from_f32_status(F16, f32::INFINITY, mode, s)),
+            exact
+        );
+        assert_eq!(flags(|s| from_f32_status(F16, f32::NAN, mode, s)), exact);
+        let snan = f32::from_bits(0x7F80_0001);
+        assert_eq!(flags(|s| from_f32_status(F16, snan, mode, s)), invalid);
+        let snan = f64::from_bits(0x7FF0_0000_0000_0001);
+        assert_eq!(flags(|s| from_f64_status(BF16, snan, mode, s)), invalid);
+        assert_eq!(flags(|s| from_f64_status(BF16, 1e300, mode, s)), overflow);
+
+        // Arithmetic
+        assert_eq!(flags(|s| super::add(F16, 0x3C00, 0x3C00, s)), exact);
+        assert_eq!(flags(|s| super::add(F16, 0x3C00, 0x0001, s)), inexact);
+        assert_eq!(flags(|s| super::add(F16, 0x7BFF, 0x7BFF, s)), overflow);
+        assert_eq!(flags(|s| super::sub(F16, 0x7C00, 0x7C00, s)), invalid);
+        assert_eq!(flags(|s| super::add(F16, 0x7C01, 0x3C00, s)), invalid);
+        assert_eq!(flags(|s| super::add(F16, 0x7E00, 0x3C00, s)), exact);
+        assert_eq!(flags(|s| super::mul(F16, 0x0400, 0x3800, s)), exact);
+        assert_eq!(flags(|s| super::mul(F16, 0x0401, 0x3800, s)), underflow);
+        assert_eq!(flags(|s| super::mul(F16, 0x7C00, 0x0000, s)), invalid);
+        assert_eq!(
+            flags(|s| super::div(F16, 0xBC00, 0x0000, s)),
+            Status {
+                division_by_zero: true,
+                ..exact
+            }
+        );
+        assert_eq!(flags(|s| super::div(F16, 0x7C00, 0x0000, s)), exact);
+        assert_eq!(flags(|s| super::div(F16, 0x0000, 0x0000, s)), invalid);
+        assert_eq!(flags(|s| super::div(F16, 0x3C00, 0x4200, s)), inexact);
+        assert_eq!(flags(|s| super::rem(F16, 0x4200, 0x3C00, s)), exact);
+        assert_eq!(flags(|s| super::rem(F16, 0x3C00, 0x0000, s)), invalid);
+        assert_eq!(flags(|s| super::sqrt(F16, 0x4400, s)), exact);
+        assert_eq!(flags(|s| super::sqrt(F16, 0x4000, s)), inexact);
+        assert_eq!(flags(|s| super::sqrt(F16, 0xBC00, s)), invalid);
+        assert_eq!(flags(|s| super::sqrt(F16, 0x7D00, s)), invalid);
+        assert_eq!(
+            flags(|s| super::mul_add(F16, 0x3C03, 0x3C03, 0xBC00, s)),
+            inexact
+        );
+        assert_eq!(
+            flags(|s| super::mul_add(F16, 0x3C00, 0x3C00, 0x7C01, s)),
+            invalid
+        );
+        assert_eq!(
+            flags(|s| super::mul_add(F16, 0x7C00, 0x3C00, 0xFC00, s)),
+            invalid
+        );
+
+        // Flags accumulate
+        let mut status = inexact;
+        status |= invalid;
+        assert_eq!(
+            status,
+            Status {
+                invalid: true,
+                inexact: true,
+                ..exact
+            }
+        );
+        assert!(exact.is_empty() && !status.is_empty());
+    }
+}
@@ -0,0 +1,851 @@
+//! Correctly rounded elementary functions for [`F16`][super::F16] and [`BF16`][super::BF16].
+//!
+//! With at most 11 significant bits in the result, an `f64` approximation that is accurate to a
+//! few units in its last place is far closer to the exact value than any input of these formats
+//! comes to a rounding boundary, so rounding the approximation once gives the correctly rounded
+//! result. The tests check this exhaustively for every function of one argument. The functions
+//! whose results can be exact ties between two representable values are computed differently:
+//! [`cbrt`] and [`hypot`] use exact integer arithmetic, and [`pow`] uses double-double arithmetic
+//! and recognizes exact ties with an integer check.
+//!
+//! Everything is computed with `core` alone, so none of it requires `std`.
+
+use super::{
+    from_f64, invalid, isqrt, propagate_nan, round_pack, to_f32, unpack, Format, Kind,
+    RoundingMode, Status, NEAREST,
+};
+
+const LN2: f64 = core::f64::consts::LN_2;
+const LOG2_E: f64 = core::f64::consts::LOG2_E;
+const LOG10_E: f64 = core::f64::consts::LOG10_E;
+const FRAC_PI_2: f64 = core::f64::consts::FRAC_PI_2;
+const FRAC_PI_4: f64 = core::f64::consts::FRAC_PI_4;
+const SQRT_2: f64 = core::f64::consts::SQRT_2;
+
+// Constants split so that multiplying the high part by a small integer is exact
+const LN2_HI: f64 = 6.931_471_803_691_238e-1;
+const LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
+const LOG10_2_HI: f64 = 3.010_299_956_636_117_7e-1;
+const LOG10_2_LO: f64 = 3.694_239_077_158_931e-13;
+
+// Double-double constants
+const LN2_DD: Dd = Dd::new(LN2, 2.319_046_813_846_299_6e-17);
+const LOG2_E_DD: Dd = Dd::new(LOG2_E, 2.035_527_374_093_103_3e-17);
+
+/// The first 512 fraction bits of 2/π
+const TWO_OVER_PI: [u64; 8] = [
+    0xA2F9_836E_4E44_1529,
+    0xFC27_57D1_F534_DDC0,
+    0xDB62_9599_3C43_9041,
+    0xFE51_63AB_DEBB_C561,
+    0xB724_6E3A_424D_D2E0,
+    0x0649_2EEA_09D1_921C,
+    0xFE1D_EB1C_B129_A73E,
+    0xE882_35F5_2EBB_4484,
+];
+
+/// Converts a finite value of `fmt` exactly into an `f64`
+#[inline]
+fn to_f64(fmt: Format, bits: u32) -> f64 {
+    to_f32(fmt, bits) as f64
+}
+
+/// Rounds an approximation of the result to `fmt`
+#[inline]
+fn round(fmt: Format, value: f64) -> u32 {
+    from_f64(fmt, value, NEAREST)
+}
+
+#[inline]
+fn one(fmt: Format) -> u32 {
+    round(fmt, 1.0)
+}
+
+/// Returns `x * 2^k` for `x` and the result in the normal range of `f64`
+#[inline]
+fn scale(x: f64, k: i32) -> f64 {
+    x * f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// Limits `x` to `[-limit, limit]`
+#[inline]
+fn clamp(x: f64, limit: f64) -> f64 {
+    if x > limit {
+        limit
+    } else if x < -limit {
+        -limit
+    } else {
+        x
+    }
+}
+
+/// Rounds to the nearest integer, ties away from zero
+#[inline]
+fn round_to_int(x: f64) -> i32 {
+    (if x < 0.0 { x - 0.5 } else { x + 0.5 }) as i32
+}
+
+/// Splits a positive normal `x` into `f * 2^e` with `f` in `[1/√2, √2)`
+#[inline]
+fn split_exponent(x: f64) -> (f64, i32) {
+    let bits = x.to_bits();
+    let e = (bits >> 52) as i32 - 1023;
+    let f = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | 0x3FF0_0000_0000_0000);
+    if f > SQRT_2 {
+        (f * 0.5, e + 1)
+    } else {
+        (f, e)
+    }
+}
+
+/// `e^r` for `|r| <= ln(2) / 2`
+fn exp_series(r: f64) -> f64 {
+    let mut sum = 1.0;
+    for n in (1..=17).rev() {
+        sum = 1.0 + sum * r / n as f64;
+    }
+    sum
+}
+
+/// `e^x - 1` for `|x| <= ln(2) / 2`
+fn expm1_series(x: f64) -> f64 {
+    let mut sum = 1.0;
+    for n in (2..=18).rev() {
+        sum = 1.0 + sum * x / n as f64;
+    }
+    x * sum
+}
+
+/// `e^x` for `|x| <= 200`
+fn exp_f64(x: f64) -> f64 {
+    let k = round_to_int(x * LOG2_E);
+    let r = (x - k as f64 * LN2_HI) - k as f64 * LN2_LO;
+    scale(exp_series(r), k)
+}
+
+/// `ln(f)` for `f` in `[1/√2, √2)`, through `2 atanh((f - 1) / (f + 1))`
+fn ln_series(f: f64) -> f64 {
+    let s = (f - 1.0) / (f + 1.0);
+    let s2 = s * s;
+    let mut sum = 1.0 / 25.0;
+    for k in (0..12).rev() {
+        sum = sum * s2 + 1.0 / (2 * k + 1) as f64;
+    }
+    2.0 * s * sum
+}
+
+/// `sin(r)` for `|r| <= π/4`
+fn sin_series(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut sum = 1.0;
+    for n in (1..=11).rev() {
+        sum = 1.0 - sum * r2 / ((2 * n) * (2 * n + 1)) as f64;
+    }
+    r * sum
+}
+
+/// `cos(r)` for `|r| <= π/4`
+fn cos_series(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut sum = 1.0;
+    for n in (1..=11).rev() {
+        sum = 1.0 - sum * r2 / ((2 * n - 1) * (2 * n)) as f64;
+    }
+    sum
+}
+
+/// Returns the bits of `TWO_OVER_PI` starting at bit `index`, counted from the most significant
+#[inline]
+fn two_over_pi_bits(index: usize) -> u64 {
+    let word = index / 64;
+    let shift = index % 64;
+    let next = TWO_OVER_PI.get(word + 1).copied().unwrap_or(0);
+    if shift == 0 {
+        TWO_OVER_PI[word]
+    } else {
+        (TWO_OVER_PI[word] << shift) | (next >> (64 - shift))
+    }
+}
+
+/// Reduces a positive value `m * 2^e` modulo π/2, returning the quadrant and the remainder in
+/// `[-π/4, π/4]`
+///
+/// Large arguments are reduced exactly with the bits of 2/π (Payne and Hanek's method): only the
+/// bits of 2/π that affect the product modulo 4 are multiplied, in integer arithmetic, so the
+/// remainder keeps more than 120 correct bits whatever the size of the argument.
+fn rem_pio2(m: u64, e: i32) -> (u32, f64) {
+    let tz = m.trailing_zeros();
+    let (m, e) = (m >> tz, e + tz as i32);
+    let x = m as f64 * scale(1.0, e);
+    if x <= FRAC_PI_4 {
+        return (0, x);
+    }
+
+    // Bits of 2/π worth 2^(e - j) for j < e - 1 only add multiples of 4 to the product. Take the
+    // 192 bits from there on, so that m * 2/π = (m * window) * 2^-shift modulo 4.
+    let first = core::cmp::max(1, e - 1);
+    let index = (first - 1) as usize;
+    let window = [
+        two_over_pi_bits(index + 128),
+        two_over_pi_bits(index + 64),
+        two_over_pi_bits(index),
+    ];
+    let shift = (first + 191 - e) as u32;
+
+    // Multiply into four little-endian limbs
+    let mut product = [0u64; 4];
+    let mut carry = 0u128;
+    for (limb, &w) in product.iter_mut().zip(&window) {
+        let t = m as u128 * w as u128 + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    product[3] = carry as u64;
+
+    // Extracts 128 bits of the product starting at bit `at`
+    let bits = |at: u32| -> u128 {
+        let word = (at / 64) as usize;
+        let offset = at % 64;
+        let limb = |i: usize| product.get(i).copied().unwrap_or(0) as u128;
+        let low = limb(word) | (limb(word + 1) << 64);
+        if offset == 0 {
+            low
+        } else {
+            (low >> offset) | (limb(word + 2) << (128 - offset))
+        }
+    };
+    let mut quadrant = bits(shift) as u32 & 3;
+    let fraction = bits(shift - 128);
+
+    // Round to the nearest quadrant so the remainder is within ±π/4
+    let r = if fraction >> 127 != 0 {
+        quadrant = (quadrant + 1) & 3;
+        -(fraction.wrapping_neg() as f64)
+    } else {
+        fraction as f64
+    };
+    (quadrant, r * scale(1.0, -128) * FRAC_PI_2)
+}
+
+/// The sine and cosine of the finite value `m * 2^e`, without its sign
+fn sin_cos(m: u64, e: i32) -> (f64, f64) {
+    let (quadrant, r) = rem_pio2(m, e);
+    let (s, c) = (sin_series(r), cos_series(r));
+    match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Computes `e^a`
+pub(crate) fn exp(fmt: Format, a: u32) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, &mut Status::default()),
+        (_, Kind::Zero) => one(fmt),
+        (true, Kind::Inf) => 0,
+        (false, Kind::Inf) => a,
+        (_, Kind::Finite(..)) => {
+            // Beyond ±200 the result is far outside the range of every format
+            let x = clamp(to_f64(fmt, a), 200.0);
+            round(fmt, exp_f64(x))
+        }
+    }
+}
+
+/// Computes `2^a`
+pub(crate) fn exp2(fmt: Format, a: u32) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, &mut Status::default()),
+        (_, Kind::Zero) => one(fmt),
+        (true, Kind::Inf) => 0,
+        (false, Kind::Inf) => a,
+        (_, Kind::Finite(..)) => {
+            let x = clamp(to_f64(fmt, a), 300.0);
+            // Every value of these formats has few enough bits that the remainder is exact
+            let k = round_to_int(x);
+            let r = x - k as f64;
+            round(fmt, scale(exp_series(r * LN2), k))
+        }
+    }
+}
+
+/// Computes a logarithm of `a` from `f` and `e` with `a = f * 2^e`, handling the special cases
+fn log(fmt: Format, a: u32, log: impl FnOnce(f64, i32) -> f64) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, &mut Status::default()),
+        (_, Kind::Zero) => fmt.sign_mask() | fmt.infinity(),
+        (true, _) => invalid(fmt, &mut Status::default()),
+        (false, Kind::Inf) => a,
+        (false, Kind::Finite(..)) => {
+            let (f, e) = split_exponent(to_f64(fmt, a));
+            round(fmt, log(f, e))
+        }
+    }
+}
+
+/// Computes `ln(a)`
+pub(crate) fn ln(fmt: Format, a: u32) -> u32 {
+    log(fmt, a, |f, e| {
+        e as f64 * LN2_HI + (e as f64 * LN2_LO + ln_series(f))
+    })
+}
+
+/// Computes `log2(a)`
+pub(crate) fn log2(fmt: Format, a: u32) -> u32 {
+    log(fmt, a, |f, e| e as f64 + ln_series(f) * LOG2_E)
+}
+
+/// Computes `log10(a)`
+pub(crate) fn log10(fmt: Format, a: u32) -> u32 {
+    log(fmt, a, |f, e| {
+        e as f64 * LOG10_2_HI + (e as f64 * LOG10_2_LO + ln_series(f) * LOG10_E)
+    })
+}
+
+/// Computes a trigonometric function of `a` from its sine and cosine, handling the special cases
+fn trig(fmt: Format, a: u32, odd: bool, f: impl FnOnce(f64, f64) -> f64) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, &mut Status::default()),
+        (_, Kind::Inf) => invalid(fmt, &mut Status::default()),
+        (_, Kind::Zero) if odd => a,
+        (_, Kind::Zero) => one(fmt),
+        (sign, Kind::Finite(m, e)) => {
+            let (s, c) = sin_cos(m, e);
+            let v = f(s, c);
+            round(fmt, if sign && odd { -v } else { v })
+        }
+    }
+}
+
+/// Computes `sin(a)`
+pub(crate) fn sin(fmt: Format, a: u32) -> u32 {
+    trig(fmt, a, true, |s, _| s)
+}
+
+/// Computes `cos(a)`
+pub(crate) fn cos(fmt: Format, a: u32) -> u32 {
+    trig(fmt, a, false, |_, c| c)
+}
+
+/// Computes `tan(a)`
+pub(crate) fn tan(fmt: Format, a: u32) -> u32 {
+    trig(fmt, a, true, |s, c| s / c)
+}
+
+/// Computes `tanh(a)`
+pub(crate) fn tanh(fmt: Format, a: u32) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, &mut Status::default()),
+        (_, Kind::Zero) => a,
+        (sign, Kind::Inf) => round(fmt, if sign { -1.0 } else { 1.0 }),
+        (sign, Kind::Finite(..)) => {
+            // tanh(20) is within 2^-57 of 1
+            let x = clamp(to_f64(fmt, a & !fmt.sign_mask()), 20.0);
+            let t = if 2.0 * x <= LN2 / 2.0 {
+                expm1_series(2.0 * x)
+            } else {
+                exp_f64(2.0 * x) - 1.0
+            };
+            let v = t / (t + 2.0);
+            round(fmt, if sign { -v } else { v })
+        }
+    }
+}
+
+/// Integer cube root of `n < 2^124`, returning the root and whether it was inexact
+fn icbrt(n: u128) -> (u128, bool) {
+    let mut root = 0u128;
+    let mut bit = 1u128 << ((127 - n.leading_zeros()) / 3);
+    while bit != 0 {
+        let c = root | bit;
+        if c * c * c <= n {
+            root = c;
+        }
+        bit >>= 1;
+    }
+    (root, root * root * root != n)
+}
+
+/// Computes the cube root of `a`, rounded once
+pub(crate) fn cbrt(fmt: Format, a: u32) -> u32 {
+    match unpack(fmt, a) {
+        (_, Kind::Nan) => propagate_nan(fmt, a, a, &mut Status::default()),
+        (_, Kind::Zero) | (_, Kind::Inf) => a,
+        (sign, Kind::Finite(m, e)) => {
+            // Make the exponent a multiple of 3 and widen so the root has 40 bits
+            let shift = e.rem_euclid(3);
+            let n = (m as u128) << (shift + 90);
+            let (root, inexact) = icbrt(n);
+            let root = ((root as u64) << 1) | inexact as u64;
+            let e = (e - shift - 90) / 3 - 1;
+            round_pack(fmt, NEAREST, sign, root, e, &mut Status::default())
+        }
+    }
+}
+
+/// Computes `sqrt(a² + b²)`, rounded once and without intermediate overflow or underflow
+pub(crate) fn hypot(fmt: Format, a: u32, b: u32) -> u32 {
+    let abs = !fmt.sign_mask();
+    match (unpack(fmt, a), unpack(fmt, b)) {
+        ((_, Kind::Inf), _) | (_, (_, Kind::Inf)) => fmt.infinity(),
+        ((_, Kind::Nan), _) | (_, (_, Kind::Nan)) => {
+            propagate_nan(fmt, a, b, &mut Status::default())
+        }
+        ((_, Kind::Zero), _) => b & abs,
+        (_, (_, Kind::Zero)) => a & abs,
+        ((_, Kind::Finite(ma, ea)), (_, Kind::Finite(mb, eb))) => {
+            // Both significands are normalized, so the larger value has the larger (e, m)
+            let (big, (ma, ea), (mb, eb)) = if (ea, ma) >= (eb, mb) {
+                (a, (ma, ea), (mb, eb))
+            } else {
+                (b, (mb, eb), (ma, ea))
+            };
+            let d = (ea - eb) as u32;
+            if d > 40 {
+                // The smaller value changes the larger one by less than 2^-78 of it
+                return big & abs;
+            }
+
+            // Drop the zero low bits so that the sum of squares fits in 104 bits
+            let zeros = 31 - fmt.man_bits;
+            let (ma, mb, eb) = (ma >> zeros, mb >> zeros, eb + zeros as i32);
+            let sum = (((ma * ma) as u128) << (2 * d)) + (mb * mb) as u128;
+            let k = (124 - (128 - sum.leading_zeros())) / 2;
+            let (root, inexact) = isqrt(sum << (2 * k));
+            let root = ((root as u64) << 1) | inexact as u64;
+            round_pack(
+                fmt,
+                NEAREST,
+                false,
+                root,
+                eb - k as i32 - 1,
+                &mut Status::default(),
+            )
+        }
+    }
+}
+
+/// A double-double value `hi + lo` with `|lo| <= ulp(hi) / 2`
+#[derive(Clone, Copy, Debug)]
+struct Dd {
+    hi: f64,
+    lo: f64,
+}
+
+impl Dd {
+    const fn new(hi: f64, lo: f64) -> Dd {
+        Dd { hi, lo }
+    }
+
+    /// `a + b` exactly, for `|a| >= |b|`
+    #[inline]
+    fn fast_two_sum(a: f64, b: f64) -> Dd {
+        let hi = a + b;
+        Dd::new(hi, b - (hi - a))
+    }
+
+    /// `a + b` exactly
+    #[inline]
+    fn two_sum(a: f64, b: f64) -> Dd {
+        let hi = a + b;
+        let bb = hi - a;
+        Dd::new(hi, (a - (hi - bb)) + (b - bb))
+    }
+
+    /// Splits `a` into two halves of 26 bits whose products are exact
+    #[inline]
+    fn split(a: f64) -> (f64, f64) {
+        let c = 134_217_729.0 * a;
+        let hi = c - (c - a);
+        (hi, a - hi)
+    }
+
+    /// `a * b` exactly
+    #[inline]
+    fn two_prod(a: f64, b: f64) -> Dd {
+        let hi = a * b;
+        let (ah, al) = Dd::split(a);
+        let (bh, bl) = Dd::split(b);
+        Dd::new(hi, ((ah * bh - hi) + ah * bl + al * bh) + al * bl)
+    }
+
+    #[inline]
+    fn add(self, rhs: Dd) -> Dd {
+        let s = Dd::two_sum(self.hi, rhs.hi);
+        let t = Dd::two_sum(self.lo, rhs.lo);
+        let s = Dd::fast_two_sum(s.hi, s.lo + t.hi);
+        Dd::fast_two_sum(s.hi, s.lo + t.lo)
+    }
+
+    #[inline]
+    fn mul(self, rhs: Dd) -> Dd {
+        let p = Dd::two_prod(self.hi, rhs.hi);
+        Dd::fast_two_sum(p.hi, p.lo + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+
+    #[inline]
+    fn div(self, rhs: Dd) -> Dd {
+        let q1 = self.hi / rhs.hi;
+        let r = self.add(rhs.mul(Dd::new(-q1, 0.0)));
+        let q2 = r.hi / rhs.hi;
+        let r = r.add(rhs.mul(Dd::new(-q2, 0.0)));
+        let q3 = r.hi / rhs.hi;
+        Dd::fast_two_sum(q1, q2).add(Dd::new(q3, 0.0))
+    }
+}
+
+impl From<f64> for Dd {
+    #[inline]
+    fn from(x: f64) -> Dd {
+        Dd::new(x, 0.0)
+    }
+}
+
+/// `log2(x)` for a positive normal `x`, with a relative error around 2^-100
+fn log2_dd(x: f64) -> Dd {
+    let (f, e) = split_exponent(x);
+    // f - 1 and f + 1 are exact since x has at most 11 significant bits
+    let s = Dd::from(f - 1.0).div(Dd::from(f + 1.0));
+    let s2 = s.mul(s);
+    let mut sum = Dd::from(1.0).div(Dd::from(43.0));
+    for k in (0..21).rev() {
+        let c = Dd::from(1.0).div(Dd::from((2 * k + 1) as f64));
+        sum = sum.mul(s2).add(c);
+    }
+    let ln_f = s.mul(sum).mul(Dd::from(2.0));
+    Dd::from(e as f64).add(ln_f.mul(LOG2_E_DD))
+}
+
+/// `2^t` for `|t| <= 200`, with a relative error around 2^-95
+fn exp2_dd(t: Dd) -> Dd {
+    let k = round_to_int(t.hi);
+    let r = Dd::fast_two_sum(t.hi - k as f64, t.lo);
+    let z = r.mul(LN2_DD);
+    let mut sum = Dd::from(1.0);
+    for n in (1..=24).rev() {
+        sum = Dd::from(1.0).add(z.mul(sum).div(Dd::from(n as f64)));
+    }
+    Dd::new(scale(sum.hi, k), scale(sum.lo, k))
+}
+
+/// Splits a finite nonzero `f64` into an odd integer significand and an exponent
+fn odd_parts(x: f64) -> (u64, i32) {
+    let bits = x.to_bits() & !(1 << 63);
+    let exp = (bits >> 52) as i32;
+    let (m, e) = if exp == 0 {
+        (bits, -1074)
+    } else {
+        ((bits & 0x000F_FFFF_FFFF_FFFF) | 1 << 52, exp - 1075)
+    };
+    let tz = m.trailing_zeros();
+    (m >> tz, e + tz as i32)
+}
+
+/// Whether `m^n`, with `m > 1`, equals `target`
+fn is_power(m: u64, n: u64, target: u64) -> bool {
+    let mut p = 1u64;
+    for _ in 0..n {
+        p *= m;
+        if p > target {
+            return false;
+        }
+    }
+    p == target
+}
+
+/// Whether `|x|^y` is exactly `tie`, a value that the approximation of the power could not tell
+/// apart from it
+///
+/// The odd significand of a tie between two values of these formats is greater than 1 and less
+/// than 2^12. With `y = n * 2^-k` for an odd `n`, `|x|^y` can only have such a significand if `n`
+/// is positive and the odd significand of `x` is `g^(2^k)` for an integer `g` with `g^n` equal to
+/// it. The exponents then agree as well, since the approximation is so close.
+fn is_exact_power(x: f64, y: f64, tie: f64) -> bool {
+    let (mt, _) = odd_parts(tie);
+    let (mx, _) = odd_parts(x);
+    let (n, k) = odd_parts(y);
+    if y < 0.0 || mx == 1 {
+        return false;
+    }
+    if k >= 0 {
+        // An integer power; tiny significands make any large exponent overflow `mt`
+        return k < 4 && is_power(mx, n << k, mt);
+    }
+    // Take the 2^k-th root of mx, which must be exact
+    let mut g = mx;
+    for _ in 0..-k {
+        let (root, inexact) = isqrt(g as u128);
+        if inexact {
+            return false;
+        }
+        g = root as u64;
+    }
+    g > 1 && is_power(g, n, mt)
+}
+
+/// Rounds the positive double-double approximation `v` of `|x|^y` to `fmt`
+fn round_power(fmt: Format, sign: bool, v: Dd, x: f64, y: f64) -> u32 {
+    let Dd { hi, mut lo } = v;
+
+    // An exact tie is approximated by the tie itself and a tiny error term, which must be
+    // dropped so that the tie rounds to even
+    let below = from_f64(fmt, hi, RoundingMode::TowardZero);
+    let tie = (to_f64(fmt, below) + to_f64(fmt, below + 1)) / 2.0;
+    if hi == tie && is_exact_power(x, y, tie) {
+        lo = 0.0;
+    }
+
+    // Round hi + lo to odd in f64, which leaves enough bits to then round once to nearest
+    let mut v = hi;
+    if lo != 0.0 && hi.to_bits() & 1 == 0 {
+        let bits = if lo > 0.0 {
+            hi.to_bits() + 1
+        } else {
+            hi.to_bits() - 1
+        };
+        v = f64::from_bits(bits);
+    }
+    round(fmt, if sign { -v } else { v })
+}
+
+/// Computes `a` raised to the power `b`, with the special cases of IEEE 754 `pow`
+pub(crate) fn pow(fmt: Format, a: u32, b: u32) -> u32 {
+    let one = one(fmt);
+    let (sa, ka) = unpack(fmt, a);
+    let (sb, kb) = unpack(fmt, b);
+    let (integer, odd) = match kb {
+        Kind::Finite(m, e) => {
+            let e = e + m.trailing_zeros() as i32;
+            (e >= 0, e == 0)
+        }
+        _ => (false, false),
+    };
+    let signed = |bits: u32, negative: bool| {
+        if negative {
+            bits | fmt.sign_mask()
+        } else {
+            bits
+        }
+    };
+
+    match (ka, kb) {
+        (_, Kind::Zero) => one,
+        _ if a == one => one,
+        (Kind::Nan, _) | (_, Kind::Nan) => propagate_nan(fmt, a, b, &mut Status::default()),
+        (_, Kind::Inf) => {
+            let magnitude = a & !fmt.sign_mask();
+            if magnitude == one {
+                one
+            } else if (magnitude < one) == sb {
+                fmt.infinity()
+            } else {
+                0
+            }
+        }
+        (Kind::Zero, _) if sb => signed(fmt.infinity(), sa && odd),
+        (Kind::Zero, _) => signed(0, sa && odd),
+        (Kind::Inf, _) if sb => signed(0, sa && odd),
+        (Kind::Inf, _) => signed(fmt.infinity(), sa && odd),
+        (Kind::Finite(..), Kind::Finite(..)) => {
+            if sa && !integer {
+                return invalid(fmt, &mut Status::default());
+            }
+            let x = to_f64(fmt, a & !fmt.sign_mask());
+            let y = to_f64(fmt, b);
+            let t = log2_dd(x).mul(Dd::from(y));
+            // Beyond ±200 the result is far outside the range of every format
+            if t.hi > 200.0 {
+                signed(fmt.infinity(), sa && odd)
+            } else if t.hi < -200.0 {
+                signed(0, sa && odd)
+            } else {
+                round_power(fmt, sa && odd, exp2_dd(t), x, y)
+            }
+        }
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::softfloat::{BF16, F16};
+    use quickcheck_macros::quickcheck;
+
+    /// Checks `result` against the exact value approximated by `reference`, an `f64` result of
+    /// the system math library. Returns false if the reference is too close to a tie between two
+    /// values of `fmt` to decide the rounding; exact ties are decided to even.
+    fn check(fmt: Format, input: &str, result: u32, reference: f64) -> bool {
+        let expected = round(fmt, reference);
+        if reference.is_nan() {
+            assert!(
+                fmt.is_nan(result),
+                "{}: expected NaN, got {:#x}",
+                input,
+                result
+            );
+            return true;
+        }
+        let magnitude = reference.abs();
+        let below = from_f64(fmt, magnitude, RoundingMode::TowardZero);
+        let tie = (to_f64(fmt, below) + to_f64(fmt, below + 1)) / 2.0;
+        if magnitude != tie && (magnitude - tie).abs() < magnitude * 2f64.powi(-40) {
+            return false;
+        }
+        assert_eq!(
+            result, expected,
+            "{}: expected {:#x} from {:e}, got {:#x}",
+            input, expected, reference, result
+        );
+        true
+    }
+
+    /// Checks `f` for every value of `fmt` against `reference`
+    fn exhaustive(fmt: Format, f: fn(Format, u32) -> u32, reference: fn(f64) -> f64) {
+        for bits in 0..1u32 << (fmt.exp_bits + fmt.man_bits + 1) {
+            let x = to_f64(fmt, bits);
+            let decided = check(fmt, &std::format!("{:e}", x), f(fmt, bits), reference(x));
+            assert!(decided, "reference is too close to a tie for {:e}", x);
+        }
+    }
+
+    macro_rules! exhaustive_tests {
+        ($($name:ident: $f:path, $reference:expr;)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    exhaustive(F16, $f, $reference);
+                    exhaustive(BF16, $f, $reference);
+                }
+            )*
+        };
+    }
+
+    exhaustive_tests! {
+        exhaustive_exp: exp, f64::exp;
+        exhaustive_exp2: exp2, f64::exp2;
+        exhaustive_ln: ln, f64::ln;
+        exhaustive_log2: log2, f64::log2;
+        exhaustive_log10: log10, f64::log10;
+        exhaustive_sin: sin, f64::sin;
+        exhaustive_cos: cos, f64::cos;
+        exhaustive_tan: tan, f64::tan;
+        exhaustive_tanh: tanh, f64::tanh;
+        exhaustive_cbrt: cbrt, f64::cbrt;
+    }
+
+    #[test]
+    fn special_values() {
+        let (one, inf, nan) = (0x3C00, 0x7C00, 0x7E00);
+        let (neg, zero, neg_zero) = (0x8000, 0, 0x8000);
+        assert_eq!(exp(F16, neg | inf), zero);
+        assert_eq!(exp(F16, neg_zero), one);
+        assert_eq!(ln(F16, zero), neg | inf);
+        assert_eq!(ln(F16, neg_zero), neg | inf);
+        assert!(F16.is_nan(ln(F16, neg | one)));
+        assert_eq!(log2(F16, inf), inf);
+        assert_eq!(sin(F16, neg_zero), neg_zero);
+        assert!(F16.is_nan(cos(F16, inf)));
+        assert_eq!(tanh(F16, neg | inf), neg | one);
+        assert_eq!(cbrt(F16, neg | inf), neg | inf);
+        assert!(F16.is_nan(exp(F16, 0x7C01)));
+
+        assert_eq!(hypot(F16, neg | inf, nan), inf);
+        assert_eq!(hypot(F16, nan, inf), inf);
+        assert!(F16.is_nan(hypot(F16, nan, one)));
+        assert_eq!(hypot(F16, neg_zero, neg | one), one);
+
+        assert_eq!(pow(F16, nan, zero), one);
+        assert_eq!(pow(F16, one, nan), one);
+        assert!(F16.is_nan(pow(F16, nan, one)));
+        assert_eq!(pow(F16, neg | one, inf), one);
+        assert_eq!(pow(F16, 0x3800, inf), zero);
+        assert_eq!(pow(F16, 0x3800, neg | inf), inf);
+        assert_eq!(pow(F16, neg_zero, neg | 0x4200), neg | inf);
+        assert_eq!(pow(F16, neg_zero, neg | 0x3E00), inf);
+        assert_eq!(pow(F16, neg_zero, neg | 0x4000), inf);
+        assert_eq!(pow(F16, neg_zero, 0x4200), neg_zero);
+        assert_eq!(pow(F16, neg | inf, 0x4200), neg | inf);
+        assert_eq!(pow(F16, neg | inf, neg | 0x4000), zero);
+        assert!(F16.is_nan(pow(F16, neg | 0x4000, 0x3800)));
+        assert_eq!(pow(F16, neg | 0x4000, 0x4200), round(F16, -8.0));
+    }
+
+    #[test]
+    fn exact_results() {
+        let f = |x: f64| round(F16, x);
+        // Exact ties between two values round to even
+        assert_eq!(pow(F16, f(63.0), f(2.0)), f(3968.0));
+        assert_eq!(pow(F16, f(65.0), f(2.0)), f(4224.0));
+        assert_eq!(pow(F16, f(9.0), f(3.5)), f(2188.0));
+        assert_eq!(pow(F16, f(81.0), f(1.75)), f(2188.0));
+        assert_eq!(pow(F16, f(-13.0), f(3.0)), f(-2196.0));
+        assert_eq!(pow(F16, f(2.0), f(-24.0)), 1);
+        assert_eq!(
+            pow(F16, f(0.5), f(0.5)),
+            f(core::f64::consts::FRAC_1_SQRT_2)
+        );
+        let g = |x: f64| round(BF16, x);
+        assert_eq!(pow(BF16, g(19.0), g(2.0)), g(360.0));
+        assert_eq!(pow(BF16, g(49.0), g(1.5)), g(344.0));
+        assert_eq!(pow(BF16, g(25.0), g(1.5)), g(125.0));
+
+        // Pythagorean triples are exact, and ties round to even
+        assert_eq!(hypot(F16, f(3.0), f(4.0)), f(5.0));
+        assert_eq!(hypot(F16, f(1995.0), f(-476.0)), f(2052.0));
+        assert_eq!(hypot(F16, f(65504.0), f(65504.0)), 0x7C00);
+        assert_eq!(hypot(F16, 1, 1), 1);
+        assert_eq!(hypot(BF16, g(3e38), g(3e38)), 0x7F80);
+        assert_eq!(cbrt(F16, f(-27.0)), f(-3.0));
+        assert_eq!(cbrt(BF16, g(2f64.powi(-120))), g(2f64.powi(-40)));
+    }
+
+    fn pow_is_correctly_rounded(fmt: Format, a: u32, b: u32) -> bool {
+        let x = to_f64(fmt, a);
+        let y = to_f64(fmt, b);
+        check(
+            fmt,
+            &std::format!("{:e} ^ {:e}", x, y),
+            pow(fmt, a, b),
+            x.powf(y),
+        )
+    }
+
+    fn hypot_is_correctly_rounded(fmt: Format, a: u32, b: u32) -> bool {
+        let x = to_f64(fmt, a);
+        let y = to_f64(fmt, b);
+        check(
+            fmt,
+            &std::format!("hypot({:e}, {:e})", x, y),
+            hypot(fmt, a, b),
+            x.hypot(y),
+        )
+    }
+
+    #[test]
+    fn pow_small_exponents() {
+        // Every base with the exponents where exact results are most common
+        for &y in &[2.0, 3.0, 0.5, 1.5, 0.25, -1.0, -0.5, 7.0, 2.5, 1.25] {
+            for a in 0..=u16::MAX as u32 {
+                assert!(pow_is_correctly_rounded(F16, a, round(F16, y)));
+                assert!(pow_is_correctly_rounded(BF16, a, round(BF16, y)));
+            }
+        }
+    }
+
+    #[quickcheck]
+    fn qc_pow(a: u16, b: u16) -> bool {
+        pow_is_correctly_rounded(F16, a as u32, b as u32)
+            && pow_is_correctly_rounded(BF16, a as u32, b as u32)
+    }
+
+    #[quickcheck]
+    fn qc_hypot(a: u16, b: u16) -> bool {
+        hypot_is_correctly_rounded(F16, a as u32, b as u32)
+            && hypot_is_correctly_rounded(BF16, a as u32, b as u32)
+    }
+}
@@ -0,0 +1,548 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::{
+    cmp::Ordering,
+    fmt::{
+        Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+    },
+    num::FpCategory,
+    ops::Neg,
+    str::FromStr,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse, shortest, softfloat, ParseHalfError, RoundingMode};
+
+pub(crate) mod convert;
+
+/// A 19-bit floating point type implementing NVIDIA's [TensorFloat-32] format, stored in 32 bits
+///
+/// TF32 has the 8-bit exponent of [`f32`] and the 10-bit fraction of [`f16`][crate::f16], for the
+/// range of [`f32`] with a precision of 11 bits. Tensor cores read [`f32`] operands and round them
+/// to TF32 before multiplying, so rounding the operands with [`tf32`] on the CPU reproduces their
+/// inputs exactly. Like the hardware, a [`tf32`] is stored as an [`f32`] whose low 13 fraction
+/// bits are zero, and [`to_f32`][tf32::to_f32] is free.
+///
+/// Conversions round to nearest, ties to even, by default. Truncation and the other
+/// [`RoundingMode`]s are available with [`from_f32_round`][tf32::from_f32_round]. For rounding
+/// buffers of [`f32`] data in place, see [`Tf32SliceExt`][crate::slice::Tf32SliceExt].
+///
+/// No arithmetic is implemented; convert to [`f32`] to compute.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// let x = tf32::from_f32(1.0 + 3.0 * f32::EPSILON);
+///
+/// assert_eq!(x, tf32::ONE);
+/// assert_eq!(tf32::from_f32(0.1).to_f32(), 0.099975586);
+/// assert_eq!(tf32::from_f32_round(0.1, RoundingMode::TowardZero).to_bits(), 0x3DCC_C000);
+/// ```
+///
+/// [TensorFloat-32]: https://blogs.nvidia.com/blog/tensorfloat-32-precision-format/
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+pub struct tf32(u32);
+
+impl tf32 {
+    /// Constructs a [`tf32`] value from the raw bits of an [`f32`]
+    ///
+    /// The low 13 bits, which TF32 does not have, are cleared.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> tf32 {
+        tf32(bits & !convert::DISCARDED)
+    }
+
+    /// Converts a [`tf32`] into the raw bits of the equal [`f32`]
+    ///
+    /// The low 13 bits are always zero.
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Constructs a [`tf32`] value from a 32-bit floating point value
+    ///
+    /// The value is rounded to nearest, ties to even, as tensor cores do. Values too large in
+    /// magnitude become ±∞, and NaN values are preserved.
+    #[inline]
+    pub fn from_f32(value: f32) -> tf32 {
+        tf32(convert::f32_bits_to_tf32_nearest(value.to_bits()))
+    }
+
+    /// Constructs a [`tf32`] value from a 64-bit floating point value
+    ///
+    /// The value is rounded to nearest, ties to even, exactly once.
+    #[inline]
+    pub fn from_f64(value: f64) -> tf32 {
+        tf32(convert::f64_to_tf32(value, RoundingMode::NearestTiesToEven))
+    }
+
+    /// Constructs a [`tf32`] value from a 32-bit floating point value using the given rounding
+    /// mode
+    ///
+    /// [`TowardZero`][RoundingMode::TowardZero] truncates the 13 low fraction bits, as some
+    /// hardware does. Whether out of range values become ±∞ or the largest finite value depends on
+    /// `mode`; see [`RoundingMode`] for details. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = 1.0 + 2.0f32.powi(-11);
+    ///
+    /// assert_eq!(tf32::from_f32_round(x, RoundingMode::NearestTiesToEven), tf32::ONE);
+    /// assert!(tf32::from_f32_round(x, RoundingMode::NearestTiesToAway) > tf32::ONE);
+    /// assert_eq!(tf32::from_f32_round(f32::MAX, RoundingMode::TowardZero), tf32::MAX);
+    /// ```
+    #[inline]
+    pub fn from_f32_round(value: f32, mode: RoundingMode) -> tf32 {
+        tf32(convert::f32_to_tf32(value, mode))
+    }
+
+    /// Constructs a [`tf32`] value from a 64-bit floating point value using the given rounding
+    /// mode
+    ///
+    /// The value is rounded once according to `mode`. See
+    /// [`from_f32_round`][Self::from_f32_round] for details.
+    #[inline]
+    pub fn from_f64_round(value: f64, mode: RoundingMode) -> tf32 {
+        tf32(convert::f64_to_tf32(value, mode))
+    }
+
+    /// Parses a decimal number from ASCII bytes, rounding to nearest, ties to even
+    ///
+    /// The syntax is the same as for [`f16::from_ascii`][crate::f16::from_ascii].
+    #[inline]
+    pub fn from_ascii(src: &[u8]) -> Result<tf32, ParseHalfError> {
+        parse::parse(softfloat::TF32, src, false).map(|bits| tf32(bits << 13))
+    }
+
+    /// Parses a decimal number from ASCII bytes, rejecting values that overflow or underflow
+    ///
+    /// See [`f16::from_ascii_strict`][crate::f16::from_ascii_strict] for details.
+    #[inline]
+    pub fn from_ascii_strict(src: &[u8]) -> Result<tf32, ParseHalfError> {
+        parse::parse(softfloat::TF32, src, true).map(|bits| tf32(bits << 13))
+    }
+
+    /// Converts a [`tf32`] value into an [`f32`] value
+    ///
+    /// This conversion is lossless and only reinterprets the bits.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits(self.0)
+    }
+
+    /// Converts a [`tf32`] value into an [`f64`] value
+    ///
+    /// This conversion is lossless as all values can be represented exactly in [`f64`].
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7FFF_FFFFu32 > 0x7F80_0000u32
+    }
+
+    /// Returns `true` if this value is ±∞ and `false` otherwise
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7FFF_FFFFu32 == 0x7F80_0000u32
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.0 & 0x7F80_0000u32 != 0x7F80_0000u32
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN
+    #[inline]
+    pub const fn is_normal(self) -> bool {
+        let exp = self.0 & 0x7F80_0000u32;
+        exp != 0x7F80_0000u32 && exp != 0
+    }
+
+    /// Returns the floating point category of the number
+    ///
+    /// If only one property is going to be tested, it is generally faster to use the specific
+    /// predicate instead.
+    pub fn classify(self) -> FpCategory {
+        self.to_f32().classify()
+    }
+
+    /// Returns a number that represents the sign of `self`
+    ///
+    /// * 1.0 if the number is positive, +0.0 or +∞
+    /// * −1.0 if the number is negative, −0.0 or −∞
+    /// * NaN if the number is NaN
+    pub fn signum(self) -> tf32 {
+        if self.is_nan() {
+            self
+        } else {
+            tf32((self.0 & 0x8000_0000u32) | tf32::ONE.0)
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including +0.0, NaNs with a
+    /// positive sign bit and +∞
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & 0x8000_0000u32 == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including −0.0, NaNs with a
+    /// negative sign bit and −∞
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & 0x8000_0000u32 != 0
+    }
+
+    /// Approximate number of [`tf32`] significant digits in base 10
+    pub const DIGITS: u32 = 3;
+    /// [`tf32`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: tf32 = tf32(0x3A80_0000u32);
+    /// [`tf32`] positive Infinity (+∞)
+    pub const INFINITY: tf32 = tf32(0x7F80_0000u32);
+    /// Number of [`tf32`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 11;
+    /// Largest finite [`tf32`] value
+    pub const MAX: tf32 = tf32(0x7F7F_E000u32);
+    /// Maximum possible [`tf32`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 38;
+    /// Maximum possible [`tf32`] power of 2 exponent
+    pub const MAX_EXP: i32 = 128;
+    /// Smallest finite [`tf32`] value
+    pub const MIN: tf32 = tf32(0xFF7F_E000u32);
+    /// Minimum possible normal [`tf32`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -37;
+    /// One greater than the minimum possible normal [`tf32`] power of 2 exponent
+    pub const MIN_EXP: i32 = -125;
+    /// Smallest positive normal [`tf32`] value
+    pub const MIN_POSITIVE: tf32 = tf32(0x0080_0000u32);
+    /// [`tf32`] Not a Number (NaN)
+    pub const NAN: tf32 = tf32(0x7FC0_0000u32);
+    /// [`tf32`] negative infinity (-∞)
+    pub const NEG_INFINITY: tf32 = tf32(0xFF80_0000u32);
+    /// The radix or base of the internal representation of [`tf32`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`tf32`] value
+    pub const MIN_POSITIVE_SUBNORMAL: tf32 = tf32(0x0000_2000u32);
+    /// Maximum subnormal [`tf32`] value
+    pub const MAX_SUBNORMAL: tf32 = tf32(0x007F_E000u32);
+
+    /// [`tf32`] 1
+    pub const ONE: tf32 = tf32(0x3F80_0000u32);
+    /// [`tf32`] 0
+    pub const ZERO: tf32 = tf32(0x0000_0000u32);
+    /// [`tf32`] -0
+    pub const NEG_ZERO: tf32 = tf32(0x8000_0000u32);
+
+    /// [`tf32`] Euler's number (ℯ)
+    pub const E: tf32 = tf32(0x402E_0000u32);
+    /// [`tf32`] Archimedes' constant (π)
+    pub const PI: tf32 = tf32(0x4049_0000u32);
+    /// [`tf32`] 1/π
+    pub const FRAC_1_PI: tf32 = tf32(0x3EA3_0000u32);
+    /// [`tf32`] 1/√2
+    pub const FRAC_1_SQRT_2: tf32 = tf32(0x3F35_0000u32);
+    /// [`tf32`] 2/π
+    pub const FRAC_2_PI: tf32 = tf32(0x3F23_0000u32);
+    /// [`tf32`] 2/√π
+    pub const FRAC_2_SQRT_PI: tf32 = tf32(0x3F90_6000u32);
+    /// [`tf32`] π/2
+    pub const FRAC_PI_2: tf32 = tf32(0x3FC9_0000u32);
+    /// [`tf32`] π/3
+    pub const FRAC_PI_3: tf32 = tf32(0x3F86_0000u32);
+    /// [`tf32`] π/4
+    pub const FRAC_PI_4: tf32 = tf32(0x3F49_0000u32);
+    /// [`tf32`] π/6
+    pub const FRAC_PI_6: tf32 = tf32(0x3F06_0000u32);
+    /// [`tf32`] π/8
+    pub const FRAC_PI_8: tf32 = tf32(0x3EC9_0000u32);
+    /// [`tf32`] 𝗅𝗇 10
+    pub const LN_10: tf32 = tf32(0x4013_6000u32);
+    /// [`tf32`] 𝗅𝗇 2
+    pub const LN_2: tf32 = tf32(0x3F31_8000u32);
+    /// [`tf32`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: tf32 = tf32(0x3EDE_6000u32);
+    /// [`tf32`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: tf32 = tf32(0x3E9A_2000u32);
+    /// [`tf32`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: tf32 = tf32(0x3FB8_A000u32);
+    /// [`tf32`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: tf32 = tf32(0x4054_A000u32);
+    /// [`tf32`] √2
+    pub const SQRT_2: tf32 = tf32(0x3FB5_0000u32);
+}
+
+impl From<tf32> for f32 {
+    #[inline]
+    fn from(x: tf32) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<tf32> for f64 {
+    #[inline]
+    fn from(x: tf32) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl PartialEq for tf32 {
+    #[inline]
+    fn eq(&self, other: &tf32) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for tf32 {
+    #[inline]
+    fn partial_cmp(&self, other: &tf32) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl FromStr for tf32 {
+    type Err = ParseHalfError;
+    fn from_str(src: &str) -> Result<tf32, ParseHalfError> {
+        tf32::from_ascii(src.as_bytes())
+    }
+}
+
+impl Debug for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            Debug::fmt(&self.to_f32(), f)
+        } else {
+            shortest::debug(softfloat::TF32, self.0 >> 13, f)
+        }
+    }
+}
+
+impl Display for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            Display::fmt(&self.to_f32(), f)
+        } else {
+            shortest::display(softfloat::TF32, self.0 >> 13, f)
+        }
+    }
+}
+
+impl LowerExp for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            LowerExp::fmt(&self.to_f32(), f)
+        } else {
+            shortest::exp(softfloat::TF32, self.0 >> 13, false, f)
+        }
+    }
+}
+
+impl UpperExp for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() || f.precision().is_some() {
+            UpperExp::fmt(&self.to_f32(), f)
+        } else {
+            shortest::exp(softfloat::TF32, self.0 >> 13, true, f)
+        }
+    }
+}
+
+impl Binary for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:b}", self.0)
+    }
+}
+
+impl Octal for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:o}", self.0)
+    }
+}
+
+impl LowerHex for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+impl UpperHex for tf32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+impl Neg for tf32 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x8000_0000)
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use core::f64::consts;
+    use quickcheck_macros::quickcheck;
+    use std::{format, string::ToString};
+
+    const MODES: [RoundingMode; 6] = [
+        RoundingMode::NearestTiesToEven,
+        RoundingMode::NearestTiesToAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+        RoundingMode::ToOdd,
+    ];
+
+    /// Rounds with the general software path, which the fast paths must match
+    fn reference(value: f32, mode: RoundingMode) -> u32 {
+        softfloat::from_f32(softfloat::TF32, value, mode) << 13
+    }
+
+    #[test]
+    fn fast_paths_match_softfloat() {
+        // Every combination of the exponent, the lowest kept fraction bit and the discarded bits
+        // around the halfway point, for both signs
+        for high in 0..=0x7FFFFu32 {
+            if high & 0x3F0 != 0 && high & 0x3F0 != 0x3F0 {
+                continue;
+            }
+            for &low in &[0u32, 1, 0x0FFF, 0x1000, 0x1001, 0x1FFF] {
+                let x = f32::from_bits((high << 13) | low);
+                for &mode in &[RoundingMode::NearestTiesToEven, RoundingMode::TowardZero] {
+                    let expected = reference(x, mode);
+                    let actual = tf32::from_f32_round(x, mode).to_bits();
+                    if x.is_nan() {
+                        assert!(tf32::from_bits(actual).is_nan());
+                    } else {
+                        assert_eq!(actual, expected, "{:08X} {:?}", x.to_bits(), mode);
+                    }
+                }
+            }
+        }
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_round(x: f32) -> bool {
+        MODES.iter().all(|&mode| {
+            let y = tf32::from_f32_round(x, mode);
+            (x.is_nan() && y.is_nan()) || y.to_bits() == reference(x, mode)
+        })
+    }
+
+    #[quickcheck]
+    fn qc_round_trip(bits: u32) -> bool {
+        let x = tf32::from_bits(bits);
+        let parsed = x.to_string().parse::<tf32>().unwrap();
+        x.is_nan() || (tf32::from_f32(x.to_f32()).to_bits() == x.to_bits() && parsed == x)
+    }
+
+    #[test]
+    fn rounding() {
+        let one = 1.0f32.to_bits();
+        let tie = f32::from_bits(one | 0x1000);
+        assert_eq!(tf32::from_f32(tie), tf32::ONE);
+        let tie = f32::from_bits(one | 0x3000);
+        assert_eq!(tf32::from_f32(tie).to_bits(), one + 0x4000);
+        assert_eq!(
+            tf32::from_f32(f32::from_bits(one | 0x1001)).to_bits(),
+            one + 0x2000
+        );
+        assert_eq!(
+            tf32::from_f32_round(f32::from_bits(one | 0x1FFF), RoundingMode::TowardZero),
+            tf32::ONE
+        );
+        assert_eq!(tf32::from_f32(f32::MAX), tf32::INFINITY);
+        assert_eq!(tf32::from_f32(f32::MIN), tf32::NEG_INFINITY);
+        assert_eq!(
+            tf32::from_f32_round(f32::MIN, RoundingMode::TowardZero),
+            tf32::MIN
+        );
+        assert_eq!(
+            tf32::from_f32(f32::from_bits(0x1FFF)),
+            tf32::MIN_POSITIVE_SUBNORMAL
+        );
+        assert_eq!(
+            tf32::from_f32(-f32::from_bits(0x0FFF)).to_bits(),
+            0x8000_0000
+        );
+
+        // A NaN with its payload only in the discarded bits stays NaN
+        let nan = f32::from_bits(0x7F80_0001);
+        assert!(tf32::from_f32(nan).is_nan());
+        assert!(tf32::from_f32_round(-nan, RoundingMode::TowardZero).is_sign_negative());
+        assert!(tf32::from_f32_round(nan, RoundingMode::TowardZero).is_nan());
+
+        // f64 values round once, not through f32
+        let x = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+        assert_eq!(tf32::from_f64(x).to_bits(), one + 0x2000);
+        assert_eq!(tf32::from_f32(x as f32), tf32::ONE);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(tf32::ONE.to_f32(), 1.0);
+        assert_eq!(tf32::EPSILON.to_f32(), 2f32.powi(-10));
+        assert_eq!(tf32::MAX.to_f32(), 2f32.powi(127) * (2.0 - 2f32.powi(-10)));
+        assert_eq!(tf32::MIN, -tf32::MAX);
+        assert_eq!(tf32::MIN_POSITIVE.to_f32(), f32::MIN_POSITIVE);
+        assert_eq!(tf32::MIN_POSITIVE_SUBNORMAL.to_f64(), 2f64.powi(-136));
+        assert_eq!(tf32::MAX_SUBNORMAL.classify(), FpCategory::Subnormal);
+        assert!(tf32::NAN.is_nan());
+        assert_eq!(tf32::from_bits(0x3F80_1FFF), tf32::ONE);
+
+        assert_eq!(tf32::E, tf32::from_f64(consts::E));
+        assert_eq!(tf32::PI, tf32::from_f64(consts::PI));
+        assert_eq!(tf32::FRAC_1_PI, tf32::from_f64(consts::FRAC_1_PI));
+        assert_eq!(tf32::FRAC_1_SQRT_2, tf32::from_f64(consts::FRAC_1_SQRT_2));
+        assert_eq!(tf32::FRAC_2_PI, tf32::from_f64(consts::FRAC_2_PI));
+        assert_eq!(tf32::FRAC_2_SQRT_PI, tf32::from_f64(consts::FRAC_2_SQRT_PI));
+        assert_eq!(tf32::FRAC_PI_2, tf32::from_f64(consts::FRAC_PI_2));
+        assert_eq!(tf32::FRAC_PI_3, tf32::from_f64(consts::FRAC_PI_3));
+        assert_eq!(tf32::FRAC_PI_4, tf32::from_f64(consts::FRAC_PI_4));
+        assert_eq!(tf32::FRAC_PI_6, tf32::from_f64(consts::FRAC_PI_6));
+        assert_eq!(tf32::FRAC_PI_8, tf32::from_f64(consts::FRAC_PI_8));
+        assert_eq!(tf32::LN_10, tf32::from_f64(consts::LN_10));
+        assert_eq!(tf32::LN_2, tf32::from_f64(consts::LN_2));
+        assert_eq!(tf32::LOG10_E, tf32::from_f64(consts::LOG10_E));
+        assert_eq!(tf32::LOG10_2, tf32::from_f64(2f64.log10()));
+        assert_eq!(tf32::LOG2_E, tf32::from_f64(consts::LOG2_E));
+        assert_eq!(tf32::LOG2_10, tf32::from_f64(10f64.log2()));
+        assert_eq!(tf32::SQRT_2, tf32::from_f64(consts::SQRT_2));
+    }
+
+    #[test]
+    fn format_and_parse() {
+        assert_eq!(tf32::from_f32(0.1).to_string(), "0.1");
+        assert_eq!(tf32::from_f32(1.0 / 3.0).to_string(), "0.3333");
+        assert_eq!(format!("{:?}", tf32::MAX), "3.401e38");
+        assert_eq!(format!("{:e}", tf32::ONE), "1e0");
+        assert_eq!(format!("{}", tf32::NEG_INFINITY), "-inf");
+        assert_eq!(format!("{:.3}", tf32::PI), "3.141");
+        assert_eq!(format!("{:X}", tf32::ONE), "3F800000");
+        assert_eq!("0.1".parse::<tf32>(), Ok(tf32::from_f32(0.1)));
+        assert_eq!("1e39".parse::<tf32>(), Ok(tf32::INFINITY));
+        assert!(tf32::from_ascii_strict(b"1e39").is_err());
+    }
+}
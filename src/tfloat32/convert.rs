@@ -0,0 +1,59 @@
+use crate::{softfloat, RoundingMode};
+
+/// The low bits of an `f32` that TensorFloat-32 does not keep
+pub(crate) const DISCARDED: u32 = 0x1FFF;
+
+/// Rounds the bits of an `f32` to the nearest TF32 value, ties to even
+///
+/// This is branch-free so that loops over slices vectorize, and keeps NaN values NaN.
+#[inline]
+pub(crate) fn f32_bits_to_tf32_nearest(x: u32) -> u32 {
+    softfloat::round_f32_bits_nearest(x, 13)
+}
+
+/// Truncates the bits of an `f32` to TF32, rounding toward zero
+///
+/// Like [`f32_bits_to_tf32_nearest`], this is branch-free and keeps NaN values NaN.
+#[inline]
+pub(crate) fn f32_bits_to_tf32_truncate(x: u32) -> u32 {
+    let nan = x & 0x7FFF_FFFF > 0x7F80_0000;
+    let bits = if nan { x | 0x0040_0000 } else { x };
+    bits & !DISCARDED
+}
+
+/// Rounds an `f32` to TF32 with any rounding mode, returning the bits in `f32` layout
+#[inline]
+pub(crate) fn f32_to_tf32(value: f32, mode: RoundingMode) -> u32 {
+    match mode {
+        RoundingMode::NearestTiesToEven => f32_bits_to_tf32_nearest(value.to_bits()),
+        RoundingMode::TowardZero => f32_bits_to_tf32_truncate(value.to_bits()),
+        _ => softfloat::from_f32(softfloat::TF32, value, mode) << 13,
+    }
+}
+
+/// Rounds an `f64` to TF32 exactly once, returning the bits in `f32` layout
+#[inline]
+pub(crate) fn f64_to_tf32(value: f64, mode: RoundingMode) -> u32 {
+    softfloat::from_f64(softfloat::TF32, value, mode) << 13
+}
+
+/// Rounds every element of `values` to TF32 in place
+pub(crate) fn round_slice(values: &mut [f32], mode: RoundingMode) {
+    match mode {
+        RoundingMode::NearestTiesToEven => {
+            for x in values.iter_mut() {
+                *x = f32::from_bits(f32_bits_to_tf32_nearest(x.to_bits()));
+            }
+        }
+        RoundingMode::TowardZero => {
+            for x in values.iter_mut() {
+                *x = f32::from_bits(f32_bits_to_tf32_truncate(x.to_bits()));
+            }
+        }
+        _ => {
+            for x in values.iter_mut() {
+                *x = f32::from_bits(f32_to_tf32(*x, mode));
+            }
+        }
+    }
+}
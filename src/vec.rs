@@ -3,7 +3,8 @@
 //!
 //! The utility [`HalfBitsVecExt`] sealed extension trait is implemented for [`Vec<u16>`] vectors,
 //! while the utility [`HalfFloatVecExt`] sealed extension trait is implemented for both
-//! [`Vec<f16>`] and [`Vec<bf16>`] vectors. These traits provide efficient conversions and
+//! [`Vec<f16>`] and [`Vec<bf16>`] vectors, as well as vectors of the other 16-bit formats
+//! [`f16ahp`] and [`dlfloat16`]. These traits provide efficient conversions and
 //! reinterpret casting of larger buffers of floating point values, and are automatically included
 //! in the [`prelude`][crate::prelude] module.
 //!
@@ -11,12 +12,13 @@
 
 #![cfg(any(feature = "alloc", feature = "std"))]
 
-use super::{bf16, f16, slice::HalfFloatSliceExt};
+use super::{bf16, dlfloat16, f16, f16ahp, slice::HalfFloatSliceExt};
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 use core::mem;
 
-/// Extensions to [`Vec<f16>`] and [`Vec<bf16>`] to support reinterpret operations.
+/// Extensions to [`Vec<f16>`], [`Vec<bf16>`] and vectors of the other 16-bit formats to support
+/// reinterpret operations.
 ///
 /// This trait is sealed and cannot be implemented outside of this crate.
 pub trait HalfFloatVecExt: private::SealedHalfFloatVec {
@@ -77,7 +79,8 @@ pub trait HalfFloatVecExt: private::SealedHalfFloatVec {
 pub trait HalfBitsVecExt: private::SealedHalfBitsVec {
     /// Reinterprets a vector of [`u16`] bits as a vector of [`f16`] or [`bf16`] numbers
     ///
-    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    /// `H` is the type to cast to, and must be one of the 16-bit floating point types, such as
+    /// [`f16`] or [`bf16`].
     ///
     /// This is a zero-copy operation. The reinterpreted vector has the same memory location as
     /// `self`.
@@ -97,13 +100,15 @@ pub trait HalfBitsVecExt: private::SealedHalfBitsVec {
 }
 
 mod private {
-    use crate::{bf16, f16};
+    use crate::{bf16, dlfloat16, f16, f16ahp};
     #[cfg(all(feature = "alloc", not(feature = "std")))]
     use alloc::vec::Vec;
 
     pub trait SealedHalfFloatVec {}
     impl SealedHalfFloatVec for Vec<f16> {}
     impl SealedHalfFloatVec for Vec<bf16> {}
+    impl SealedHalfFloatVec for Vec<f16ahp> {}
+    impl SealedHalfFloatVec for Vec<dlfloat16> {}
 
     pub trait SealedHalfBitsVec {}
     impl SealedHalfBitsVec for Vec<u16> {}
@@ -193,6 +198,37 @@ impl HalfFloatVecExt for Vec<bf16> {
     }
 }
 
+macro_rules! impl_half_float_vec {
+    ($ty:ident) => {
+        impl HalfFloatVecExt for Vec<$ty> {
+            #[inline]
+            fn reinterpret_into(mut self) -> Vec<u16> {
+                let length = self.len();
+                let capacity = self.capacity();
+                let pointer = self.as_mut_ptr() as *mut u16;
+
+                // Prevent running a destructor on the old Vec, so the pointer won't be deleted
+                mem::forget(self);
+
+                // SAFETY: We are reconstructing full length and capacity of original vector,
+                // using its original pointer, and the size of elements are identical.
+                unsafe { Vec::from_raw_parts(pointer, length, capacity) }
+            }
+
+            fn from_f32_slice(slice: &[f32]) -> Self {
+                slice.iter().map(|&x| $ty::from_f32(x)).collect()
+            }
+
+            fn from_f64_slice(slice: &[f64]) -> Self {
+                slice.iter().map(|&x| $ty::from_f64(x)).collect()
+            }
+        }
+    };
+}
+
+impl_half_float_vec!(f16ahp);
+impl_half_float_vec!(dlfloat16);
+
 impl HalfBitsVecExt for Vec<u16> {
     // This is safe because all traits are sealed
     #[inline]
@@ -242,9 +278,9 @@ pub fn to_bits(numbers: Vec<f16>) -> Vec<u16> {
 #[cfg(test)]
 mod test {
     use super::{HalfBitsVecExt, HalfFloatVecExt};
-    use crate::{bf16, f16};
+    use crate::{bf16, dlfloat16, f16, f16ahp, slice::HalfFloatSliceExt};
     #[cfg(all(feature = "alloc", not(feature = "std")))]
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
 
     #[test]
     fn test_vec_conversions_f16() {
@@ -285,4 +321,19 @@ mod test {
         let to_bits = from_bits.reinterpret_into();
         assert_eq!(&to_bits[..], &bits_cloned[..]);
     }
+
+    #[test]
+    fn test_vec_conversions_alt16() {
+        let values = [1.0, -2.5, 99968.0, 1e-6];
+        let ahp = Vec::<f16ahp>::from_f32_slice(&values);
+        assert_eq!(ahp.to_f32_vec()[..3], values[..3]);
+        let bits = ahp.reinterpret_into();
+        assert_eq!(bits[0], f16ahp::ONE.to_bits());
+        let dlf = bits.reinterpret_into::<dlfloat16>();
+        assert_eq!(dlf[0].to_bits(), 0x3C00);
+
+        let dlf = Vec::<dlfloat16>::from_f64_slice(&[1.0, 1e10]);
+        assert_eq!(dlf[0], dlfloat16::ONE);
+        assert!(dlf[1].is_nan());
+    }
 }